@@ -6,9 +6,9 @@ async fn main() {
     let context = WgpuContext::new_async().await;
 
     // Create buffers
-    let source = StorageBuffer::new::<i32, _>(&context, 10);
-    let target = StorageBuffer::new::<i32, _>(&context, 10);
-    let readback = ReadbackBuffer::new::<i32, _>(&context, 10);
+    let source = StorageBuffer::<i32>::new(&context, 10);
+    let target = StorageBuffer::<i32>::new(&context, 10);
+    let readback = ReadbackBuffer::<i32>::new(&context, 10);
     
     // Initialize input buffer with data
     source.update_buffer_range(&context, &(0_i32..10).collect::<Vec<_>>(), 0);
@@ -30,7 +30,7 @@ async fn main() {
     );
 
     // Execute the shader
-    shader.execute_with_params(&context, 10, &[&source, &target]);
+    shader.execute_with_params(&context, 10, &[&source, &target]).unwrap();
 
-    println!("Output data: {:?}", readback.read::<i32>(&context, &target, 0, 10));
+    println!("Output data: {:?}", readback.read(&context, &target, 0, 10));
 } 
\ No newline at end of file
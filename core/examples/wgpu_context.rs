@@ -7,6 +7,7 @@ async fn main() -> Result<(), Box<dyn Error>> {
     println!("Initializing GPU context...");
     let context = WgpuContext::new_async().await;
     println!("GPU context initialized successfully!");
+    println!("{}", context.report());
 
     // Create a simple value buffer
     println!("\nCreating a value buffer...");
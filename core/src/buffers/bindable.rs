@@ -0,0 +1,45 @@
+use std::num::NonZeroU64;
+
+/// A GPU buffer that can be bound to a shader binding slot.
+///
+/// Implemented by the typed buffers ([`StorageBuffer<T>`](crate::StorageBuffer),
+/// [`ValueBuffer<T>`](crate::ValueBuffer)) and by raw [`wgpu::Buffer`]s, so
+/// heterogeneous buffers can be passed to [`Shader`](crate::Shader) as `&[&dyn Bindable]`.
+///
+/// # Examples
+/// ```no_run
+/// use sgrmath_core::{Error, Shader, StorageBuffer, ValueBuffer, WgpuContext};
+///
+/// fn example(context: &WgpuContext, shader: &Shader) -> Result<(), Error> {
+///     let options = ValueBuffer::init(context, &42_u32);
+///     let values = StorageBuffer::<f32>::new(context, 100);
+///
+///     shader.execute_with_params(context, 100, &[&options, &values])
+/// }
+/// ```
+pub trait Bindable {
+    /// Returns the underlying WGPU buffer.
+    fn buffer(&self) -> &wgpu::Buffer;
+
+    /// Returns the number of bytes exposed to the shader.
+    fn binding_size(&self) -> u64;
+
+    /// Returns the binding resource covering the first `binding_size` bytes of the buffer.
+    fn as_binding(&self) -> wgpu::BindingResource<'_> {
+        wgpu::BindingResource::Buffer(wgpu::BufferBinding {
+            buffer: self.buffer(),
+            offset: 0,
+            size: NonZeroU64::new(self.binding_size()),
+        })
+    }
+}
+
+impl Bindable for wgpu::Buffer {
+    fn buffer(&self) -> &wgpu::Buffer {
+        self
+    }
+
+    fn binding_size(&self) -> u64 {
+        self.size()
+    }
+}
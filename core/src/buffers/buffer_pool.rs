@@ -0,0 +1,197 @@
+use std::{
+    collections::HashMap,
+    ops::Deref,
+    sync::{Arc, Mutex, Weak},
+};
+
+use crate::{Bindable, ReadbackBuffer, Size, StorageBuffer};
+
+/// The smallest size class in bytes
+const MIN_SIZE_CLASS: u64 = 256;
+
+/// Idle buffers keyed by usage and size class
+type FreeList = HashMap<(wgpu::BufferUsages, u64), Vec<wgpu::Buffer>>;
+
+#[derive(Debug)]
+struct PoolState {
+    device: wgpu::Device,
+    free: FreeList,
+}
+
+/// A pool of GPU buffers for transient, per-evaluation allocations.
+///
+/// Buffers are handed out as [`Lease`]s keyed by usage and a power-of-two size class,
+/// and go back to the pool when the last clone of the lease is dropped. A problem that
+/// requests the same scratch space every generation therefore allocates GPU memory once.
+///
+/// Every [`WgpuContext`](crate::WgpuContext) owns a pool, available as `context.pool`.
+/// Cloning the pool shares it.
+///
+/// Leased buffers are not cleared: they hold whatever the previous lease wrote.
+/// Clones of the leased buffer itself (e.g. `(*lease).clone()`) must not outlive the lease.
+///
+/// # Examples
+/// ```no_run
+/// use sgrmath_core::WgpuContext;
+///
+/// fn example(context: &WgpuContext) {
+///     let scratch = context.pool.storage::<f32, _>(1000);
+///     assert_eq!(scratch.len(), 1000);
+///     drop(scratch);
+///
+///     // The same GPU buffer is handed out again
+///     let scratch = context.pool.storage::<f32, _>(900);
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct BufferPool(Arc<Mutex<PoolState>>);
+
+impl BufferPool {
+    /// Creates an empty pool allocating buffers on the given device
+    ///
+    /// # Arguments
+    /// * `device` - The device buffers are created on
+    #[must_use]
+    pub fn new(device: &wgpu::Device) -> Self {
+        Self(Arc::new(Mutex::new(PoolState { device: device.clone(), free: HashMap::new() })))
+    }
+
+    /// Leases a storage buffer holding `size` elements of type T
+    ///
+    /// # Arguments
+    /// * `size` - The size of the buffer in elements of type T
+    ///
+    /// # Returns
+    /// A lease dereferencing to a [`StorageBuffer<T>`] of exactly `size` elements
+    pub fn storage<T, S>(&self, size: S) -> Lease<StorageBuffer<T>>
+    where
+        T: bytemuck::Pod,
+        S: Into<Size>,
+    {
+        let len = size.into().len();
+        let buffer = self.acquire(StorageBuffer::<T>::usage(), (len * std::mem::size_of::<T>()) as u64);
+
+        self.lease(StorageBuffer::from_raw(buffer.clone(), len), buffer)
+    }
+
+    /// Leases a readback buffer holding `size` elements of type T
+    ///
+    /// # Arguments
+    /// * `size` - The size of the buffer in elements of type T
+    ///
+    /// # Returns
+    /// A lease dereferencing to a [`ReadbackBuffer<T>`] of exactly `size` elements
+    pub fn readback<T, S>(&self, size: S) -> Lease<ReadbackBuffer<T>>
+    where
+        T: bytemuck::Pod,
+        S: Into<Size>,
+    {
+        let len = size.into().len();
+        let buffer = self.acquire(ReadbackBuffer::<T>::usage(), (len * std::mem::size_of::<T>()) as u64);
+
+        self.lease(ReadbackBuffer::from_raw(buffer.clone(), len), buffer)
+    }
+
+    /// Returns the number of idle buffers kept by the pool
+    #[must_use]
+    pub fn idle_count(&self) -> usize {
+        self.0
+            .lock()
+            .map_or(0, |state| state.free.values().map(Vec::len).sum())
+    }
+
+    /// Releases all idle buffers
+    pub fn clear(&self) {
+        if let Ok(mut state) = self.0.lock() {
+            state.free.clear();
+        }
+    }
+
+    fn lease<B>(&self, value: B, buffer: wgpu::Buffer) -> Lease<B> {
+        Lease(Arc::new(LeaseInner { value, buffer: Some(buffer), pool: Arc::downgrade(&self.0) }))
+    }
+
+    /// Takes an idle buffer of the matching size class or creates a new one
+    fn acquire(&self, usage: wgpu::BufferUsages, size: u64) -> wgpu::Buffer {
+        let mut state = self.0.lock().expect("Buffer pool lock poisoned");
+        let size = size_class(size, state.device.limits().max_buffer_size);
+
+        if let Some(buffer) = state.free.get_mut(&(usage, size)).and_then(Vec::pop) {
+            return buffer;
+        }
+
+        state.device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("Pooled Buffer"),
+            size,
+            usage,
+            mapped_at_creation: false,
+        })
+    }
+}
+
+/// Rounds a byte size up to its size class
+///
+/// Sizes are rounded up to the next power of two, or to the copy alignment
+/// when the power of two would exceed the device limit.
+fn size_class(size: u64, max_buffer_size: u64) -> u64 {
+    let class = size.max(MIN_SIZE_CLASS).next_power_of_two();
+    if class <= max_buffer_size {
+        return class;
+    }
+
+    (size + wgpu::COPY_BUFFER_ALIGNMENT - 1) / wgpu::COPY_BUFFER_ALIGNMENT * wgpu::COPY_BUFFER_ALIGNMENT
+}
+
+#[derive(Debug)]
+struct LeaseInner<B> {
+    value: B,
+    buffer: Option<wgpu::Buffer>,
+    pool: Weak<Mutex<PoolState>>,
+}
+
+impl<B> Drop for LeaseInner<B> {
+    fn drop(&mut self) {
+        let (Some(buffer), Some(pool)) = (self.buffer.take(), self.pool.upgrade()) else {
+            return;
+        };
+
+        if let Ok(mut state) = pool.lock() {
+            state.free.entry((buffer.usage(), buffer.size())).or_default().push(buffer);
+        };
+    }
+}
+
+/// A buffer leased from a [`BufferPool`].
+///
+/// Dereferences to the typed buffer and can be passed to a [`Shader`](crate::Shader)
+/// like the buffer itself. Cloning a lease shares it; the buffer goes back to the pool
+/// when the last clone is dropped.
+#[derive(Debug)]
+pub struct Lease<B>(Arc<LeaseInner<B>>);
+
+impl<B> Clone for Lease<B> {
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
+impl<B> Deref for Lease<B> {
+    type Target = B;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0.value
+    }
+}
+
+impl<B> Bindable for Lease<B>
+where
+    B: Bindable,
+{
+    fn buffer(&self) -> &wgpu::Buffer {
+        self.0.value.buffer()
+    }
+
+    fn binding_size(&self) -> u64 {
+        self.0.value.binding_size()
+    }
+}
@@ -1,29 +1,30 @@
 macro_rules! buffer {
     ($doc:expr, $name:ident, $usage:expr) => {
         #[doc = $doc]
-        #[derive(Clone, Debug)]
-        pub struct $name (pub wgpu::Buffer);
+        pub struct $name<T> (pub wgpu::Buffer, pub(crate) usize, std::marker::PhantomData<T>);
 
-        impl $name {
+        impl<T> $name<T>
+        where
+            T: bytemuck::Pod,
+        {
             /// Creates a new buffer with the specified size
-            /// 
+            ///
             /// # Arguments
             /// * `context` - The WGPU context
             /// * `size` - The size of the buffer in elements of type T
-            /// 
+            ///
             /// # Returns
             /// A new buffer instance
-            /// 
+            ///
             /// # Panics
             /// Panics if the buffer size would overflow
-            pub fn new<T, S>(context: &crate::WgpuContext, size: S) -> Self 
+            pub fn new<S>(context: &crate::WgpuContext, size: S) -> Self
             where
-                T: bytemuck::Pod,
                 S: Into<crate::Size>,
             {
-                let size = size.into();
-                
-                Self(Self::create_buffer::<T>(context, size.len() as u64))
+                let len = size.into().len();
+
+                Self(Self::create_buffer(context, len as u64), len, std::marker::PhantomData)
             }
 
             /// Returns the size of the buffer in bytes
@@ -34,30 +35,69 @@ macro_rules! buffer {
 
             /// Returns the size of the buffer in elements of type T
             #[must_use]
-            pub fn len<T>(&self) -> usize 
-            where
-                T: bytemuck::Pod,
-            {
-                (self.0.size() / std::mem::size_of::<T>() as u64) as usize
+            pub const fn len(&self) -> usize {
+                self.1
             }
 
             /// Returns true if the buffer is empty
             #[must_use]
-            pub fn is_empty(&self) -> bool {
-                self.0.size() == 0
+            pub const fn is_empty(&self) -> bool {
+                self.1 == 0
+            }
+
+            /// Checks that `len` elements starting at element `start` lie inside the buffer
+            ///
+            /// # Errors
+            /// Returns [`Error::OutOfBounds`](crate::Error::OutOfBounds) if they do not.
+            pub(crate) fn check_range(&self, start: usize, len: usize) -> Result<(), crate::Error> {
+                let element = std::mem::size_of::<T>() as u64;
+                let size = self.0.size();
+                let offset = (start as u64).saturating_mul(element);
+                let len = (len as u64).saturating_mul(element);
+
+                match offset.checked_add(len) {
+                    Some(end) if end <= size => Ok(()),
+                    _ => Err(crate::Error::OutOfBounds { offset, len, size }),
+                }
             }
 
-            pub(crate) fn create_buffer<T>(context: &crate::WgpuContext, len: u64) -> wgpu::Buffer {
-                context.device.create_buffer(&wgpu::BufferDescriptor {
+            /// Returns the usages every buffer of this kind is created with
+            pub(crate) fn usage() -> wgpu::BufferUsages {
+                $usage
+            }
+
+            /// Wraps an existing buffer holding at least `len` elements of type T
+            pub(crate) const fn from_raw(buffer: wgpu::Buffer, len: usize) -> Self {
+                Self(buffer, len, std::marker::PhantomData)
+            }
+
+            pub(crate) fn create_buffer(device: &wgpu::Device, len: u64) -> wgpu::Buffer {
+                device.create_buffer(&wgpu::BufferDescriptor {
                     label: Some(stringify!($name)),
                     size: len * std::mem::size_of::<T>() as u64,
-                    usage: $usage,
+                    usage: Self::usage(),
                     mapped_at_creation: false,
                 })
             }
         }
 
-        impl std::ops::Deref for $name {
+        impl<T> Clone for $name<T> {
+            fn clone(&self) -> Self {
+                Self(self.0.clone(), self.1, std::marker::PhantomData)
+            }
+        }
+
+        impl<T> std::fmt::Debug for $name<T> {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.debug_struct(stringify!($name))
+                    .field("buffer", &self.0)
+                    .field("len", &self.1)
+                    .field("element", &std::any::type_name::<T>())
+                    .finish()
+            }
+        }
+
+        impl<T> std::ops::Deref for $name<T> {
             type Target = wgpu::Buffer;
 
             fn deref(&self) -> &Self::Target {
@@ -70,28 +110,43 @@ macro_rules! buffer {
 buffer!(
     "
      StorageBuffer
-     
-     A buffer that can be used as a storage buffer
-     
+
+     A buffer that can be used as a storage buffer. The buffer remembers its element
+     type `T` and its length in elements, so reads and writes don't need a turbofish
+     and mismatched element types are compile errors.
+
      # Usage
      * `wgpu::BufferUsages::STORAGE` - The buffer can be used as a storage buffer
      * `wgpu::BufferUsages::COPY_DST` - The buffer can be used as a destination for copying
      * `wgpu::BufferUsages::COPY_SRC` - The buffer can be used as a source for copying
     ",
-    StorageBuffer, 
+    StorageBuffer,
     wgpu::BufferUsages::STORAGE | wgpu::BufferUsages::COPY_DST | wgpu::BufferUsages::COPY_SRC
 );
 buffer!(
     "
      ReadbackBuffer
-     
-     A buffer that can be read back from the GPU
-     
+
+     A buffer that can be read back from the GPU. It reads elements of type `T`
+     from a [`StorageBuffer<T>`](crate::StorageBuffer) of the same element type.
+
      # Usage
      * `wgpu::BufferUsages::MAP_READ` - The buffer can be mapped for reading
      * `wgpu::BufferUsages::COPY_DST` - The buffer can be used as a destination for copying
     ",
-    ReadbackBuffer, 
+    ReadbackBuffer,
     wgpu::BufferUsages::MAP_READ | wgpu::BufferUsages::COPY_DST
 );
 
+impl<T> crate::Bindable for StorageBuffer<T>
+where
+    T: bytemuck::Pod,
+{
+    fn buffer(&self) -> &wgpu::Buffer {
+        &self.0
+    }
+
+    fn binding_size(&self) -> u64 {
+        (self.1 * std::mem::size_of::<T>()) as u64
+    }
+}
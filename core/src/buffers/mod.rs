@@ -1,7 +1,15 @@
+mod bindable;
+mod buffer_pool;
 mod buffers;
+mod readback_batch;
 mod readback_buffer;
+mod readback_future;
 mod storage_buffer;
 mod value_buffer;
 
+pub use bindable::Bindable;
+pub use buffer_pool::{BufferPool, Lease};
 pub use buffers::{ReadbackBuffer, StorageBuffer};
+pub use readback_batch::{ReadbackBatch, ReadbackResults, ReadbackSlot};
+pub use readback_future::{MapRequest, ReadbackBatchFuture, ReadbackFuture};
 pub use value_buffer::ValueBuffer;
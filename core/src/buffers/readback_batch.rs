@@ -0,0 +1,244 @@
+use std::marker::PhantomData;
+
+use crate::{Error, ReadbackBuffer, StorageBuffer, WgpuContext};
+
+use super::readback_future::{MapRequest, ReadbackBatchFuture};
+
+/// Alignment of every range inside the staging buffer.
+///
+/// Covers `wgpu::COPY_BUFFER_ALIGNMENT` and the alignment of every 8-byte `Pod` type,
+/// so each range can be viewed as a typed slice.
+const RANGE_ALIGNMENT: usize = 8;
+
+/// A handle to a range pushed into a [`ReadbackBatch`].
+///
+/// The handle remembers the element type, so [`ReadbackResults::get`] returns a typed slice.
+#[derive(Debug)]
+pub struct ReadbackSlot<T> {
+    index: usize,
+    _t: PhantomData<fn() -> T>,
+}
+
+impl<T> Clone for ReadbackSlot<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for ReadbackSlot<T> {}
+
+/// A copy of a source range into the staging buffer
+#[derive(Debug)]
+struct BatchCopy {
+    source: wgpu::Buffer,
+    source_offset: u64,
+    offset: usize,
+    len: usize,
+}
+
+/// Reads several ranges, possibly from different storage buffers, with a single mapping.
+///
+/// Every pushed range is copied into one staging buffer, which is mapped once and
+/// split back into typed slices. The staging buffer is kept between reads and only
+/// grows, so a batch reused every generation allocates once.
+///
+/// # Examples
+/// ```no_run
+/// use sgrmath_core::{ReadbackBatch, StorageBuffer, WgpuContext};
+///
+/// fn example(context: &WgpuContext, parents: &StorageBuffer<u32>, results: &StorageBuffer<f32>) {
+///     let mut batch = ReadbackBatch::new();
+///     let parents_slot = batch.push(parents, 0, parents.len());
+///     let results_slot = batch.push(results, 0, results.len());
+///
+///     let data = batch.read(context);
+///     println!("{:?} {:?}", data.get(parents_slot), data.get(results_slot));
+/// }
+/// ```
+#[derive(Debug, Default)]
+pub struct ReadbackBatch {
+    staging: Option<ReadbackBuffer<u8>>,
+    copies: Vec<BatchCopy>,
+    size: usize,
+}
+
+impl ReadbackBatch {
+    /// Creates an empty batch
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the number of pending ranges
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.copies.len()
+    }
+
+    /// Returns true if no range is pending
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.copies.is_empty()
+    }
+
+    /// Adds a range of a storage buffer to the batch
+    ///
+    /// # Arguments
+    /// * `source` - The source buffer to copy from
+    /// * `start` - The starting index in elements of type T
+    /// * `len` - The number of elements to read
+    ///
+    /// # Returns
+    /// A slot used to get the range from the [`ReadbackResults`]
+    ///
+    /// # Panics
+    /// * If start + len would cause an integer overflow
+    /// * If start + len is beyond the end of the source buffer
+    /// * If the byte offset or length of the range is not a multiple of 4
+    pub fn push<T>(&mut self, source: &StorageBuffer<T>, start: usize, len: usize) -> ReadbackSlot<T>
+    where
+        T: bytemuck::Pod,
+    {
+        let byte_start = start.checked_mul(std::mem::size_of::<T>())
+            .expect("Buffer size overflow");
+        let byte_len = len.checked_mul(std::mem::size_of::<T>())
+            .expect("Buffer size overflow");
+
+        assert!(
+            byte_start + byte_len <= source.size(),
+            "Read would go beyond source buffer bounds ({} + {} > {})",
+            byte_start,
+            byte_len,
+            source.size()
+        );
+        assert!(
+            byte_start as u64 % wgpu::COPY_BUFFER_ALIGNMENT == 0 && byte_len as u64 % wgpu::COPY_BUFFER_ALIGNMENT == 0,
+            "Readback ranges must be aligned to {} bytes",
+            wgpu::COPY_BUFFER_ALIGNMENT
+        );
+
+        let offset = (self.size + RANGE_ALIGNMENT - 1) / RANGE_ALIGNMENT * RANGE_ALIGNMENT;
+        self.size = offset + byte_len;
+        self.copies.push(BatchCopy { source: source.0.clone(), source_offset: byte_start as u64, offset, len: byte_len });
+
+        ReadbackSlot { index: self.copies.len() - 1, _t: PhantomData }
+    }
+
+    /// Copies all pending ranges and reads them, blocking until the copies have finished
+    ///
+    /// The pending ranges are cleared, the staging buffer is kept for the next read.
+    ///
+    /// # Arguments
+    /// * `context` - The WGPU context
+    ///
+    /// # Returns
+    /// The read ranges
+    ///
+    /// # Panics
+    /// * If the buffer mapping fails
+    pub fn read(&mut self, context: &WgpuContext) -> ReadbackResults {
+        self.try_read(context).expect("Failed to map buffer")
+    }
+
+    /// Copies all pending ranges and reads them, blocking until the copies have finished
+    ///
+    /// The pending ranges are cleared, the staging buffer is kept for the next read.
+    ///
+    /// # Arguments
+    /// * `context` - The WGPU context
+    ///
+    /// # Returns
+    /// The read ranges
+    ///
+    /// # Errors
+    /// Returns [`Error::Poll`] if waiting for the copies fails and [`Error::BufferMap`]
+    /// if the buffer mapping fails.
+    pub fn try_read(&mut self, context: &WgpuContext) -> Result<ReadbackResults, Error> {
+        match self.request(context) {
+            Some((request, ranges)) => Ok(ReadbackResults::new(&request.wait()?, ranges)),
+            None => Ok(ReadbackResults::default()),
+        }
+    }
+
+    /// Copies all pending ranges and returns a future resolving to them
+    ///
+    /// The copies are submitted immediately; awaiting the future polls the device without
+    /// blocking until the staging buffer is mapped. The batch stays borrowed until then.
+    ///
+    /// # Arguments
+    /// * `context` - The WGPU context
+    ///
+    /// # Returns
+    /// A future resolving to the read ranges, or an error if the mapping fails
+    pub fn read_async(&mut self, context: &WgpuContext) -> ReadbackBatchFuture<'_> {
+        match self.request(context) {
+            Some((request, ranges)) => ReadbackBatchFuture::new(Some(request), ranges),
+            None => ReadbackBatchFuture::new(None, Vec::new()),
+        }
+    }
+
+    /// Encodes and submits the pending copies and requests the mapping
+    fn request(&mut self, context: &WgpuContext) -> Option<(MapRequest, Vec<(usize, usize)>)> {
+        if self.copies.is_empty() {
+            return None;
+        }
+
+        let size = std::mem::take(&mut self.size);
+        let staging = match &mut self.staging {
+            Some(staging) => {
+                staging.scale(context, size);
+                staging
+            }
+            None => self.staging.insert(ReadbackBuffer::new(context, size)),
+        };
+
+        let mut encoder = context.device.create_command_encoder(
+            &wgpu::CommandEncoderDescriptor { label: Some("ReadbackBatch Copy") }
+        );
+        let ranges = self.copies
+            .drain(..)
+            .map(|copy| {
+                encoder.copy_buffer_to_buffer(&copy.source, copy.source_offset, staging, copy.offset as u64, copy.len as u64);
+                (copy.offset, copy.len)
+            })
+            .collect();
+
+        Some((MapRequest::submit(context, encoder, staging, size as u64), ranges))
+    }
+}
+
+/// The ranges read by a [`ReadbackBatch`], accessed through their [`ReadbackSlot`]s.
+#[derive(Debug, Default)]
+pub struct ReadbackResults {
+    data: Vec<u64>,
+    ranges: Vec<(usize, usize)>,
+}
+
+impl ReadbackResults {
+    pub(crate) fn new(bytes: &[u8], ranges: Vec<(usize, usize)>) -> Self {
+        let mut data = vec![0_u64; (bytes.len() + std::mem::size_of::<u64>() - 1) / std::mem::size_of::<u64>()];
+        bytemuck::cast_slice_mut::<u64, u8>(&mut data)[..bytes.len()].copy_from_slice(bytes);
+
+        Self { data, ranges }
+    }
+
+    /// Returns the elements read for a slot
+    ///
+    /// # Arguments
+    /// * `slot` - The slot returned by [`ReadbackBatch::push`]
+    ///
+    /// # Panics
+    /// * If the slot was not pushed in the batch that produced these results
+    /// * If the alignment of T is above 8 bytes
+    #[must_use]
+    pub fn get<T>(&self, slot: ReadbackSlot<T>) -> &[T]
+    where
+        T: bytemuck::Pod,
+    {
+        let (offset, len) = *self.ranges
+            .get(slot.index)
+            .expect("Slot does not belong to this batch");
+
+        bytemuck::cast_slice(&bytemuck::cast_slice::<u64, u8>(&self.data)[offset..offset + len])
+    }
+}
@@ -1,8 +1,11 @@
-use wgpu::Buffer;
+use crate::{Error, ReadbackBuffer, StorageBuffer, WgpuContext};
 
-use crate::{ReadbackBuffer, WgpuContext};
+use super::readback_future::{MapRequest, ReadbackFuture};
 
-impl ReadbackBuffer {
+impl<T> ReadbackBuffer<T>
+where
+    T: bytemuck::Pod,
+{
     /// Scales the buffer to a new size if needed
     /// 
     /// # Arguments
@@ -14,9 +17,8 @@ impl ReadbackBuffer {
     /// 
     /// # Panics
     /// * If the buffer size would overflow
-    pub fn scale<T, S>(&mut self, context: &crate::WgpuContext, size: S) -> bool
+    pub fn scale<S>(&mut self, context: &crate::WgpuContext, size: S) -> bool
     where
-        T: bytemuck::Pod,
         S: Into<crate::Size>,
     {
         let new_len = size.into().len();
@@ -24,7 +26,8 @@ impl ReadbackBuffer {
             .expect("Buffer size overflow");
 
         if new_size > self.size() {
-            self.0 = Self::create_buffer::<T>(context, new_len as u64);
+            self.0 = Self::create_buffer(context, new_len as u64);
+            self.1 = new_len;
             return true;
         }
 
@@ -33,6 +36,10 @@ impl ReadbackBuffer {
 
     /// Copies data from source buffer and reads it
     /// 
+    /// Blocks until the copy has finished. Use [`ReadbackBuffer::read_async`] to wait
+    /// without blocking, or a [`ReadbackBatch`](crate::ReadbackBatch) to read several
+    /// ranges with a single mapping.
+    /// 
     /// # Arguments
     /// * `context` - The WGPU context
     /// * `source` - The source buffer to copy from
@@ -47,10 +54,75 @@ impl ReadbackBuffer {
     /// * If start + len is beyond the end of the source buffer
     /// * If the buffer mapping fails
     #[must_use]
-    pub fn read<T>(&self, context: &WgpuContext, source: &Buffer, start: usize, len: usize) -> Vec<T>
-    where
-        T: bytemuck::Pod,
-    {
+    pub fn read(&self, context: &WgpuContext, source: &StorageBuffer<T>, start: usize, len: usize) -> Vec<T> {
+        let bytes = self.request(context, source, start, len)
+            .wait()
+            .expect("Failed to map buffer");
+
+        bytemuck::pod_collect_to_vec(&bytes)
+    }
+
+    /// Copies data from source buffer and reads it, reporting failures as errors
+    /// 
+    /// # Arguments
+    /// * `context` - The WGPU context
+    /// * `source` - The source buffer to copy from
+    /// * `start` - The starting index in elements of type T
+    /// * `len` - The number of elements to read
+    /// 
+    /// # Returns
+    /// A vector containing the read data
+    /// 
+    /// # Errors
+    /// * [`Error::OutOfBounds`] if the range is beyond the end of the source buffer
+    ///   or does not fit into this buffer
+    /// * [`Error::BufferMap`] or [`Error::Poll`] if the buffer mapping fails
+    pub fn try_read(&self, context: &WgpuContext, source: &StorageBuffer<T>, start: usize, len: usize) -> Result<Vec<T>, Error> {
+        source.check_range(start, len)?;
+        self.check_range(0, len)?;
+
+        let bytes = self.request(context, source, start, len).wait()?;
+        Ok(bytemuck::pod_collect_to_vec(&bytes))
+    }
+
+    /// Copies data from source buffer and returns a future resolving to it
+    /// 
+    /// The copy is submitted immediately; awaiting the future polls the device without
+    /// blocking until the buffer is mapped. The buffer stays borrowed until then.
+    /// 
+    /// # Arguments
+    /// * `context` - The WGPU context
+    /// * `source` - The source buffer to copy from
+    /// * `start` - The starting index in elements of type T
+    /// * `len` - The number of elements to read
+    /// 
+    /// # Returns
+    /// A future resolving to the read data, or an error if the mapping fails
+    /// 
+    /// # Panics
+    /// * If start + len would cause an integer overflow
+    /// * If start + len is beyond the end of the source buffer
+    /// 
+    /// # Examples
+    /// ```no_run
+    /// use sgrmath_core::{ReadbackBuffer, StorageBuffer, WgpuContext};
+    ///
+    /// async fn example(context: &WgpuContext, source: &StorageBuffer<f32>) -> Result<Vec<f32>, sgrmath_core::Error> {
+    ///     let mut readback = ReadbackBuffer::new(context, source.len());
+    ///     readback.read_async(context, source, 0, source.len()).await
+    /// }
+    /// ```
+    pub fn read_async(
+        &mut self, 
+        context: &WgpuContext, 
+        source: &StorageBuffer<T>, 
+        start: usize, 
+        len: usize
+    ) -> ReadbackFuture<'_, T> {
+        ReadbackFuture::new(self.request(context, source, start, len))
+    }
+
+    fn request(&self, context: &WgpuContext, source: &StorageBuffer<T>, start: usize, len: usize) -> MapRequest {
         let byte_start = start.checked_mul(std::mem::size_of::<T>())
             .map(|x| x as u64)
             .expect("Buffer size overflow");
@@ -60,44 +132,18 @@ impl ReadbackBuffer {
             .expect("Buffer size overflow");
 
         assert!(
-            byte_start + byte_len <= source.size(), 
+            byte_start + byte_len <= source.size() as u64, 
             "Read would go beyond source buffer bounds ({} + {} > {})", 
             byte_start, 
             byte_len, 
             source.size()
         );
 
-        // First copy data from source to our buffer
         let mut encoder = context.device.create_command_encoder(
             &wgpu::CommandEncoderDescriptor { label: Some("ReadbackBuffer Copy") }
         );
         encoder.copy_buffer_to_buffer(source, byte_start, &self.0, 0, byte_len);
-        context.queue.submit(Some(encoder.finish()));
-
-        // Now read from our buffer
-        let buffer_slice = self.0.slice(0..byte_len as u64);
-        
-        // Create a oneshot channel for this operation
-        let (tx, rx) = std::sync::mpsc::channel();
-        buffer_slice.map_async(wgpu::MapMode::Read, move |result| {
-            tx.send(result).expect("Failed to send mapping result");
-        });
-
-        // Wait for the mapping to complete
-        context.device.poll(wgpu::MaintainBase::Wait)
-            .expect("Failed to poll device");
-
-        // Get the mapping result
-        rx.recv()
-            .expect("Failed to receive mapping result")
-            .expect("Failed to map buffer");
-
-        // Read the data
-        let data = buffer_slice.get_mapped_range();
-        let result = bytemuck::cast_slice(&data).to_vec();
-        drop(data);
-        self.0.unmap();
 
-        result
+        MapRequest::submit(context, encoder, &self.0, byte_len)
     }
-}
\ No newline at end of file
+}
@@ -0,0 +1,205 @@
+use std::{
+    future::Future,
+    marker::PhantomData,
+    pin::Pin,
+    sync::{Arc, Mutex},
+    task::{Context, Poll, Waker},
+    thread,
+};
+
+use crate::{Error, WgpuContext};
+
+/// Shared state between a pending `map_async` callback and the future waiting for it
+#[derive(Debug, Default)]
+struct MapState {
+    result: Option<Result<(), Error>>,
+    waker: Option<Waker>,
+}
+
+impl MapState {
+    /// Stores the result of the mapping, unless it is known already, and wakes the future
+    fn complete(&mut self, result: Result<(), Error>) {
+        self.result.get_or_insert(result);
+        if let Some(waker) = self.waker.take() {
+            waker.wake();
+        }
+    }
+}
+
+/// A pending read mapping of the first `len` bytes of a staging buffer.
+///
+/// Native backends have no background thread driving the device, so the first pending poll
+/// of the future starts a thread waiting for the copies. The mapping callback, run by that
+/// wait, wakes the future.
+#[derive(Debug)]
+pub struct MapRequest {
+    device: wgpu::Device,
+    buffer: wgpu::Buffer,
+    len: u64,
+    submission: wgpu::SubmissionIndex,
+    state: Arc<Mutex<MapState>>,
+    /// Whether a thread waits for the copies, see [`MapRequest::poll_bytes`]
+    polling: bool,
+    finished: bool,
+}
+
+impl MapRequest {
+    /// Submits the encoded copies and requests a read mapping of the staging buffer
+    pub fn submit(
+        context: &WgpuContext,
+        encoder: wgpu::CommandEncoder,
+        buffer: &wgpu::Buffer,
+        len: u64,
+    ) -> Self {
+        let submission = context.queue.submit(Some(encoder.finish()));
+        let state = Arc::new(Mutex::new(MapState::default()));
+
+        let callback_state = state.clone();
+        buffer.slice(0..len).map_async(wgpu::MapMode::Read, move |result| {
+            if let Ok(mut state) = callback_state.lock() {
+                state.complete(result.map_err(Error::from));
+            }
+        });
+
+        Self {
+            device: context.device.clone(),
+            buffer: buffer.clone(),
+            len,
+            submission,
+            state,
+            polling: false,
+            finished: false,
+        }
+    }
+
+    /// Blocks until the copies have finished and the buffer is mapped
+    pub fn wait(mut self) -> Result<Vec<u8>, Error> {
+        self.device.poll(wgpu::PollType::wait_for(self.submission.clone()))?;
+        // The callback always runs before a successful wait returns
+        let result = self.take_result().unwrap_or(Err(Error::BufferMap(wgpu::BufferAsyncError)));
+        self.finish(result)
+    }
+
+    fn take_result(&self) -> Option<Result<(), Error>> {
+        self.state.lock().ok().and_then(|mut state| state.result.take())
+    }
+
+    /// Copies the mapped bytes out and unmaps the buffer
+    fn finish(&mut self, result: Result<(), Error>) -> Result<Vec<u8>, Error> {
+        self.finished = true;
+        result?;
+
+        let data = self.buffer.slice(0..self.len).get_mapped_range().to_vec();
+        self.buffer.unmap();
+
+        Ok(data)
+    }
+
+    /// Returns the mapped bytes, or registers the waker of the task until the mapping callback fires
+    ///
+    /// The first pending poll starts a thread waiting for the copies, which runs the callback.
+    /// A failed wait completes the mapping with its error.
+    fn poll_bytes(&mut self, cx: &Context<'_>) -> Poll<Result<Vec<u8>, Error>> {
+        // The waker is registered under the same lock the callback takes, so no wake is lost
+        let result = self.state.lock().map_or(Some(Err(Error::BufferMap(wgpu::BufferAsyncError))), |mut state| {
+            let result = state.result.take();
+            if result.is_none() {
+                state.waker = Some(cx.waker().clone());
+            }
+            result
+        });
+        if let Some(result) = result {
+            return Poll::Ready(self.finish(result));
+        }
+
+        if !self.polling {
+            self.polling = true;
+            let (device, submission, state) = (self.device.clone(), self.submission.clone(), self.state.clone());
+            // The lock must not be held while waiting: the mapping callback runs inside `poll`
+            thread::spawn(move || {
+                if let Err(error) = device.poll(wgpu::PollType::wait_for(submission)) {
+                    if let Ok(mut state) = state.lock() {
+                        state.complete(Err(error.into()));
+                    }
+                }
+            });
+        }
+
+        Poll::Pending
+    }
+}
+
+impl Drop for MapRequest {
+    fn drop(&mut self) {
+        // Cancels a mapping that is still pending, so the buffer can be read again
+        if !self.finished {
+            self.buffer.unmap();
+        }
+    }
+}
+
+/// A future resolving to the elements read by
+/// [`ReadbackBuffer::read_async`](crate::ReadbackBuffer::read_async).
+///
+/// The readback buffer stays mapped, and therefore borrowed, until the future resolves.
+#[derive(Debug)]
+#[must_use = "futures do nothing unless polled"]
+pub struct ReadbackFuture<'a, T> {
+    request: MapRequest,
+    _buffer: PhantomData<&'a mut [T]>,
+}
+
+impl<T> ReadbackFuture<'_, T> {
+    pub(crate) const fn new(request: MapRequest) -> Self {
+        Self { request, _buffer: PhantomData }
+    }
+}
+
+impl<T> Future for ReadbackFuture<'_, T>
+where
+    T: bytemuck::Pod,
+{
+    type Output = Result<Vec<T>, Error>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        self.request
+            .poll_bytes(cx)
+            .map(|bytes| bytes.map(|bytes| bytemuck::pod_collect_to_vec(&bytes)))
+    }
+}
+
+/// A future resolving to the [`ReadbackResults`](crate::ReadbackResults) of a
+/// [`ReadbackBatch`](crate::ReadbackBatch).
+#[derive(Debug)]
+#[must_use = "futures do nothing unless polled"]
+pub struct ReadbackBatchFuture<'a> {
+    request: Option<MapRequest>,
+    ranges: Vec<(usize, usize)>,
+    _batch: PhantomData<&'a mut ()>,
+}
+
+impl ReadbackBatchFuture<'_> {
+    pub(crate) const fn new(request: Option<MapRequest>, ranges: Vec<(usize, usize)>) -> Self {
+        Self { request, ranges, _batch: PhantomData }
+    }
+}
+
+impl Future for ReadbackBatchFuture<'_> {
+    type Output = Result<crate::ReadbackResults, Error>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let ranges = std::mem::take(&mut self.ranges);
+        let Some(request) = self.request.as_mut() else {
+            // Nothing was pushed into the batch
+            return Poll::Ready(Ok(crate::ReadbackResults::default()));
+        };
+
+        match request.poll_bytes(cx) {
+            Poll::Ready(bytes) => Poll::Ready(bytes.map(|bytes| crate::ReadbackResults::new(&bytes, ranges))),
+            Poll::Pending => {
+                self.ranges = ranges;
+                Poll::Pending
+            }
+        }
+    }
+}
@@ -1,16 +1,16 @@
-use crate::{StorageBuffer, WgpuContext};
+use crate::{Error, StorageBuffer, WgpuContext};
 
-impl StorageBuffer {
+impl<T> StorageBuffer<T>
+where
+    T: bytemuck::Pod,
+{
     /// Initializes a new storage buffer with the given data
     /// 
     /// # Arguments
     /// * `context` - The WGPU context
     /// * `data` - The data to write
-    pub fn init<T>(context: &WgpuContext, data: &[T]) -> Self 
-    where
-        T: bytemuck::Pod,
-    {
-        let result = Self::new::<T, _>(context, data.len());
+    pub fn init(context: &WgpuContext, data: &[T]) -> Self {
+        let result = Self::new(context, data.len());
         result.update_buffer_range(context, data, 0);
         result
     }
@@ -26,14 +26,34 @@ impl StorageBuffer {
     /// * If the data slice is empty
     /// * If the start index would cause an overflow
     /// * If the data would write beyond the buffer's bounds
-    pub fn update_buffer_range<T>(&self, context: &WgpuContext, data: &[T], start: usize)
-    where
-        T: bytemuck::Pod,
-    {
+    pub fn update_buffer_range(&self, context: &WgpuContext, data: &[T], start: usize) {
         self.update_buffer_range_async(context, data, start);
         context.device.poll(wgpu::MaintainBase::Wait).unwrap();
     }
 
+    /// Updates a range of the buffer with new data, reporting invalid ranges as errors
+    /// 
+    /// # Arguments
+    /// * `context` - The WGPU context
+    /// * `data` - The data to write
+    /// * `start` - The starting index in elements of type T
+    /// 
+    /// # Errors
+    /// * [`Error::EmptyData`] if the data slice is empty
+    /// * [`Error::OutOfBounds`] if the data would write beyond the buffer's bounds
+    /// * [`Error::Poll`] if waiting for the device fails
+    pub fn try_update_buffer_range(&self, context: &WgpuContext, data: &[T], start: usize) -> Result<(), Error> {
+        if data.is_empty() {
+            return Err(Error::EmptyData);
+        }
+        self.check_range(start, data.len())?;
+
+        self.update_buffer_range_async(context, data, start);
+        context.device.poll(wgpu::PollType::Wait)?;
+
+        Ok(())
+    }
+
     /// Updates a range of the buffer with new data asynchronously
     /// 
     /// # Arguments
@@ -45,10 +65,7 @@ impl StorageBuffer {
     /// * If the data slice is empty
     /// * If the start index would cause an overflow
     /// * If the data would write beyond the buffer's bounds
-    pub fn update_buffer_range_async<T>(&self, context: &WgpuContext, data: &[T], start: usize)
-    where
-        T: bytemuck::Pod,
-    {
+    pub fn update_buffer_range_async(&self, context: &WgpuContext, data: &[T], start: usize) {
         assert!(!data.is_empty(), "Cannot update buffer with empty data");
 
         let byte_offset = start.checked_mul(std::mem::size_of::<T>())
@@ -57,7 +74,7 @@ impl StorageBuffer {
             .expect("Data size overflow");
         
         assert!(
-            byte_offset + byte_size <= self.size(),
+            byte_offset + byte_size <= self.len() * std::mem::size_of::<T>(),
             "Data would write beyond buffer bounds"
         );
 
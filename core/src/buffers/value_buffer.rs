@@ -1,7 +1,9 @@
+use std::marker::PhantomData;
+
 use wgpu::Buffer;
-use crate::WgpuContext;
+use crate::{Bindable, WgpuContext};
 
-/// A GPU buffer for storing a single value.
+/// A GPU buffer for storing a single value of type `T`.
 /// 
 /// This buffer is useful for passing parameters or uniform data to shaders.
 /// 
@@ -9,10 +11,12 @@ use crate::WgpuContext;
 /// * `wgpu::BufferUsages::STORAGE` - The buffer can be used as a storage buffer
 /// * `wgpu::BufferUsages::UNIFORM` - The buffer can be used as a uniform buffer
 /// * `wgpu::BufferUsages::COPY_DST` - The buffer can be used as a destination for copying
-#[derive(Clone, Debug)]
-pub struct ValueBuffer(pub Buffer);
+pub struct ValueBuffer<T>(pub Buffer, PhantomData<T>);
 
-impl ValueBuffer {
+impl<T> ValueBuffer<T>
+where
+    T: bytemuck::Pod,
+{
     /// Creates a new `ValueBuffer` with uninitialized contents.
     ///
     /// # Arguments
@@ -21,17 +25,15 @@ impl ValueBuffer {
     /// # Returns
     /// A new `ValueBuffer` instance
     #[must_use]
-    pub fn new<T>(context: &WgpuContext) -> Self
-    where
-        T: bytemuck::Pod,
-    {
+    pub fn new(context: &WgpuContext) -> Self {
         Self(
             context.device.create_buffer(&wgpu::BufferDescriptor {
                 label: Some("ValueBuffer"),
                 size: std::mem::size_of::<T>() as u64,
                 usage: wgpu::BufferUsages::STORAGE | wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST,
                 mapped_at_creation: false,
-            })
+            }),
+            PhantomData
         )
     }
 
@@ -43,11 +45,8 @@ impl ValueBuffer {
     ///
     /// # Returns
     /// A new `ValueBuffer` instance containing the value
-    pub fn init<T>(context: &WgpuContext, value: &T) -> Self
-    where
-        T: bytemuck::Pod,
-    {
-        let buf = Self::new::<T>(context);
+    pub fn init(context: &WgpuContext, value: &T) -> Self {
+        let buf = Self::new(context);
         buf.set(context, value);
         buf
     }
@@ -57,15 +56,40 @@ impl ValueBuffer {
     /// # Arguments
     /// * `context` - The WGPU context
     /// * `value` - The value to write to the buffer
-    pub fn set<T>(&self, context: &WgpuContext, value: &T)
-    where
-        T: bytemuck::Pod,
-    {
+    pub fn set(&self, context: &WgpuContext, value: &T) {
         context.queue.write_buffer(self, 0, bytemuck::cast_slice(std::slice::from_ref(value)));
     }
 }
 
-impl std::ops::Deref for ValueBuffer {
+impl<T> Bindable for ValueBuffer<T>
+where
+    T: bytemuck::Pod,
+{
+    fn buffer(&self) -> &Buffer {
+        &self.0
+    }
+
+    fn binding_size(&self) -> u64 {
+        std::mem::size_of::<T>() as u64
+    }
+}
+
+impl<T> Clone for ValueBuffer<T> {
+    fn clone(&self) -> Self {
+        Self(self.0.clone(), PhantomData)
+    }
+}
+
+impl<T> std::fmt::Debug for ValueBuffer<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ValueBuffer")
+            .field("buffer", &self.0)
+            .field("element", &std::any::type_name::<T>())
+            .finish()
+    }
+}
+
+impl<T> std::ops::Deref for ValueBuffer<T> {
     type Target = Buffer;
 
     fn deref(&self) -> &Self::Target {
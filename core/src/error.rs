@@ -0,0 +1,221 @@
+/// Errors returned by the fallible parts of the library.
+///
+/// # Examples
+/// ```no_run
+/// use sgrmath_core::{Error, WgpuContext};
+///
+/// fn example() -> Result<(), Error> {
+///     let context = WgpuContext::builder().build()?;
+///     println!("{}", context.report());
+///     Ok(())
+/// }
+/// ```
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    /// No adapter matched the requested backends and options.
+    #[error("no suitable GPU adapter found: {0}")]
+    AdapterNotFound(#[from] wgpu::RequestAdapterError),
+    /// The selected adapter does not support all required features.
+    #[error("adapter `{adapter}` does not support required features: {missing:?}")]
+    UnsupportedFeatures {
+        /// The name of the selected adapter
+        adapter: String,
+        /// The required features the adapter is missing
+        missing: wgpu::Features,
+    },
+    /// The adapter refused to create a device.
+    #[error("failed to create device: {0}")]
+    DeviceRequest(#[from] wgpu::RequestDeviceError),
+    /// The runtime used to drive device creation could not be started.
+    #[error("failed to start runtime: {0}")]
+    Runtime(#[from] std::io::Error),
+    /// A readback buffer could not be mapped for reading.
+    #[error("failed to map buffer: {0}")]
+    BufferMap(#[from] wgpu::BufferAsyncError),
+    /// Polling the device for finished work failed.
+    #[error("failed to poll device: {0}")]
+    Poll(#[from] wgpu::PollError),
+    /// The WGSL source of a shader could not be parsed or validated.
+    #[error("shader `{label}` failed to compile:\n{message}")]
+    ShaderCompilation {
+        /// The label of the shader
+        label: String,
+        /// The formatted compiler error
+        message: String,
+    },
+    /// The WGSL modules of a shader could not be composed.
+    #[error("shader `{label}` failed to compose: {reason} (module `{module}`, line {line})")]
+    ShaderComposition {
+        /// The label of the shader
+        label: String,
+        /// The name of the module containing the directive
+        module: String,
+        /// The 1-based line of the directive inside the module
+        line: usize,
+        /// Why the source could not be composed
+        reason: CompositionError,
+    },
+    /// The number of buffers does not match the bindings of a shader.
+    #[error("shader `{shader}` group {group} expects {expected} buffers, got {actual}")]
+    BindingCount {
+        /// The label of the shader
+        shader: String,
+        /// The bind group index
+        group: u32,
+        /// The number of buffers the shader expects
+        expected: usize,
+        /// The number of buffers supplied
+        actual: usize,
+    },
+    /// A buffer does not match the shader binding it is bound to.
+    #[error("shader `{shader}` group {group} binding {binding} (`{name}`): {reason}")]
+    InvalidBinding {
+        /// The label of the shader
+        shader: String,
+        /// The bind group index
+        group: u32,
+        /// The binding index
+        binding: u32,
+        /// The name of the WGSL variable
+        name: String,
+        /// Why the buffer does not match
+        reason: BindingError,
+    },
+    /// The shader's entry point has no bindings in the bind group.
+    #[error("shader `{shader}` has no bind group {group}")]
+    InvalidGroup {
+        /// The label of the shader
+        shader: String,
+        /// The bind group index
+        group: u32,
+    },
+    /// The pipeline cache file or directory could not be read or written.
+    #[error("pipeline cache `{}`: {source}", path.display())]
+    PipelineCache {
+        /// The file or directory
+        path: std::path::PathBuf,
+        /// The underlying IO error
+        source: std::io::Error,
+    },
+    /// A buffer range lies beyond the end of the buffer.
+    #[error("range of {len} bytes at offset {offset} exceeds the buffer size of {size} bytes")]
+    OutOfBounds {
+        /// The byte offset of the range
+        offset: u64,
+        /// The length of the range in bytes
+        len: u64,
+        /// The size of the buffer in bytes
+        size: u64,
+    },
+    /// An empty slice was written to a buffer.
+    #[error("cannot update a buffer with empty data")]
+    EmptyData,
+    /// An iteration does not implement the operation.
+    #[error("not implemented: {0}")]
+    NotImplemented(String),
+    /// An iteration was evaluated before its parameters were bound.
+    #[error("{0} called before bind")]
+    NotBound(String),
+    /// An iteration panicked while being evaluated through a fallible call.
+    #[error("iteration `{label}` panicked: {message}")]
+    IterationPanicked {
+        /// The label of the iteration
+        label: String,
+        /// The panic message
+        message: String,
+    },
+    /// A fitness value was rejected by the [`FitnessPolicy`](crate::FitnessPolicy).
+    #[error("fitness value {0} rejected by the fitness policy")]
+    InvalidFitness(f32),
+    /// The thread creating the context panicked.
+    #[error("context creation thread panicked")]
+    ThreadPanicked,
+}
+
+impl Error {
+    /// Runs a function, turning a panic into [`Error::IterationPanicked`]
+    ///
+    /// Lets long unattended runs report a failing step instead of unwinding through the
+    /// caller. The panic hook still runs, so the message is printed as usual.
+    ///
+    /// # Arguments
+    /// * `label` - The label of the failing step in the error
+    /// * `f` - The function to run
+    ///
+    /// # Errors
+    /// Returns [`Error::IterationPanicked`] with the panic message if `f` panics.
+    ///
+    /// # Examples
+    /// ```
+    /// use sgrmath_core::Error;
+    ///
+    /// let result = Error::catch("step", || -> usize { panic!("out of range") });
+    ///
+    /// assert!(matches!(result, Err(Error::IterationPanicked { message, .. }) if message == "out of range"));
+    /// ```
+    pub fn catch<R, F>(label: &str, f: F) -> Result<R, Self>
+    where
+        F: FnOnce() -> R,
+    {
+        std::panic::catch_unwind(std::panic::AssertUnwindSafe(f)).map_err(|payload| {
+            let message = payload
+                .downcast_ref::<&str>()
+                .map(ToString::to_string)
+                .or_else(|| payload.downcast_ref::<String>().cloned())
+                .unwrap_or_else(|| "unknown panic payload".to_string());
+
+            Self::IterationPanicked { label: label.to_string(), message }
+        })
+    }
+}
+
+/// The reason a buffer does not match a shader binding.
+///
+/// Returned inside [`Error::InvalidBinding`].
+#[derive(Clone, Debug, PartialEq, Eq, thiserror::Error)]
+pub enum BindingError {
+    /// The buffer was not created with the usage the address space requires.
+    #[error("buffer usage {actual:?} does not include {required:?}")]
+    MissingUsage {
+        /// The usage the binding requires
+        required: wgpu::BufferUsages,
+        /// The usages of the buffer
+        actual: wgpu::BufferUsages,
+    },
+    /// The bound range is smaller than the variable.
+    #[error("buffer binds {size} bytes, at least {min_size} required")]
+    TooSmall {
+        /// The number of bytes bound
+        size: u64,
+        /// The minimum number of bytes the variable needs
+        min_size: u64,
+    },
+    /// The buffer is bound to another binding and one of them is written.
+    #[error("buffer is also bound to binding {other} and one of them is read_write")]
+    Aliased {
+        /// The other binding index
+        other: u32,
+    },
+}
+
+/// The reason the WGSL modules of a shader could not be composed.
+///
+/// Returned inside [`Error::ShaderComposition`].
+#[derive(Clone, Debug, PartialEq, Eq, thiserror::Error)]
+pub enum CompositionError {
+    /// The directive is not one of `include`, `ifdef`, `ifndef`, `else` and `endif`.
+    #[error("unknown directive `#{0}`")]
+    UnknownDirective(String),
+    /// The directive requires a name.
+    #[error("directive `#{0}` requires a name")]
+    MissingArgument(String),
+    /// An `#else` or `#endif` has no matching `#ifdef` or `#ifndef`.
+    #[error("unexpected `#{0}`")]
+    UnexpectedDirective(String),
+    /// An `#ifdef` or `#ifndef` is not closed by an `#endif`.
+    #[error("missing `#endif`")]
+    UnterminatedConditional,
+    /// The included module was not registered.
+    #[error("unknown include `{0}`")]
+    UnknownInclude(String),
+}
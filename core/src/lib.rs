@@ -20,16 +20,28 @@
 //! ```
 
 mod buffers;
+mod error;
 mod model;
+mod profiler;
+mod random;
 mod shader;
 mod wgpu_context;
 
 // Re-export main types
-pub use wgpu_context::WgpuContext;
+pub use error::{BindingError, CompositionError, Error};
+pub use wgpu_context::{AdapterReport, WgpuContext, WgpuContextBuilder};
 
 // Re-export buffer types
 pub use buffers::{
+    Bindable,
+    BufferPool,
+    Lease,
+    ReadbackBatch,
+    ReadbackBatchFuture,
     ReadbackBuffer,
+    ReadbackFuture,
+    ReadbackResults,
+    ReadbackSlot,
     StorageBuffer,
     ValueBuffer,
 };
@@ -38,18 +50,40 @@ pub use buffers::{
 pub use model::{
     Size,
     OptimizationDirection,
+    FitnessPolicy,
+    ConstraintHandling,
+    NanPolicy,
+    InfinityPolicy,
+    Schedule,
     ProblemParams,
     CpuProblem,
     ShaderProblem,
     Iteration,
+    TryIteration,
+    Fallible,
     Compiled,
     CompiledIteration,
     NotImplementedIteration,
     CombinedIteration,
+    EveryN,
+    FirstN,
+    AfterN,
+    When,
+    Sequence,
+    MapParams,
+    Executor,
+    Submission,
+    Profiled,
     IterationSize,
     Sliced,
     SlicedIteration,
 };
 
+// Re-export profiling types
+pub use profiler::{ProfileEntry, ProfileKind, ProfileReport, ProfileScope, Profiler};
+
+// Re-export random number types
+pub use random::{philox4x32, Random, RandomKey, RandomStream, RandomStreams};
+
 // Re-export shader types
-pub use shader::Shader;
+pub use shader::{Access, AddressSpace, BindingInfo, Shader, ShaderBuilder, ShaderCache, ShaderConstant};
@@ -0,0 +1,126 @@
+use std::cmp::Ordering;
+
+use crate::{FitnessPolicy, OptimizationDirection};
+
+/// How constraint violations rank solutions.
+///
+/// A violation is a non-negative measure of how much a solution breaks the constraints
+/// of a problem, zero for feasible solutions. NaN violations are infeasible and worse
+/// than every other violation.
+///
+/// # Examples
+/// ```
+/// use std::cmp::Ordering;
+/// use sgrmath_core::{ConstraintHandling, FitnessPolicy, OptimizationDirection};
+///
+/// let (direction, policy) = (OptimizationDirection::Minimize, FitnessPolicy::default());
+///
+/// // A feasible solution beats an infeasible one, whatever their fitness
+/// assert_eq!(
+///     ConstraintHandling::Feasibility.total_cmp(&direction, (10.0, 0.0), (1.0, 0.5), &policy),
+///     Ordering::Less
+/// );
+/// // A penalty adds the weighted violation to the fitness
+/// assert_eq!(ConstraintHandling::Penalty(4.0).penalized(&direction, 1.0, 0.5), 3.0);
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum ConstraintHandling {
+    /// Deb's feasibility rules
+    ///
+    /// A feasible solution is better than an infeasible one, two feasible solutions are
+    /// compared by fitness and two infeasible ones by violation.
+    #[default]
+    Feasibility,
+    /// Worsens the fitness by the violation multiplied by the coefficient
+    Penalty(f32),
+}
+
+impl ConstraintHandling {
+    /// Returns true if a violation belongs to a feasible solution
+    #[must_use]
+    pub fn is_feasible(violation: f32) -> bool {
+        violation <= 0.0
+    }
+
+    /// Returns the fitness worsened by the penalty of the violation
+    ///
+    /// # Arguments
+    /// * `direction` - The optimization direction of the fitness
+    /// * `result` - The fitness value
+    /// * `violation` - The constraint violation
+    ///
+    /// # Returns
+    /// The penalized fitness for [`ConstraintHandling::Penalty`], the fitness itself for
+    /// feasible solutions and for [`ConstraintHandling::Feasibility`]
+    #[must_use]
+    pub fn penalized(self, direction: &OptimizationDirection, result: f32, violation: f32) -> f32 {
+        match self {
+            Self::Penalty(coefficient) if !Self::is_feasible(violation) => match direction {
+                OptimizationDirection::Minimize => coefficient.mul_add(violation, result),
+                OptimizationDirection::Maximize => (-coefficient).mul_add(violation, result),
+            },
+            _ => result,
+        }
+    }
+
+    /// Compares two `(fitness, violation)` pairs in a total order, the better pair being "less"
+    ///
+    /// # Arguments
+    /// * `direction` - The optimization direction of the fitness
+    /// * `a` - The fitness and violation of the first solution
+    /// * `b` - The fitness and violation of the second solution
+    /// * `policy` - How NaN and infinite fitness values are ordered
+    ///
+    /// # Returns
+    /// * `Less` if `a` is better than `b`
+    /// * `Equal` if `a` is as good as `b`
+    /// * `Greater` if `a` is worse than `b`
+    #[must_use]
+    pub fn total_cmp(
+        self,
+        direction: &OptimizationDirection,
+        a: (f32, f32),
+        b: (f32, f32),
+        policy: &FitnessPolicy,
+    ) -> Ordering {
+        match self {
+            Self::Feasibility => match (Self::is_feasible(a.1), Self::is_feasible(b.1)) {
+                (true, true) => direction.total_cmp(&a.0, &b.0, policy),
+                (true, false) => Ordering::Less,
+                (false, true) => Ordering::Greater,
+                (false, false) => OptimizationDirection::Minimize.compare(&a.1, &b.1),
+            },
+            Self::Penalty(_) => direction.total_cmp(
+                &self.penalized(direction, a.0, a.1),
+                &self.penalized(direction, b.0, b.1),
+                policy,
+            ),
+        }
+    }
+
+    /// Finds the best of the `(fitness, violation)` pairs
+    ///
+    /// # Arguments
+    /// * `direction` - The optimization direction of the fitness
+    /// * `values` - The fitness and violation of each solution
+    /// * `policy` - How NaN and infinite fitness values are ordered
+    ///
+    /// # Returns
+    /// The index and fitness of the first best solution, or `None` if there are no values
+    #[must_use]
+    pub fn best_of<I>(self, direction: &OptimizationDirection, values: I, policy: &FitnessPolicy) -> Option<(usize, f32)>
+    where
+        I: IntoIterator<Item = (f32, f32)>,
+    {
+        values
+            .into_iter()
+            .enumerate()
+            .reduce(|best, value| {
+                match self.total_cmp(direction, value.1, best.1, policy) {
+                    Ordering::Less => value,
+                    _ => best,
+                }
+            })
+            .map(|(index, (result, _))| (index, result))
+    }
+}
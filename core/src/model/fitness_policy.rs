@@ -0,0 +1,88 @@
+use crate::Error;
+
+/// How NaN fitness values are ordered.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum NanPolicy {
+    /// NaN is worse than every other value
+    #[default]
+    Worst,
+    /// NaN is better than every other value
+    Best,
+    /// NaN is rejected by [`FitnessPolicy::check`] and ordered as the worst value
+    Error,
+}
+
+/// How infinite fitness values are ordered.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum InfinityPolicy {
+    /// Infinities are ordinary values, e.g. `-inf` is the best value when minimizing
+    #[default]
+    Ordinary,
+    /// Infinities of either sign are worse than every finite value and better than NaN
+    /// ordered as the worst value, e.g. overflowed results
+    Worst,
+    /// Infinities are rejected by [`FitnessPolicy::check`] and ordered as the worst values
+    Error,
+}
+
+/// How fitness values that are not finite numbers are ordered.
+///
+/// Used by the comparisons of [`OptimizationDirection`](crate::OptimizationDirection), which
+/// order every value, so a single NaN from a problem never breaks a sort. The default orders
+/// NaN as the worst value and infinities as ordinary values.
+///
+/// # Examples
+/// ```
+/// use sgrmath_core::{FitnessPolicy, NanPolicy, OptimizationDirection};
+///
+/// let policy = FitnessPolicy { nan: NanPolicy::Error, ..FitnessPolicy::default() };
+///
+/// assert!(policy.check(f32::NAN).is_err());
+/// assert!(OptimizationDirection::Minimize.is_better(&1.0, &f32::NAN, &policy));
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct FitnessPolicy {
+    /// How NaN values are ordered
+    pub nan: NanPolicy,
+    /// How infinite values are ordered
+    pub infinity: InfinityPolicy,
+}
+
+/// The rank of a value before its magnitude is compared, lower ranks are better
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Rank {
+    Best,
+    Ordinary,
+    /// Infinities worse than every finite value
+    Infinite,
+    Worst,
+}
+
+impl FitnessPolicy {
+    /// Checks that a fitness value is accepted by the policy
+    ///
+    /// # Errors
+    /// Returns [`Error::InvalidFitness`] for NaN under [`NanPolicy::Error`] and for
+    /// infinities under [`InfinityPolicy::Error`].
+    pub fn check(self, value: f32) -> Result<(), Error> {
+        let rejected = (value.is_nan() && self.nan == NanPolicy::Error)
+            || (value.is_infinite() && self.infinity == InfinityPolicy::Error);
+
+        if rejected { Err(Error::InvalidFitness(value)) } else { Ok(()) }
+    }
+
+    /// Returns the rank of a value
+    pub(crate) fn rank(self, value: f32) -> Rank {
+        if value.is_nan() {
+            return match self.nan {
+                NanPolicy::Best => Rank::Best,
+                NanPolicy::Worst | NanPolicy::Error => Rank::Worst,
+            };
+        }
+
+        match self.infinity {
+            InfinityPolicy::Worst | InfinityPolicy::Error if value.is_infinite() => Rank::Infinite,
+            _ => Rank::Ordinary,
+        }
+    }
+}
@@ -5,8 +5,8 @@ use crate::{Iteration, WgpuContext};
 /// A container that combines multiple iterations to be executed in parallel.
 /// 
 /// CombinedIteration allows you to run multiple iterations concurrently, collecting
-/// their command buffers and managing synchronization. All iterations are executed
-/// asynchronously, and the container ensures proper synchronization when needed.
+/// their command buffers in order. The synchronous methods submit them at once and wait
+/// for the GPU; the asynchronous methods return them, e.g. to an [`Executor`](crate::Executor).
 /// 
 /// # Examples
 /// ```
@@ -104,12 +104,11 @@ where
     /// 
     /// This method:
     /// 1. Collects command buffers from all iterations asynchronously
-    /// 2. Waits for all operations to complete
-    /// 
-    /// Note: While this method is synchronous, the underlying iterations
-    /// are still executed asynchronously.
+    /// 2. Submits them in one submission
+    /// 3. Waits for all operations to complete
     fn evaluate(&mut self) {
-        self.evaluate_async();
+        let commands = self.evaluate_async();
+        self.context.queue.submit(commands);
         self.context.device.poll(wgpu::MaintainBase::Wait).unwrap();
     }
     
@@ -117,8 +116,7 @@ where
     /// 
     /// This method:
     /// 1. Collects command buffers from all iterations
-    /// 2. Waits for any pending operations to complete
-    /// 3. Returns the collected command buffers
+    /// 2. Returns the collected command buffers
     /// 
     /// The iterations are executed in parallel, and their command buffers
     /// are combined into a single vector.
@@ -128,7 +126,6 @@ where
             result.extend(iteration.evaluate_async());
         }
 
-        self.context.device.poll(wgpu::MaintainBase::Wait).unwrap();
         result
     }
 
@@ -136,12 +133,11 @@ where
     /// 
     /// This method:
     /// 1. Collects command buffers from all iterations asynchronously
-    /// 2. Waits for all operations to complete
-    /// 
-    /// Note: While this method is synchronous, the underlying iterations
-    /// are still executed asynchronously.
+    /// 2. Submits them in one submission
+    /// 3. Waits for all operations to complete
     fn evaluate_with_params(&mut self, params: &T) {
-        self.evaluate_with_params_async(params);
+        let commands = self.evaluate_with_params_async(params);
+        self.context.queue.submit(commands);
         self.context.device.poll(wgpu::MaintainBase::Wait).unwrap();
     }
     
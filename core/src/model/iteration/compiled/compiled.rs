@@ -1,3 +1,17 @@
+/// Options compiling an iteration for the parameters it is bound to.
+///
+/// Used by [`CompiledIteration`](crate::CompiledIteration), which compiles the iteration lazily.
 pub trait Compiled<P, I> {
+    /// Compiles an iteration for the parameters
     fn compile(&self, params: &P) -> I;
-}
\ No newline at end of file
+
+    /// Returns the parts of the parameters a compiled iteration depends on
+    ///
+    /// An iteration compiled for parameters with one key cannot evaluate parameters with
+    /// another, e.g. because it sized a buffer by the number of solutions, so
+    /// [`CompiledIteration`](crate::CompiledIteration) compiles another one. The default key
+    /// is empty: one iteration evaluates all parameters.
+    fn compile_key(&self, _params: &P) -> Vec<usize> {
+        vec![]
+    }
+}
@@ -1,15 +1,29 @@
 use std::marker::PhantomData;
 
-use crate::model::Iteration;
+use crate::{model::Iteration, Error, TryIteration};
 use super::Compiled;
 
-pub enum CompiledIteration<O, I, P> 
+/// An iteration compiled lazily from its options.
+///
+/// The iteration is compiled on the first `bind` or `evaluate_with_params`. Parameters with
+/// another [`Compiled::compile_key`] get an iteration of their own, so e.g. the slices of a
+/// [`Sliced`](crate::Sliced) iteration with different sizes never share buffers sized for
+/// another slice. Evaluating with the bound parameters uses the iteration compiled for them.
+///
+/// At most two iterations are kept: the one of the bound parameters and the one of the last
+/// other key. Compiling for a new key drops the other iterations with their buffers, so
+/// parameters alternating between keys compile again on every change.
+pub struct CompiledIteration<O, I, P> 
 where
     O: Compiled<P, I>,
     I: Iteration<P>,
 {
-    Options((O, PhantomData<P>)),
-    Iteration(I),
+    options: O,
+    /// The compiled iterations with their keys
+    iterations: Vec<(Vec<usize>, I)>,
+    /// The index of the iteration compiled for the bound parameters
+    bound: Option<usize>,
+    params: PhantomData<P>,
 }
 
 impl<O, I, P> CompiledIteration<O, I, P>
@@ -26,8 +40,68 @@ where
     /// 
     /// # Returns
     /// A new `CompiledIteration` instance
-    pub fn new(options: O) -> Self {
-        Self::Options((options, PhantomData))
+    pub const fn new(options: O) -> Self {
+        Self { options, iterations: Vec::new(), bound: None, params: PhantomData }
+    }
+
+    /// Returns the options the iterations are compiled from
+    #[must_use]
+    pub const fn options(&self) -> &O {
+        &self.options
+    }
+
+    /// Returns the number of iterations compiled so far
+    #[must_use]
+    pub fn compiled_count(&self) -> usize {
+        self.iterations.len()
+    }
+
+    /// Returns the iteration compiled for the bound parameters, if any
+    #[must_use]
+    pub fn iteration(&self) -> Option<&I> {
+        self.bound.map(|index| &self.iterations[index].1)
+    }
+
+    /// Returns the index of the iteration compiled for the parameters, compiling it if needed
+    ///
+    /// A new iteration replaces all but the one of the bound parameters.
+    fn compiled(&mut self, params: &P) -> usize {
+        let key = self.options.compile_key(params);
+        if let Some(index) = self.iterations.iter().position(|(compiled, _)| *compiled == key) {
+            return index;
+        }
+
+        if let Some(index) = self.bound {
+            self.iterations.swap(0, index);
+            self.iterations.truncate(1);
+            self.bound = Some(0);
+        } else {
+            self.iterations.clear();
+        }
+        self.iterations.push((key, self.options.compile(params)));
+        self.iterations.len() - 1
+    }
+
+    /// Returns the iteration compiled for the bound parameters
+    ///
+    /// # Errors
+    /// Returns [`Error::NotBound`] if called before `bind`
+    fn try_bound(&mut self, method: &str) -> Result<&mut I, Error> {
+        let index = self.bound.ok_or_else(|| Error::NotBound(format!("CompiledIteration::{method}")))?;
+
+        Ok(&mut self.iterations[index].1)
+    }
+
+    /// Returns the iteration compiled for the bound parameters
+    ///
+    /// # Panics
+    /// Panics if called before `bind`
+    fn bound(&mut self, method: &str) -> &mut I {
+        match self.try_bound(method) {
+            Ok(iteration) => iteration,
+            #[allow(clippy::panic)]
+            Err(error) => panic!("{error}"),
+        }
     }
 }
 
@@ -38,30 +112,23 @@ where
 {
     /// Binds parameters to the iteration, initializing it if necessary.
     /// 
-    /// If the iteration hasn't been initialized yet, it will be created using the compiled options.
+    /// If no iteration was compiled for the key of the parameters yet, it will be created
+    /// using the compiled options.
     /// 
     /// # Arguments
     /// * `params` - The parameters to bind to the iteration
     fn bind(&mut self, params: &P) {
-        match self {
-            Self::Options((options, _)) => {
-                let iteration = options.compile(params);
-                *self = Self::Iteration(iteration);
-                self.bind(params);
-            }
-            Self::Iteration(iteration) => iteration.bind(params),
-        }
+        let index = self.compiled(params);
+        self.bound = Some(index);
+        self.iterations[index].1.bind(params);
     }
 
     /// Evaluates the iteration using previously bound parameters.
     /// 
     /// # Panics
-    /// Panics if called before `bind` or `evaluate_with_params`
+    /// Panics if called before `bind`
     fn evaluate(&mut self) {
-        match self {
-            Self::Options((_, _)) => panic!("CompiledIteration::evaluate called before bind"),
-            Self::Iteration(iteration) => iteration.evaluate(),
-        }
+        self.bound("evaluate").evaluate();
     }
 
     /// Evaluates the iteration asynchronously using previously bound parameters.
@@ -70,34 +137,27 @@ where
     /// A vector of command buffers from the iteration
     /// 
     /// # Panics
-    /// Panics if called before `bind` or `evaluate_with_params`
+    /// Panics if called before `bind`
     fn evaluate_async(&mut self) -> Vec<wgpu::CommandBuffer> {
-        match self {
-            Self::Options((_, _)) => panic!("CompiledIteration::evaluate_async called before bind"),
-            Self::Iteration(iteration) => iteration.evaluate_async(),
-        }
+        self.bound("evaluate_async").evaluate_async()
     }
 
     /// Evaluates the iteration with explicitly provided parameters.
     /// 
-    /// If the iteration hasn't been initialized yet, it will be created using the compiled options.
+    /// If no iteration was compiled for the key of the parameters yet, it will be created
+    /// using the compiled options.
     /// 
     /// # Arguments
     /// * `params` - The parameters to use for evaluation
     fn evaluate_with_params(&mut self, params: &P) {
-        match self {
-            Self::Options((options, _)) => {
-                let iteration = options.compile(params);
-                *self = Self::Iteration(iteration);
-                self.evaluate_with_params(params);
-            }
-            Self::Iteration(iteration) => iteration.evaluate_with_params(params),
-        }
+        let index = self.compiled(params);
+        self.iterations[index].1.evaluate_with_params(params);
     }
 
     /// Evaluates the iteration asynchronously with explicitly provided parameters.
     /// 
-    /// If the iteration hasn't been initialized yet, it will be created using the compiled options.
+    /// If no iteration was compiled for the key of the parameters yet, it will be created
+    /// using the compiled options.
     /// 
     /// # Arguments
     /// * `params` - The parameters to use for evaluation
@@ -105,13 +165,60 @@ where
     /// # Returns
     /// A vector of command buffers from the iteration
     fn evaluate_with_params_async(&mut self, params: &P) -> Vec<wgpu::CommandBuffer> {
-        match self {
-            Self::Options((options, _)) => {
-                let iteration = options.compile(params);
-                *self = Self::Iteration(iteration);
-                self.evaluate_with_params_async(params)
-            },
-            Self::Iteration(iteration) => iteration.evaluate_with_params_async(params),
-        }
+        let index = self.compiled(params);
+        self.iterations[index].1.evaluate_with_params_async(params)
+    }
+}
+
+impl<O, I, P> TryIteration<P> for CompiledIteration<O, I, P>
+where
+    O: Compiled<P, I>,
+    I: Iteration<P> + TryIteration<P>,
+{
+    /// Binds parameters to the iteration, initializing it if necessary.
+    /// 
+    /// The bound parameters are kept if the compiled iteration fails to bind the new ones.
+    /// 
+    /// # Arguments
+    /// * `params` - The parameters to bind to the iteration
+    fn try_bind(&mut self, params: &P) -> Result<(), Error> {
+        let index = self.compiled(params);
+        self.iterations[index].1.try_bind(params)?;
+        self.bound = Some(index);
+        Ok(())
+    }
+
+    /// Evaluates the iteration using previously bound parameters.
+    /// 
+    /// # Errors
+    /// Returns [`Error::NotBound`] if called before `bind`
+    fn try_evaluate(&mut self) -> Result<(), Error> {
+        self.try_bound("try_evaluate")?.try_evaluate()
+    }
+
+    /// Evaluates the iteration asynchronously using previously bound parameters.
+    /// 
+    /// # Errors
+    /// Returns [`Error::NotBound`] if called before `bind`
+    fn try_evaluate_async(&mut self) -> Result<Vec<wgpu::CommandBuffer>, Error> {
+        self.try_bound("try_evaluate_async")?.try_evaluate_async()
+    }
+
+    /// Evaluates the iteration with explicitly provided parameters.
+    /// 
+    /// # Arguments
+    /// * `params` - The parameters to use for evaluation
+    fn try_evaluate_with_params(&mut self, params: &P) -> Result<(), Error> {
+        let index = self.compiled(params);
+        self.iterations[index].1.try_evaluate_with_params(params)
+    }
+
+    /// Evaluates the iteration asynchronously with explicitly provided parameters.
+    /// 
+    /// # Arguments
+    /// * `params` - The parameters to use for evaluation
+    fn try_evaluate_with_params_async(&mut self, params: &P) -> Result<Vec<wgpu::CommandBuffer>, Error> {
+        let index = self.compiled(params);
+        self.iterations[index].1.try_evaluate_with_params_async(params)
     }
 }
@@ -0,0 +1,217 @@
+use crate::Iteration;
+
+/// Declares an iteration running the wrapped one only on some of its evaluations.
+///
+/// The wrapper counts every `evaluate*` call, whether the wrapped iteration runs or not,
+/// and decides from the limit `n` and the number of previous calls. Binding is always
+/// forwarded and does not count.
+macro_rules! counted_iteration {
+    ($(#[$meta:meta])* $name:ident, |$n:ident, $count:ident| $runs:expr) => {
+        $(#[$meta])*
+        pub struct $name<I> {
+            iteration: I,
+            n: usize,
+            count: usize,
+        }
+
+        impl<I> $name<I> {
+            /// Wraps an iteration
+            ///
+            /// # Arguments
+            /// * `iteration` - The wrapped iteration
+            /// * `n` - The number of evaluations the condition depends on
+            pub const fn new(iteration: I, n: usize) -> Self {
+                Self { iteration, n, count: 0 }
+            }
+
+            /// Returns the number of evaluations so far, including the skipped ones
+            pub const fn count(&self) -> usize {
+                self.count
+            }
+
+            /// Starts counting the evaluations from zero again
+            pub fn reset(&mut self) {
+                self.count = 0;
+            }
+
+            /// Returns the wrapped iteration
+            pub fn into_inner(self) -> I {
+                self.iteration
+            }
+
+            /// Counts an evaluation and returns true if the wrapped iteration runs
+            fn next(&mut self) -> bool {
+                let ($n, $count) = (self.n, self.count);
+                self.count += 1;
+                $runs
+            }
+        }
+
+        impl<T, I> Iteration<T> for $name<I>
+        where
+            I: Iteration<T>,
+        {
+            fn bind(&mut self, params: &T) {
+                self.iteration.bind(params);
+            }
+
+            fn evaluate(&mut self) {
+                if self.next() {
+                    self.iteration.evaluate();
+                }
+            }
+
+            fn evaluate_async(&mut self) -> Vec<wgpu::CommandBuffer> {
+                if self.next() { self.iteration.evaluate_async() } else { vec![] }
+            }
+
+            fn evaluate_with_params(&mut self, params: &T) {
+                if self.next() {
+                    self.iteration.evaluate_with_params(params);
+                }
+            }
+
+            fn evaluate_with_params_async(&mut self, params: &T) -> Vec<wgpu::CommandBuffer> {
+                if self.next() { self.iteration.evaluate_with_params_async(params) } else { vec![] }
+            }
+        }
+    };
+}
+
+counted_iteration!(
+    /// An iteration running the wrapped one on every `n`-th evaluation.
+    ///
+    /// The wrapped iteration runs on evaluations `n`, `2n`, `3n`, ..., counting from one,
+    /// e.g. a migration between islands every ten generations. With `n` of zero it never runs.
+    ///
+    /// # Examples
+    /// ```
+    /// use sgrmath_core::{EveryN, Iteration, NotImplementedIteration};
+    ///
+    /// let mut iteration = EveryN::new(NotImplementedIteration::new("Migration"), 10);
+    /// for _ in 0..9 {
+    ///     // Skipped, the wrapped iteration would panic
+    ///     Iteration::<usize>::evaluate(&mut iteration);
+    /// }
+    ///
+    /// assert_eq!(iteration.count(), 9);
+    /// ```
+    EveryN, |n, count| n > 0 && (count + 1) % n == 0
+);
+
+counted_iteration!(
+    /// An iteration running the wrapped one on the first `n` evaluations only.
+    ///
+    /// Useful for warm-up steps, e.g. a local search applied to the initial generations.
+    ///
+    /// # Examples
+    /// ```
+    /// use sgrmath_core::{FirstN, Iteration, NotImplementedIteration};
+    ///
+    /// let mut iteration = FirstN::new(NotImplementedIteration::new("Warm-up"), 0);
+    ///
+    /// // Skipped, the wrapped iteration would panic
+    /// Iteration::<usize>::evaluate(&mut iteration);
+    /// ```
+    FirstN, |n, count| count < n
+);
+
+counted_iteration!(
+    /// An iteration skipping the first `n` evaluations and running the wrapped one afterwards.
+    ///
+    /// The complement of [`FirstN`], e.g. an operator enabled once the population settled.
+    ///
+    /// # Examples
+    /// ```
+    /// use sgrmath_core::{AfterN, Iteration, NotImplementedIteration};
+    ///
+    /// let mut iteration = AfterN::new(NotImplementedIteration::new("Refinement"), 100);
+    ///
+    /// // Skipped, the wrapped iteration would panic
+    /// Iteration::<usize>::evaluate(&mut iteration);
+    /// ```
+    AfterN, |n, count| count >= n
+);
+
+/// An iteration running the wrapped one only when a predicate on the parameters holds.
+///
+/// Evaluations with explicit parameters test them, evaluations with the bound parameters
+/// test the ones bound last, so the parameters must be cloneable. The predicate sees the
+/// parameters before the wrapped iteration runs.
+///
+/// # Examples
+/// ```
+/// use sgrmath_core::{Iteration, NotImplementedIteration, When};
+///
+/// let mut iteration = When::new(NotImplementedIteration::new("Restart"), |generation: &usize| *generation > 1000);
+///
+/// // Skipped, the wrapped iteration would panic
+/// iteration.evaluate_with_params(&10);
+/// ```
+pub struct When<I, T, F> {
+    iteration: I,
+    predicate: F,
+    params: Option<T>,
+}
+
+impl<I, T, F> When<I, T, F> {
+    /// Wraps an iteration
+    ///
+    /// # Arguments
+    /// * `iteration` - The wrapped iteration
+    /// * `predicate` - Returns true if the wrapped iteration runs with the parameters
+    pub const fn new(iteration: I, predicate: F) -> Self {
+        Self { iteration, predicate, params: None }
+    }
+
+    /// Returns the wrapped iteration
+    pub fn into_inner(self) -> I {
+        self.iteration
+    }
+}
+
+impl<I, T, F> When<I, T, F>
+where
+    F: FnMut(&T) -> bool,
+{
+    /// Tests the bound parameters
+    ///
+    /// # Panics
+    /// Panics if no parameters were bound.
+    fn bound_holds(&mut self) -> bool {
+        let params = self.params.as_ref().expect("When evaluated before the parameters were bound");
+        (self.predicate)(params)
+    }
+}
+
+impl<I, T, F> Iteration<T> for When<I, T, F>
+where
+    I: Iteration<T>,
+    T: Clone,
+    F: FnMut(&T) -> bool,
+{
+    fn bind(&mut self, params: &T) {
+        self.params = Some(params.clone());
+        self.iteration.bind(params);
+    }
+
+    fn evaluate(&mut self) {
+        if self.bound_holds() {
+            self.iteration.evaluate();
+        }
+    }
+
+    fn evaluate_async(&mut self) -> Vec<wgpu::CommandBuffer> {
+        if self.bound_holds() { self.iteration.evaluate_async() } else { vec![] }
+    }
+
+    fn evaluate_with_params(&mut self, params: &T) {
+        if (self.predicate)(params) {
+            self.iteration.evaluate_with_params(params);
+        }
+    }
+
+    fn evaluate_with_params_async(&mut self, params: &T) -> Vec<wgpu::CommandBuffer> {
+        if (self.predicate)(params) { self.iteration.evaluate_with_params_async(params) } else { vec![] }
+    }
+}
@@ -0,0 +1,163 @@
+use crate::{Error, Iteration, TryIteration, WgpuContext};
+
+/// Gathers the command buffers of iterations and submits them at once.
+///
+/// `evaluate_async` of an iteration returns the command buffers of its whole tree, e.g. of
+/// every slice of a [`SlicedIteration`](crate::SlicedIteration) or every iteration of a
+/// [`CombinedIteration`](crate::CombinedIteration). The executor collects them in evaluation
+/// order and [`Executor::submit`] passes them to a single `queue.submit`, returning a
+/// [`Submission`] to wait for.
+///
+/// Iterations doing CPU work in `evaluate_async` follow the queue order instead:
+/// - `queue.write_buffer` calls take effect before every command buffer of the next
+///   submission, so writes that must follow a gathered dispatch need a `submit` in between.
+/// - Reading buffers back observes only the submitted work, so iterations reading the output
+///   of gathered dispatches must be evaluated after a `submit`.
+///
+/// Command buffers still gathered when the executor is dropped are discarded.
+///
+/// # Examples
+/// ```no_run
+/// use sgrmath_core::{Error, Executor, Iteration, WgpuContext};
+///
+/// fn generation(
+///     context: &WgpuContext,
+///     crossover: &mut dyn Iteration<()>,
+///     problem: &mut dyn Iteration<()>,
+/// ) -> Result<(), Error> {
+///     Executor::new(context)
+///         .evaluate(crossover)
+///         .evaluate(problem)
+///         .submit()
+///         .wait()
+/// }
+/// ```
+#[derive(Debug)]
+#[must_use = "gathered command buffers are discarded unless submitted"]
+pub struct Executor {
+    context: WgpuContext,
+    commands: Vec<wgpu::CommandBuffer>,
+}
+
+/// A submitted batch of command buffers.
+///
+/// Returned by [`Executor::submit`]. Dropping it does not cancel the work; later
+/// submissions and readbacks are ordered after it anyway.
+#[derive(Debug)]
+pub struct Submission {
+    device: wgpu::Device,
+    index: wgpu::SubmissionIndex,
+}
+
+impl Executor {
+    /// Creates an executor with no gathered command buffers
+    ///
+    /// # Arguments
+    /// * `context` - The WGPU context whose queue the command buffers are submitted to
+    pub fn new(context: &WgpuContext) -> Self {
+        Self { context: context.clone(), commands: Vec::new() }
+    }
+
+    /// Gathers the command buffers of an iteration using its bound parameters
+    ///
+    /// # Returns
+    /// `&mut Self` for method chaining
+    pub fn evaluate<T, I>(&mut self, iteration: &mut I) -> &mut Self
+    where
+        I: Iteration<T> + ?Sized,
+    {
+        self.commands.extend(iteration.evaluate_async());
+        self
+    }
+
+    /// Gathers the command buffers of an iteration evaluated with explicit parameters
+    ///
+    /// # Returns
+    /// `&mut Self` for method chaining
+    pub fn evaluate_with_params<T, I>(&mut self, iteration: &mut I, params: &T) -> &mut Self
+    where
+        I: Iteration<T> + ?Sized,
+    {
+        self.commands.extend(iteration.evaluate_with_params_async(params));
+        self
+    }
+
+    /// Gathers the command buffers of a fallible iteration using its bound parameters
+    ///
+    /// # Returns
+    /// `&mut Self` for method chaining
+    ///
+    /// # Errors
+    /// Returns the error of the iteration, no command buffer of it is gathered then.
+    pub fn try_evaluate<T, I>(&mut self, iteration: &mut I) -> Result<&mut Self, Error>
+    where
+        I: TryIteration<T> + ?Sized,
+    {
+        self.commands.extend(iteration.try_evaluate_async()?);
+        Ok(self)
+    }
+
+    /// Gathers the command buffers of a fallible iteration evaluated with explicit parameters
+    ///
+    /// # Returns
+    /// `&mut Self` for method chaining
+    ///
+    /// # Errors
+    /// Returns the error of the iteration, no command buffer of it is gathered then.
+    pub fn try_evaluate_with_params<T, I>(&mut self, iteration: &mut I, params: &T) -> Result<&mut Self, Error>
+    where
+        I: TryIteration<T> + ?Sized,
+    {
+        self.commands.extend(iteration.try_evaluate_with_params_async(params)?);
+        Ok(self)
+    }
+
+    /// Gathers command buffers encoded elsewhere, e.g. by [`Shader::execute_async`](crate::Shader::execute_async)
+    ///
+    /// # Returns
+    /// `&mut Self` for method chaining
+    pub fn push<C>(&mut self, commands: C) -> &mut Self
+    where
+        C: IntoIterator<Item = wgpu::CommandBuffer>,
+    {
+        self.commands.extend(commands);
+        self
+    }
+
+    /// Returns the number of gathered command buffers
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.commands.len()
+    }
+
+    /// Returns true if no command buffer is gathered
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.commands.is_empty()
+    }
+
+    /// Submits the gathered command buffers in one `queue.submit` and clears them
+    ///
+    /// The executor can be reused for the next batch.
+    pub fn submit(&mut self) -> Submission {
+        let index = self.context.queue.submit(self.commands.drain(..));
+        Submission { device: self.context.device.clone(), index }
+    }
+}
+
+impl Submission {
+    /// Returns the index of the submission on the queue
+    #[must_use]
+    pub const fn index(&self) -> &wgpu::SubmissionIndex {
+        &self.index
+    }
+
+    /// Blocks until the GPU has finished the submitted work
+    ///
+    /// # Errors
+    /// Returns [`Error::Poll`] if the device is lost or the wait times out.
+    pub fn wait(&self) -> Result<(), Error> {
+        self.device.poll(wgpu::PollType::wait_for(self.index.clone()))?;
+        Ok(())
+    }
+}
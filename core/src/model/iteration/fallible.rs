@@ -0,0 +1,73 @@
+use crate::{Error, Iteration, TryIteration};
+
+/// An adapter turning an [`Iteration`] into a [`TryIteration`].
+///
+/// Panics of the wrapped iteration, e.g. an evaluation before `bind` or an out-of-bounds
+/// buffer write, are caught and returned as [`Error::IterationPanicked`] labelled with
+/// the label of the adapter. The wrapped iteration may be left half-evaluated, so after
+/// an error it should be bound again or dropped.
+///
+/// # Examples
+/// ```
+/// use sgrmath_core::{Error, Fallible, NotImplementedIteration, TryIteration};
+///
+/// let mut iteration = Fallible::new("crossover", NotImplementedIteration::new("crossover"));
+///
+/// assert!(matches!(
+///     TryIteration::<usize>::try_evaluate(&mut iteration),
+///     Err(Error::IterationPanicked { label, .. }) if label == "crossover"
+/// ));
+/// ```
+pub struct Fallible<I> {
+    label: String,
+    iteration: I,
+}
+
+impl<I> Fallible<I> {
+    /// Wraps an iteration
+    ///
+    /// # Arguments
+    /// * `label` - The label of the iteration in the errors
+    /// * `iteration` - The wrapped iteration
+    pub fn new<L>(label: L, iteration: I) -> Self
+    where
+        L: Into<String>,
+    {
+        Self { label: label.into(), iteration }
+    }
+
+    /// Returns the label of the iteration in the errors
+    pub fn label(&self) -> &str {
+        &self.label
+    }
+
+    /// Returns the wrapped iteration
+    pub fn into_inner(self) -> I {
+        self.iteration
+    }
+}
+
+impl<T, I> TryIteration<T> for Fallible<I>
+where
+    I: Iteration<T>,
+{
+    fn try_bind(&mut self, params: &T) -> Result<(), Error> {
+        Error::catch(&self.label, || self.iteration.bind(params))
+    }
+
+    fn try_evaluate(&mut self) -> Result<(), Error> {
+        Error::catch(&self.label, || self.iteration.evaluate())
+    }
+
+    fn try_evaluate_async(&mut self) -> Result<Vec<wgpu::CommandBuffer>, Error> {
+        Error::catch(&self.label, || self.iteration.evaluate_async())
+    }
+
+    fn try_evaluate_with_params(&mut self, params: &T) -> Result<(), Error> {
+        Error::catch(&self.label, || self.iteration.evaluate_with_params(params))
+    }
+
+    fn try_evaluate_with_params_async(&mut self, params: &T) -> Result<Vec<wgpu::CommandBuffer>, Error> {
+        Error::catch(&self.label, || self.iteration.evaluate_with_params_async(params))
+    }
+}
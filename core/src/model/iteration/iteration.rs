@@ -273,3 +273,28 @@ pub trait Iteration<T> {
     /// ```
     fn evaluate_with_params_async(&mut self, params: &T) -> Vec<wgpu::CommandBuffer>;
 }
+
+impl<T, I> Iteration<T> for Box<I>
+where
+    I: Iteration<T> + ?Sized,
+{
+    fn bind(&mut self, params: &T) {
+        (**self).bind(params);
+    }
+
+    fn evaluate(&mut self) {
+        (**self).evaluate();
+    }
+
+    fn evaluate_async(&mut self) -> Vec<wgpu::CommandBuffer> {
+        (**self).evaluate_async()
+    }
+
+    fn evaluate_with_params(&mut self, params: &T) {
+        (**self).evaluate_with_params(params);
+    }
+
+    fn evaluate_with_params_async(&mut self, params: &T) -> Vec<wgpu::CommandBuffer> {
+        (**self).evaluate_with_params_async(params)
+    }
+}
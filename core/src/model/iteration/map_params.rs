@@ -0,0 +1,74 @@
+use std::marker::PhantomData;
+
+use crate::Iteration;
+
+/// An adapter turning an `Iteration<A>` into an `Iteration<B>`.
+///
+/// Every call projects the parameters of type `B` to the `A` the wrapped iteration
+/// expects, e.g. the parameters of an optimizer to the [`ProblemParams`](crate::ProblemParams)
+/// of the problem it solves. Evaluating with the bound parameters forwards to the wrapped
+/// iteration, which keeps the projection of the parameters bound last.
+///
+/// # Examples
+/// ```
+/// use std::ops::Range;
+///
+/// use sgrmath_core::{Iteration, MapParams, NotImplementedIteration, Sequence};
+///
+/// struct Params {
+///     range: Range<usize>,
+/// }
+///
+/// let _sequence = Sequence::<Params>::new()
+///     .add(Box::new(MapParams::new(
+///         NotImplementedIteration::new("Range"),
+///         |params: &Params| params.range.clone(),
+///     )));
+/// ```
+pub struct MapParams<I, A, F> {
+    iteration: I,
+    projection: F,
+    params: PhantomData<fn() -> A>,
+}
+
+impl<I, A, F> MapParams<I, A, F> {
+    /// Wraps an iteration
+    ///
+    /// # Arguments
+    /// * `iteration` - The wrapped iteration
+    /// * `projection` - Maps the parameters of the adapter to those of the iteration
+    pub const fn new(iteration: I, projection: F) -> Self {
+        Self { iteration, projection, params: PhantomData }
+    }
+
+    /// Returns the wrapped iteration
+    pub fn into_inner(self) -> I {
+        self.iteration
+    }
+}
+
+impl<A, B, I, F> Iteration<B> for MapParams<I, A, F>
+where
+    I: Iteration<A>,
+    F: Fn(&B) -> A,
+{
+    fn bind(&mut self, params: &B) {
+        self.iteration.bind(&(self.projection)(params));
+    }
+
+    fn evaluate(&mut self) {
+        self.iteration.evaluate();
+    }
+
+    fn evaluate_async(&mut self) -> Vec<wgpu::CommandBuffer> {
+        self.iteration.evaluate_async()
+    }
+
+    fn evaluate_with_params(&mut self, params: &B) {
+        self.iteration.evaluate_with_params(&(self.projection)(params));
+    }
+
+    fn evaluate_with_params_async(&mut self, params: &B) -> Vec<wgpu::CommandBuffer> {
+        self.iteration.evaluate_with_params_async(&(self.projection)(params))
+    }
+}
@@ -2,12 +2,26 @@ mod compiled;
 mod problem;
 mod sliced;
 mod combined;
+mod conditional;
+mod executor;
+mod fallible;
 mod iteration;
+mod map_params;
 mod not_implemented;
+mod profiled;
+mod sequence;
+mod try_iteration;
 
 pub use compiled::{Compiled, CompiledIteration};
 pub use combined::CombinedIteration;
+pub use conditional::{AfterN, EveryN, FirstN, When};
+pub use executor::{Executor, Submission};
+pub use fallible::Fallible;
 pub use problem::{CpuProblem, ProblemParams, ShaderProblem};
 pub use iteration::Iteration;
+pub use map_params::MapParams;
 pub use not_implemented::NotImplementedIteration;
+pub use profiled::Profiled;
+pub use sequence::Sequence;
 pub use sliced::{IterationSize, Sliced, SlicedIteration};
+pub use try_iteration::TryIteration;
@@ -1,3 +1,5 @@
+use crate::{Error, TryIteration};
+
 use super::Iteration;
 
 pub struct NotImplementedIteration(String);
@@ -17,4 +19,12 @@ impl<T> Iteration<T> for NotImplementedIteration {
     fn evaluate_with_params(&mut self, _params: &T) { panic!("{}", self.0); }
     fn evaluate_async(&mut self) -> Vec<wgpu::CommandBuffer> { panic!("{}", self.0); }
     fn evaluate_with_params_async(&mut self, _params: &T) -> Vec<wgpu::CommandBuffer> { panic!("{}", self.0); }
-}
\ No newline at end of file
+}
+
+impl<T> TryIteration<T> for NotImplementedIteration {
+    fn try_bind(&mut self, _params: &T) -> Result<(), Error> { Err(Error::NotImplemented(self.0.clone())) }
+    fn try_evaluate(&mut self) -> Result<(), Error> { Err(Error::NotImplemented(self.0.clone())) }
+    fn try_evaluate_with_params(&mut self, _params: &T) -> Result<(), Error> { Err(Error::NotImplemented(self.0.clone())) }
+    fn try_evaluate_async(&mut self) -> Result<Vec<wgpu::CommandBuffer>, Error> { Err(Error::NotImplemented(self.0.clone())) }
+    fn try_evaluate_with_params_async(&mut self, _params: &T) -> Result<Vec<wgpu::CommandBuffer>, Error> { Err(Error::NotImplemented(self.0.clone())) }
+}
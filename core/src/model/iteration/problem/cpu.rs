@@ -1,4 +1,4 @@
-use crate::{Iteration, ReadbackBuffer};
+use crate::Iteration;
 
 use super::ProblemParams;
 
@@ -46,14 +46,17 @@ where
     T: bytemuck::Pod,
 {
     /// A function that implements the actual problem evaluation logic.
-    pub solver: Box<dyn Fn(Vec<T>, &O, &ProblemParams) -> Vec<f32>>,
+    ///
+    /// It returns `objectives_count` results per solution, strided by solution.
+    pub solver: Box<dyn Fn(Vec<T>, &O, &ProblemParams<T>) -> Vec<f32>>,
+    /// An optional function computing the constraint violation of each solution.
+    ///
+    /// The violations are written only if the parameters carry a violations buffer.
+    pub constraints: Option<Box<dyn Fn(&[T], &O, &ProblemParams<T>) -> Vec<f32>>>,
     /// The problem options.
     pub options: O,
-    /// A buffer used for reading data from GPU memory.
-    /// Lazily initialized when first needed to minimize memory usage.
-    reader: Option<ReadbackBuffer>,
     /// The parameters bound to this problem.
-    binded_params: Option<ProblemParams>,
+    binded_params: Option<ProblemParams<T>>,
 }
 
 impl<T, O> CpuProblem<T, O> 
@@ -67,34 +70,47 @@ where
     ///
     /// # Returns
     /// A new `CpuProblem` instance.
-    pub fn new(solver: impl Fn(Vec<T>, &O, &ProblemParams) -> Vec<f32> + 'static, options: O) -> Self {
-        Self { solver: Box::new(solver), options, reader: None, binded_params: None }
+    pub fn new(solver: impl Fn(Vec<T>, &O, &ProblemParams<T>) -> Vec<f32> + 'static, options: O) -> Self {
+        Self { solver: Box::new(solver), constraints: None, options, binded_params: None }
     }
 
-    fn evaluate_inner(&mut self, params: Option<&ProblemParams>) {
+    /// Sets the function computing the constraint violations.
+    ///
+    /// # Arguments
+    /// * `constraints` - Returns a non-negative violation per solution, zero for feasible solutions.
+    ///
+    /// # Returns
+    /// The problem with the constraints.
+    #[must_use]
+    pub fn constraints(mut self, constraints: impl Fn(&[T], &O, &ProblemParams<T>) -> Vec<f32> + 'static) -> Self {
+        self.constraints = Some(Box::new(constraints));
+        self
+    }
+
+    fn evaluate_inner(&self, params: Option<&ProblemParams<T>>) {
         let params = params
             .or_else(|| self.binded_params.as_ref())
             .expect("bind() must be called before evaluate()");
 
+        // The readback buffer is leased from the context pool and reused every evaluation
         let solutions_len = params.solutions_count * params.vector_length;
-        let reader = self.reader.get_or_insert_with(|| ReadbackBuffer::new::<T, _>(
-            &params.context, 
-            solutions_len
-        ));
-        reader.scale::<T, _>(&params.context, solutions_len);
-
-        let solutions = reader.read(&params.context, &params.solutions, 0, solutions_len);
-        let results = (self.solver)(solutions, &self.options, &params);
+        let solutions = params.context.pool
+            .readback::<T, _>(solutions_len)
+            .read(&params.context, &params.solutions, 0, solutions_len);
+        if let (Some(constraints), Some(violations)) = (&self.constraints, &params.violations) {
+            violations.update_buffer_range(&params.context, &constraints(&solutions, &self.options, params), 0);
+        }
+        let results = (self.solver)(solutions, &self.options, params);
 
         params.results.update_buffer_range(&params.context, &results, 0);
     }
 }
 
-impl<T, O> Iteration<ProblemParams> for CpuProblem<T, O> 
+impl<T, O> Iteration<ProblemParams<T>> for CpuProblem<T, O> 
 where
     T: bytemuck::Pod,
 {
-    fn bind(&mut self, params: &ProblemParams) {
+    fn bind(&mut self, params: &ProblemParams<T>) {
         self.binded_params = Some(params.clone());
     }
 
@@ -102,7 +118,7 @@ where
         self.evaluate_inner(None);
     }
 
-    fn evaluate_with_params(&mut self, params: &ProblemParams) {
+    fn evaluate_with_params(&mut self, params: &ProblemParams<T>) {
         self.evaluate_inner(Some(params));
     }
 
@@ -111,7 +127,7 @@ where
         vec![]
     }
 
-    fn evaluate_with_params_async(&mut self, params: &ProblemParams) -> Vec<wgpu::CommandBuffer> {
+    fn evaluate_with_params_async(&mut self, params: &ProblemParams<T>) -> Vec<wgpu::CommandBuffer> {
         self.evaluate_inner(Some(params));
         vec![]
     }
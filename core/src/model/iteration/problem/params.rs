@@ -1,5 +1,7 @@
 use std::ops::Range;
 
+use bytemuck::Pod;
+
 use crate::{Sliced, StorageBuffer, WgpuContext};
 
 /// A struct representing the parameters for a problem.
@@ -7,6 +9,9 @@ use crate::{Sliced, StorageBuffer, WgpuContext};
 /// This struct contains the buffers and parameters needed for evaluating a problem.
 /// It is used to pass parameters to the `Problem` trait methods.
 ///
+/// # Type Parameters
+/// * `T` - The element type of the solution vectors (defaults to `f32`)
+///
 /// # Fields
 /// * `context` - The GPU context
 /// * `solutions` - The solutions buffer
@@ -14,6 +19,12 @@ use crate::{Sliced, StorageBuffer, WgpuContext};
 /// * `solutions_offset` - The offset of the solutions buffer
 /// * `solutions_count` - The number of solutions to evaluate
 /// * `vector_length` - The size of each solution vector
+/// * `objectives_count` - The number of objectives each solution is evaluated on
+/// * `violations` - The optional constraint violations buffer, one value per solution
+///
+/// # Results layout
+/// The results are strided by solution: objective `k` of solution `i` is stored at
+/// `i * objectives_count + k`. With a single objective this is one result per solution.
 ///
 /// # Examples
 /// ```
@@ -21,32 +32,105 @@ use crate::{Sliced, StorageBuffer, WgpuContext};
 /// 
 /// async fn create_problem_params(
 ///     context: WgpuContext,
-///     solutions: StorageBuffer,
-///     results: StorageBuffer,
+///     solutions: StorageBuffer<f32>,
+///     results: StorageBuffer<f32>,
 ///     solutions_offset: usize,
 ///     solutions_count: usize,
 ///     vector_length: usize
 /// ) -> ProblemParams {
-///     ProblemParams { context, solutions, results, solutions_offset, solutions_count, vector_length }
+///     ProblemParams::new(context, solutions, results, solutions_offset, solutions_count, vector_length)
 /// }
 /// ```
-#[derive(Debug, Clone)]
-pub struct ProblemParams {
+#[derive(Debug)]
+pub struct ProblemParams<T = f32>
+where
+    T: Pod
+{
     /// The GPU context
     pub context: WgpuContext,
     /// The solutions buffer
-    pub solutions: StorageBuffer,
+    pub solutions: StorageBuffer<T>,
     /// The results buffer
-    pub results: StorageBuffer,
+    pub results: StorageBuffer<f32>,
     /// The offset of the solutions buffer
     pub solutions_offset: usize,
     /// The number of solutions to evaluate
     pub solutions_count: usize,
     /// The size of each solution vector
     pub vector_length: usize,
+    /// The number of objectives each solution is evaluated on
+    pub objectives_count: usize,
+    /// The constraint violations buffer, `None` for unconstrained problems
+    ///
+    /// Problems write one non-negative value per solution, zero for feasible solutions.
+    pub violations: Option<StorageBuffer<f32>>,
+}
+
+impl<T> ProblemParams<T>
+where
+    T: Pod
+{
+    /// Creates parameters of a single-objective unconstrained problem
+    ///
+    /// # Arguments
+    /// * `context` - The GPU context
+    /// * `solutions` - The solutions buffer
+    /// * `results` - The results buffer
+    /// * `solutions_offset` - The offset of the solutions buffer
+    /// * `solutions_count` - The number of solutions to evaluate
+    /// * `vector_length` - The size of each solution vector
+    #[must_use]
+    pub const fn new(
+        context: WgpuContext,
+        solutions: StorageBuffer<T>,
+        results: StorageBuffer<f32>,
+        solutions_offset: usize,
+        solutions_count: usize,
+        vector_length: usize,
+    ) -> Self {
+        Self { context, solutions, results, solutions_offset, solutions_count, vector_length, objectives_count: 1, violations: None }
+    }
+
+    /// Returns the index of a result in the results buffer
+    ///
+    /// # Arguments
+    /// * `solution` - The index of the solution in the solutions buffer
+    /// * `objective` - The index of the objective
+    #[must_use]
+    pub const fn result_index(&self, solution: usize, objective: usize) -> usize {
+        solution * self.objectives_count + objective
+    }
+
+    /// Returns the range of the results of the evaluated solutions
+    #[must_use]
+    pub const fn results_range(&self) -> Range<usize> {
+        self.solutions_offset * self.objectives_count
+            ..(self.solutions_offset + self.solutions_count) * self.objectives_count
+    }
+}
+
+impl<T> Clone for ProblemParams<T>
+where
+    T: Pod
+{
+    fn clone(&self) -> Self {
+        Self {
+            context: self.context.clone(),
+            solutions: self.solutions.clone(),
+            results: self.results.clone(),
+            solutions_offset: self.solutions_offset,
+            solutions_count: self.solutions_count,
+            vector_length: self.vector_length,
+            objectives_count: self.objectives_count,
+            violations: self.violations.clone(),
+        }
+    }
 }
 
-impl Sliced for ProblemParams {
+impl<T> Sliced for ProblemParams<T>
+where
+    T: Pod
+{
     fn range(&self) -> Range<usize> {
         self.solutions_offset..self.solutions_offset + self.solutions_count
     }
@@ -1,12 +1,16 @@
+use bytemuck::Pod;
 use wgpu::Buffer;
 
-use crate::{Iteration, ProblemParams, Shader, WgpuContext};
+use crate::{Bindable, Error, Iteration, ProblemParams, Shader, TryIteration, WgpuContext};
 
 /// A problem implementation that uses GPU shaders for computation.
 /// 
 /// This struct manages the lifecycle of a shader-based computation problem,
 /// handling the binding of buffers and execution of the shader program.
 /// 
+/// The shader is bound to the solutions at binding 0 and the results at binding 1. Constrained
+/// problems get the violations at binding 2, the additional buffers follow.
+/// 
 /// # Example
 /// 
 /// ```rust
@@ -15,8 +19,8 @@ use crate::{Iteration, ProblemParams, Shader, WgpuContext};
 /// // Create a shader that computes distance to target point
 /// fn create_distance_problem() -> ShaderProblem {
 ///     let context = WgpuContext::new();
-///     let target_buffer = StorageBuffer::new::<f32, _>(&context, 2);
-///     target_buffer.update_buffer_range::<f32>(&context, &[ 42.0, 42.2 ], 0);
+///     let target_buffer = StorageBuffer::<f32>::new(&context, 2);
+///     target_buffer.update_buffer_range(&context, &[ 42.0, 42.2 ], 0);
 ///     
 ///     ShaderProblem::new(
 ///         Shader::new(&context, "distance", "shader source"),
@@ -45,19 +49,27 @@ impl ShaderProblem {
     pub const fn new(shader: Shader, additional_params: Vec<Buffer>) -> Self {
         Self { binding_state: None, shader, additional_params }
     }
+
+    /// Returns the context and the number of solutions of the bound parameters
+    fn binding_state(&self) -> Result<&(WgpuContext, usize), Error> {
+        self.binding_state.as_ref().ok_or_else(|| Error::NotBound("ShaderProblem::evaluate".to_string()))
+    }
 }
 
-impl Iteration<ProblemParams> for ShaderProblem {
+impl<T> Iteration<ProblemParams<T>> for ShaderProblem 
+where
+    T: Pod
+{
     /// Binds the problem to a WGPU context and prepares the shader for execution.
     /// 
     /// This method sets up the binding state and configures the shader with
     /// the necessary buffer parameters.
-    fn bind(&mut self, params: &ProblemParams) {
-        self.binding_state = Some((params.context.clone(), params.solutions_count));
-        let mut buffers = vec![ &params.solutions.0, &params.results ];
-        buffers.extend(self.additional_params.iter());
-
-        self.shader.bind(&params.context, &buffers);
+    /// 
+    /// # Panics
+    /// 
+    /// Panics if the buffers do not match the shader bindings.
+    fn bind(&mut self, params: &ProblemParams<T>) {
+        self.try_bind(params).expect("ShaderProblem buffers do not match the shader bindings");
     }
 
     /// Executes the shader program using the previously bound context and parameters.
@@ -66,36 +78,82 @@ impl Iteration<ProblemParams> for ShaderProblem {
     /// 
     /// Panics if the problem has not been bound to a context using `bind()`.
     fn evaluate(&mut self) {
-        let (context, solutions_count) = self.binding_state
-            .as_ref()
-            .expect("ShaderProblem must be bound before evaluate()");
-
-        self.shader.execute(context, *solutions_count);
+        TryIteration::<ProblemParams<T>>::try_evaluate(self).expect("ShaderProblem must be bound before evaluate()");
     }
 
     /// Executes the shader program with new parameters without changing the binding state.
     /// 
     /// This method allows for executing the shader with different input parameters
     /// while maintaining the same WGPU context binding.
-    fn evaluate_with_params(&mut self, params: &ProblemParams) {
-        let mut buffers = vec![ &params.solutions.0, &params.results ];
-        buffers.extend(self.additional_params.iter());
-
-        self.shader.execute_with_params(&params.context, params.solutions_count, &buffers);
+    fn evaluate_with_params(&mut self, params: &ProblemParams<T>) {
+        self.try_evaluate_with_params(params).expect("ShaderProblem buffers do not match the shader bindings");
     }
 
     fn evaluate_async(&mut self) -> Vec<wgpu::CommandBuffer> {
-        let (context, solutions_count) = self.binding_state
-            .as_ref()
-            .expect("ShaderProblem must be bound before evaluate()");
+        TryIteration::<ProblemParams<T>>::try_evaluate_async(self).expect("ShaderProblem must be bound before evaluate()")
+    }
+
+    fn evaluate_with_params_async(&mut self, params: &ProblemParams<T>) -> Vec<wgpu::CommandBuffer> {
+        self.try_evaluate_with_params_async(params).expect("ShaderProblem buffers do not match the shader bindings")
+    }
+}
+
+impl<T> TryIteration<ProblemParams<T>> for ShaderProblem 
+where
+    T: Pod
+{
+    /// Binds the problem to a WGPU context and prepares the shader for execution.
+    /// 
+    /// # Errors
+    /// 
+    /// Returns an error if the buffers do not match the shader bindings, the problem stays unbound then.
+    fn try_bind(&mut self, params: &ProblemParams<T>) -> Result<(), Error> {
+        self.shader.bind(&params.context, &buffers(params, &self.additional_params))?;
+        self.binding_state = Some((params.context.clone(), params.solutions_count));
+        Ok(())
+    }
 
-        vec![ self.shader.execute_async(context, *solutions_count) ]
+    /// Executes the shader program using the previously bound context and parameters.
+    /// 
+    /// # Errors
+    /// 
+    /// Returns [`Error::NotBound`] if the problem has not been bound to a context.
+    fn try_evaluate(&mut self) -> Result<(), Error> {
+        let (context, solutions_count) = self.binding_state()?;
+        self.shader.execute(context, *solutions_count);
+        Ok(())
     }
 
-    fn evaluate_with_params_async(&mut self, params: &ProblemParams) -> Vec<wgpu::CommandBuffer> {
-        let mut buffers = vec![ &params.solutions.0, &params.results ];
-        buffers.extend(self.additional_params.iter());
+    /// Executes the shader program with new parameters without changing the binding state.
+    /// 
+    /// # Errors
+    /// 
+    /// Returns an error if the buffers do not match the shader bindings.
+    fn try_evaluate_with_params(&mut self, params: &ProblemParams<T>) -> Result<(), Error> {
+        let buffers = buffers(params, &self.additional_params);
+        self.shader.execute_with_params(&params.context, params.solutions_count, &buffers)
+    }
+
+    fn try_evaluate_async(&mut self) -> Result<Vec<wgpu::CommandBuffer>, Error> {
+        let (context, solutions_count) = self.binding_state()?;
+        Ok(vec![ self.shader.execute_async(context, *solutions_count) ])
+    }
+
+    fn try_evaluate_with_params_async(&mut self, params: &ProblemParams<T>) -> Result<Vec<wgpu::CommandBuffer>, Error> {
+        let buffers = buffers(params, &self.additional_params);
+        Ok(vec![ self.shader.execute_with_params_async(&params.context, params.solutions_count, &buffers)? ])
+    }
+}
 
-        vec![ self.shader.execute_with_params_async(&params.context, params.solutions_count, &buffers) ]
+/// Returns the shader parameters: solutions, results, the violations if any and the additional buffers.
+fn buffers<'a, T>(params: &'a ProblemParams<T>, additional_params: &'a [Buffer]) -> Vec<&'a dyn Bindable>
+where
+    T: Pod
+{
+    let mut buffers: Vec<&dyn Bindable> = vec![ &params.solutions, &params.results ];
+    if let Some(violations) = &params.violations {
+        buffers.push(violations);
     }
+    buffers.extend(additional_params.iter().map(|buffer| buffer as &dyn Bindable));
+    buffers
 }
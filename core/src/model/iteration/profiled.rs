@@ -0,0 +1,115 @@
+use crate::{Error, Iteration, TryIteration, WgpuContext};
+
+/// An iteration recording its evaluations in the [`Profiler`](crate::Profiler) of a context.
+///
+/// Every `evaluate*` call is recorded under the label with the CPU time spent in it and
+/// the GPU time of the shader dispatches it encodes. Without timestamp queries the
+/// synchronous evaluations wait for the device, so their CPU time includes the GPU work.
+/// While the profiler is disabled the wrapper only forwards the calls.
+///
+/// # Examples
+/// ```no_run
+/// use sgrmath_core::{Iteration, Profiled, WgpuContext};
+///
+/// fn example<I: Iteration<usize>>(context: &WgpuContext, crossover: I) {
+///     let mut iteration = Profiled::new(context, "crossover", crossover);
+///     iteration.bind(&10);
+///     iteration.evaluate();
+///
+///     println!("{}", context.profiler.report(context).unwrap());
+/// }
+/// ```
+pub struct Profiled<I> {
+    label: String,
+    iteration: I,
+    context: WgpuContext,
+}
+
+impl<I> Profiled<I> {
+    /// Wraps an iteration
+    ///
+    /// # Arguments
+    /// * `context` - The context whose profiler records the evaluations
+    /// * `label` - The label of the iteration in the report
+    /// * `iteration` - The profiled iteration
+    pub fn new<L>(context: &WgpuContext, label: L, iteration: I) -> Self
+    where
+        L: Into<String>,
+    {
+        Self { label: label.into(), iteration, context: context.clone() }
+    }
+
+    /// Returns the label of the iteration in the report
+    pub fn label(&self) -> &str {
+        &self.label
+    }
+
+    /// Returns the wrapped iteration
+    pub fn into_inner(self) -> I {
+        self.iteration
+    }
+
+    /// Runs `f` in a profiler scope, waiting for the device if `wait` and no timestamps are measured
+    fn profile<R, F>(&mut self, wait: bool, f: F) -> R
+    where
+        F: FnOnce(&mut I) -> R,
+    {
+        let _scope = self.context.profiler.scope(&self.label);
+        let result = f(&mut self.iteration);
+        if wait && self.context.profiler.waits_for_device() {
+            let _ = self.context.device.poll(wgpu::PollType::Wait);
+        }
+
+        result
+    }
+}
+
+impl<T, I> Iteration<T> for Profiled<I>
+where
+    I: Iteration<T>,
+{
+    fn bind(&mut self, params: &T) {
+        self.iteration.bind(params);
+    }
+
+    fn evaluate(&mut self) {
+        self.profile(true, Iteration::evaluate);
+    }
+
+    fn evaluate_async(&mut self) -> Vec<wgpu::CommandBuffer> {
+        self.profile(false, Iteration::evaluate_async)
+    }
+
+    fn evaluate_with_params(&mut self, params: &T) {
+        self.profile(true, |iteration| iteration.evaluate_with_params(params));
+    }
+
+    fn evaluate_with_params_async(&mut self, params: &T) -> Vec<wgpu::CommandBuffer> {
+        self.profile(false, |iteration| iteration.evaluate_with_params_async(params))
+    }
+}
+
+impl<T, I> TryIteration<T> for Profiled<I>
+where
+    I: TryIteration<T>,
+{
+    fn try_bind(&mut self, params: &T) -> Result<(), Error> {
+        self.iteration.try_bind(params)
+    }
+
+    fn try_evaluate(&mut self) -> Result<(), Error> {
+        self.profile(true, TryIteration::try_evaluate)
+    }
+
+    fn try_evaluate_async(&mut self) -> Result<Vec<wgpu::CommandBuffer>, Error> {
+        self.profile(false, TryIteration::try_evaluate_async)
+    }
+
+    fn try_evaluate_with_params(&mut self, params: &T) -> Result<(), Error> {
+        self.profile(true, |iteration| iteration.try_evaluate_with_params(params))
+    }
+
+    fn try_evaluate_with_params_async(&mut self, params: &T) -> Result<Vec<wgpu::CommandBuffer>, Error> {
+        self.profile(false, |iteration| iteration.try_evaluate_with_params_async(params))
+    }
+}
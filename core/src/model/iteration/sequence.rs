@@ -0,0 +1,139 @@
+use std::ops::Deref;
+
+use crate::Iteration;
+
+/// A container running several iterations one after another.
+///
+/// Unlike [`CombinedIteration`](crate::CombinedIteration), whose synchronous methods submit the
+/// command buffers of all iterations at once, `evaluate` and `evaluate_with_params` evaluate
+/// each iteration synchronously in turn, so CPU steps observe the results of the previous ones.
+/// The asynchronous methods return the command buffers of all iterations in order, e.g. for an
+/// [`Executor`](crate::Executor).
+///
+/// Together with [`MapParams`](crate::MapParams) a sequence can hold iterations of different
+/// parameter types, e.g. the operators and the problem of a whole generation.
+///
+/// # Examples
+/// ```
+/// use sgrmath_core::{NotImplementedIteration, Sequence};
+///
+/// let _sequence = Sequence::<Params>::new()
+///     .add(Box::new(NotImplementedIteration::new("First")))
+///     .add(Box::new(NotImplementedIteration::new("Second")));
+///
+/// type Params = std::ops::Range<usize>;
+/// ```
+pub struct Sequence<T> {
+    iterations: Vec<Box<dyn Iteration<T>>>,
+}
+
+impl<T> Sequence<T> {
+    /// Creates a new empty Sequence.
+    #[must_use]
+    pub fn new() -> Self {
+        Self { iterations: vec![] }
+    }
+
+    /// Appends an iteration to run after the others.
+    ///
+    /// # Arguments
+    /// * `iteration` - The iteration to add
+    ///
+    /// # Returns
+    /// Self for method chaining
+    #[must_use]
+    #[allow(clippy::should_implement_trait)]
+    pub fn add(mut self, iteration: Box<dyn Iteration<T>>) -> Self {
+        self.iterations.push(iteration);
+        self
+    }
+
+    /// Removes the iteration at the specified index.
+    ///
+    /// # Arguments
+    /// * `index` - The index of the iteration to remove
+    ///
+    /// # Returns
+    /// Self for method chaining
+    #[must_use]
+    pub fn remove(mut self, index: usize) -> Self {
+        self.iterations.remove(index);
+        self
+    }
+
+    /// Removes all iterations.
+    ///
+    /// # Returns
+    /// Self for method chaining
+    #[must_use]
+    pub fn clear(mut self) -> Self {
+        self.iterations.clear();
+        self
+    }
+
+    /// Sets the iterations to the specified vector.
+    ///
+    /// # Arguments
+    /// * `iterations` - Vector of iterations to run in order
+    ///
+    /// # Returns
+    /// &mut Self for method chaining
+    pub fn set(&mut self, iterations: Vec<Box<dyn Iteration<T>>>) -> &mut Self {
+        self.iterations = iterations;
+        self
+    }
+}
+
+impl<T> Default for Sequence<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Iteration<T> for Sequence<T> {
+    /// Binds the parameters to all iterations.
+    fn bind(&mut self, params: &T) {
+        for iteration in &mut self.iterations {
+            iteration.bind(params);
+        }
+    }
+
+    /// Evaluates the iterations one after another.
+    fn evaluate(&mut self) {
+        for iteration in &mut self.iterations {
+            iteration.evaluate();
+        }
+    }
+
+    /// Returns the command buffers of all iterations in order.
+    fn evaluate_async(&mut self) -> Vec<wgpu::CommandBuffer> {
+        self.iterations
+            .iter_mut()
+            .flat_map(Iteration::evaluate_async)
+            .collect()
+    }
+
+    /// Evaluates the iterations one after another with the parameters.
+    fn evaluate_with_params(&mut self, params: &T) {
+        for iteration in &mut self.iterations {
+            iteration.evaluate_with_params(params);
+        }
+    }
+
+    /// Returns the command buffers of all iterations evaluated with the parameters, in order.
+    fn evaluate_with_params_async(&mut self, params: &T) -> Vec<wgpu::CommandBuffer> {
+        self.iterations
+            .iter_mut()
+            .flat_map(|iteration| iteration.evaluate_with_params_async(params))
+            .collect()
+    }
+}
+
+impl<T> Deref for Sequence<T> {
+    type Target = Vec<Box<dyn Iteration<T>>>;
+
+    /// Returns a reference to the underlying vector of iterations.
+    fn deref(&self) -> &Self::Target {
+        &self.iterations
+    }
+}
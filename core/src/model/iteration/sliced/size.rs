@@ -1,8 +1,11 @@
-/// Represents the size of a slice, either as a fixed count or a proportional value.
+/// Represents the size of a slice of a [`SlicedIteration`](crate::SlicedIteration).
 ///
-/// Proportional values represent parts of available space to distribute. For example,
-/// if you have three slices with proportional values 1, 2, and 1, they will get 25%, 50%,
-/// and 25% of the available space respectively.
+/// Fixed counts and percentages of the total are allocated first. The rest of the total is
+/// shared by the proportional sizes by their weights. For example, if you have three slices
+/// with proportional values 1, 2, and 1, they will get 25%, 50%, and 25% of the available
+/// space respectively. Bounded proportional sizes keep their share between a minimum and a
+/// maximum, the others share what the bounds free. Remainder slices get what is left, e.g.
+/// the elements lost to rounding the proportional shares down.
 ///
 /// # Examples
 /// ```
@@ -13,13 +16,59 @@
 ///
 /// // Proportional value of 2 (will get twice as much space as a value of 1)
 /// let proportional = IterationSize::Proportional(2.0);
+///
+/// // A tenth of the total
+/// let percentage = IterationSize::Percentage(10.0);
+///
+/// // Proportional, but at least 5 and at most 50 elements
+/// let bounded = IterationSize::Bounded { weight: 1.0, min: 5, max: 50 };
+///
+/// // Everything the other slices leave
+/// let remainder = IterationSize::Remainder;
 /// ```
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub enum IterationSize {
     /// Fixed number of elements
     Count(usize),
     /// Proportional value (must be positive)
     Proportional(f32),
+    /// Percentage of the total, rounded down (between 0 and 100)
+    Percentage(f32),
+    /// Proportional value with the share kept between `min` and `max` elements
+    Bounded {
+        /// The proportional value (must be positive)
+        weight: f32,
+        /// The minimum number of elements
+        min: usize,
+        /// The maximum number of elements
+        max: usize,
+    },
+    /// The elements left by the other slices, shared equally by all remainder slices
+    Remainder,
+}
+
+impl IterationSize {
+    /// Returns the proportional weight, if the size has one
+    #[must_use]
+    pub const fn weight(&self) -> Option<f32> {
+        match self {
+            Self::Proportional(weight) | Self::Bounded { weight, .. } => Some(*weight),
+            _ => None,
+        }
+    }
+
+    /// Returns the size with another proportional weight
+    ///
+    /// # Returns
+    /// The size with the weight replaced, or `None` if the size has no weight
+    #[must_use]
+    pub const fn with_weight(self, weight: f32) -> Option<Self> {
+        match self {
+            Self::Proportional(_) => Some(Self::Proportional(weight)),
+            Self::Bounded { min, max, .. } => Some(Self::Bounded { weight, min, max }),
+            _ => None,
+        }
+    }
 }
 
 impl Into<IterationSize> for usize {
@@ -6,7 +6,8 @@ use crate::{Iteration, IterationSize, Sliced};
 /// 
 /// SlicedIteration allows you to split a population into multiple segments and apply
 /// different iteration strategies to each segment. The size of each segment can be
-/// specified either as a fixed count or as a proportional value.
+/// specified as a fixed count, a percentage, a (bounded) proportional value or the
+/// remainder, see [`IterationSize`].
 /// 
 /// # Examples
 /// ```
@@ -23,7 +24,10 @@ use crate::{Iteration, IterationSize, Sliced};
 /// ```
 pub struct SlicedIteration<T: Sliced + Clone> (
     Vec<(IterationSize, Box<dyn Iteration<T>>)>,
-    Option<Vec<usize>>
+    /// The last distributed total with its sizes
+    Option<(usize, Vec<usize>)>,
+    /// The parameters of the last `bind`, the slices are bound again when the sizes change
+    Option<T>
 );
 
 impl<T> SlicedIteration<T> 
@@ -31,7 +35,7 @@ where
     T: Sliced + Clone
 {
     /// Creates a new empty SlicedIteration.
-    pub fn new() -> Self { Self(vec![], None) }
+    pub fn new() -> Self { Self(vec![], None, None) }
 
     /// Adds a new iteration with the specified size.
     /// 
@@ -78,20 +82,78 @@ where
     /// # Arguments
     /// * `slices` - Vector of (size, iteration) pairs
     /// 
+    /// The new iterations are bound with the parameters of the last `bind`, if any.
+    /// 
     /// # Returns
     /// &mut Self for method chaining
     pub fn set(&mut self, slices: Vec<(IterationSize, Box<dyn Iteration<T>>)>) -> &mut Self {
         self.0 = slices;
         self.1 = None;
+        self.rebind()
+    }
+
+    /// Replaces the size of an iteration.
+    /// 
+    /// The sizes are distributed again on the next call, e.g. to rebalance the operators
+    /// between generations. Bound slices are bound again to their new ranges.
+    /// 
+    /// # Arguments
+    /// * `index` - The index of the iteration
+    /// * `size` - The new size of its segment
+    /// 
+    /// # Returns
+    /// &mut Self for method chaining
+    /// 
+    /// # Panics
+    /// If there is no iteration at the index
+    pub fn set_size<S>(&mut self, index: usize, size: S) -> &mut Self
+    where
+        S: Into<IterationSize>
+    {
+        self.0[index].0 = size.into();
+        self.1 = None;
+        self.rebind()
+    }
+
+    /// Replaces the proportional weight of an iteration, keeping its bounds.
+    /// 
+    /// # Arguments
+    /// * `index` - The index of the iteration
+    /// * `weight` - The new proportional value (must be positive)
+    /// 
+    /// # Returns
+    /// &mut Self for method chaining
+    /// 
+    /// # Panics
+    /// If there is no iteration at the index or its size has no proportional weight
+    pub fn set_weight(&mut self, index: usize, weight: f32) -> &mut Self {
+        let size = self.0[index].0
+            .with_weight(weight)
+            .expect("Only proportional sizes have a weight");
+
+        self.set_size(index, size)
+    }
+
+    /// Binds the slices again with the parameters of the last `bind`, if any
+    fn rebind(&mut self) -> &mut Self {
+        if let Some(params) = self.2.take() {
+            self.bind(&params);
+        }
         self
     }
 
     /// Distributes the total size across all iterations according to their size specifications.
     /// 
     /// This method:
-    /// 1. First allocates space for all fixed sizes
-    /// 2. Then distributes the remaining space proportionally among proportional sizes
-    /// 3. Ensures the last proportional size gets the remaining space to maintain the total
+    /// 1. First allocates space for all fixed sizes and percentages
+    /// 2. Then distributes the remaining space proportionally among proportional sizes,
+    ///    keeping bounded ones between their bounds
+    /// 3. Gives the space left by rounding to the remainder sizes, or to the last
+    ///    proportional size with a positive weight if there are none, to maintain the total
+    /// 
+    /// The result is kept until the total or the sizes change. Without remainder sizes the
+    /// slices cover less than the total if every proportional size reached its maximum
+    /// or has a zero weight.
     /// 
     /// # Arguments
     /// * `total` - The total size to distribute
@@ -100,64 +162,95 @@ where
     /// A reference to the vector of calculated sizes
     /// 
     /// # Panics
-    /// If the total size is less than the sum of fixed sizes
+    /// If the total size is less than the sum of fixed sizes and percentages,
+    /// or the remaining space is less than the sum of the minimums of bounded sizes
     pub fn distribute(&mut self, total: usize) -> &Vec<usize> {
-        match self.1 {
-            Some(ref sizes) => sizes,
-            None => {
-                // First pass: validate proportional values and calculate fixed sum
-                let mut fixed_sum = 0;
-                let mut proportional_sum = 0.0;
-                let mut proportional_indices = Vec::new();
-                
-                for (i, (size, _)) in self.0.iter().enumerate() {
-                    match size {
-                        IterationSize::Count(count) => fixed_sum += count,
-                        IterationSize::Proportional(value) => {
-                            proportional_sum += value;
-                            proportional_indices.push(i);
-                        }
-                    }
-                }
-
-                // Check if we have enough space for fixed sizes
-                if fixed_sum > total {
-                    panic!("Total size is less than sum of fixed sizes, got {} < {}", total, fixed_sum);
-                }
-
-                // Calculate remaining space for proportional distribution
-                let remaining = total - fixed_sum;
-                
-                // Second pass: calculate final sizes
-                let mut result = vec![0; self.0.len()];
-                let mut distributed = 0;
-
-                // Handle all proportional values except the last one
-                for &idx in proportional_indices.iter().take(proportional_indices.len().saturating_sub(1)) {
-                    if let IterationSize::Proportional(value) = self.0[idx].0 {
-                        let size = (remaining as f32 * (value / proportional_sum)) as usize;
-                        result[idx] = size;
-                        distributed += size;
-                    }
-                }
-
-                // Handle the last proportional value to ensure total sum
-                if let Some(&last_idx) = proportional_indices.last() {
-                    result[last_idx] = remaining - distributed;
-                }
-
-                // Fill in fixed sizes
-                for (i, (size, _)) in self.0.iter().enumerate() {
-                    if let IterationSize::Count(count) = size {
-                        result[i] = *count;
-                    }
-                }
-
-                self.1 = Some(result);
-                self.1.as_ref().unwrap()
-            }
+        if !matches!(self.1, Some((cached, _)) if cached == total) {
+            self.1 = None;
+        }
+
+        let slices = &self.0;
+        &self.1
+            .get_or_insert_with(|| {
+                let sizes = slices.iter().map(|(size, _)| *size).collect::<Vec<_>>();
+                (total, distribute(&sizes, total))
+            })
+            .1
+    }
+}
+
+/// Distributes the total across the sizes, see [`SlicedIteration::distribute`]
+#[allow(clippy::cast_precision_loss, clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+fn distribute(sizes: &[IterationSize], total: usize) -> Vec<usize> {
+    // First pass: fixed sizes and percentages
+    let mut result = sizes
+        .iter()
+        .map(|size| match size {
+            IterationSize::Count(count) => *count,
+            IterationSize::Percentage(percentage) => (total as f64 * f64::from(*percentage) / 100.0) as usize,
+            _ => 0,
+        })
+        .collect::<Vec<_>>();
+
+    let fixed_sum = result.iter().sum::<usize>();
+    assert!(fixed_sum <= total, "Total size is less than sum of fixed sizes, got {total} < {fixed_sum}");
+    let mut remaining = total - fixed_sum;
+
+    // Second pass: proportional shares, bounded sizes outside their bounds are fixed to them
+    let mut proportional = (0..sizes.len()).filter(|&i| sizes[i].weight().is_some()).collect::<Vec<_>>();
+    let shares = loop {
+        let weight_sum = proportional.iter().filter_map(|&i| sizes[i].weight()).map(f64::from).sum::<f64>();
+        let share = |i: usize| sizes[i].weight().map_or(0.0, |weight| {
+            if weight_sum > 0.0 { remaining as f64 * f64::from(weight) / weight_sum } else { 0.0 }
+        });
+
+        let clamped = proportional
+            .iter()
+            .filter_map(|&i| match sizes[i] {
+                IterationSize::Bounded { min, .. } if share(i) < min as f64 => Some((i, min)),
+                IterationSize::Bounded { max, .. } if share(i) > max as f64 => Some((i, max)),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+
+        if clamped.is_empty() {
+            break proportional.iter().map(|&i| (i, share(i) as usize)).collect::<Vec<_>>();
+        }
+
+        for (i, size) in clamped {
+            result[i] = size;
+            remaining = remaining
+                .checked_sub(size)
+                .expect("Remaining size is less than sum of minimum sizes");
+            proportional.retain(|&index| index != i);
         }
+    };
+
+    for &(i, share) in &shares {
+        result[i] = share;
+    }
+    let mut left = remaining - shares.iter().map(|(_, share)| share).sum::<usize>();
+
+    // Third pass: the space left goes to the remainders, or to the last weighted proportional size
+    let remainders = (0..sizes.len())
+        .filter(|&i| matches!(sizes[i], IterationSize::Remainder))
+        .collect::<Vec<_>>();
+    if let Some((&last, others)) = remainders.split_last() {
+        let share = left / remainders.len();
+        for &i in others {
+            result[i] = share;
+        }
+        result[last] = left - share * others.len();
+    } else if let Some(&(last, _)) = shares.iter().rev().find(|(i, _)| sizes[*i].weight() > Some(0.0)) {
+        let max = match sizes[last] {
+            IterationSize::Bounded { max, .. } => max,
+            _ => usize::MAX,
+        };
+        left = left.min(max.saturating_sub(result[last]));
+        result[last] += left;
     }
+
+    result
 }
 
 impl<T> Iteration<T> for SlicedIteration<T> 
@@ -170,7 +263,10 @@ where
     /// 1. Calculates its portion of the range based on its size
     /// 2. Creates a new parameter instance with the appropriate range
     /// 3. Binds the parameters to the iteration
+    /// 
+    /// The parameters are kept to bind the slices again when their sizes change.
     fn bind(&mut self, params: &T) {
+        self.2 = Some(params.clone());
         let range = params.range();
         let sizes = self.distribute(range.len());
         let mut prev = range.start;
@@ -0,0 +1,108 @@
+use crate::Error;
+
+/// A fallible variant of [`Iteration`](crate::Iteration).
+///
+/// Every step reports failures as an [`Error`] instead of panicking, so long unattended
+/// runs can log a failure and stop, retry or recover. [`CompiledIteration`](crate::CompiledIteration)
+/// and [`ShaderProblem`](crate::ShaderProblem) implement it, other iterations are adapted
+/// by [`Fallible`](crate::Fallible).
+///
+/// # Type Parameters
+/// * `T` - The type of parameters used for binding and evaluation
+///
+/// # Examples
+/// ```
+/// use sgrmath_core::{Error, TryIteration};
+///
+/// struct Checked {
+///     limit: Option<usize>,
+/// }
+///
+/// impl TryIteration<usize> for Checked {
+///     fn try_bind(&mut self, params: &usize) -> Result<(), Error> {
+///         self.limit = Some(*params);
+///         Ok(())
+///     }
+///
+///     fn try_evaluate(&mut self) -> Result<(), Error> {
+///         self.limit.map(|_| ()).ok_or_else(|| Error::NotImplemented("unbound".to_string()))
+///     }
+///
+///     fn try_evaluate_async(&mut self) -> Result<Vec<wgpu::CommandBuffer>, Error> {
+///         self.try_evaluate().map(|()| vec![])
+///     }
+///
+///     fn try_evaluate_with_params(&mut self, _params: &usize) -> Result<(), Error> {
+///         Ok(())
+///     }
+///
+///     fn try_evaluate_with_params_async(&mut self, _params: &usize) -> Result<Vec<wgpu::CommandBuffer>, Error> {
+///         Ok(vec![])
+///     }
+/// }
+///
+/// let mut iteration = Checked { limit: None };
+/// assert!(iteration.try_evaluate().is_err());
+/// ```
+pub trait TryIteration<T> {
+    /// Binds the parameters to this iteration step
+    ///
+    /// # Errors
+    /// Returns an error if the parameters cannot be bound.
+    fn try_bind(&mut self, params: &T) -> Result<(), Error>;
+
+    /// Evaluates the iteration step using previously bound parameters
+    ///
+    /// # Errors
+    /// Returns an error if the evaluation fails, e.g. when no parameters were bound.
+    fn try_evaluate(&mut self) -> Result<(), Error>;
+
+    /// Evaluates asynchronously the iteration step using previously bound parameters
+    ///
+    /// # Returns
+    /// The command buffers to submit
+    ///
+    /// # Errors
+    /// Returns an error if the evaluation fails, e.g. when no parameters were bound.
+    fn try_evaluate_async(&mut self) -> Result<Vec<wgpu::CommandBuffer>, Error>;
+
+    /// Evaluates the iteration step with explicitly provided parameters
+    ///
+    /// # Errors
+    /// Returns an error if the evaluation fails.
+    fn try_evaluate_with_params(&mut self, params: &T) -> Result<(), Error>;
+
+    /// Evaluates asynchronously the iteration step with explicitly provided parameters
+    ///
+    /// # Returns
+    /// The command buffers to submit
+    ///
+    /// # Errors
+    /// Returns an error if the evaluation fails.
+    fn try_evaluate_with_params_async(&mut self, params: &T) -> Result<Vec<wgpu::CommandBuffer>, Error>;
+}
+
+impl<T, I> TryIteration<T> for Box<I>
+where
+    I: TryIteration<T> + ?Sized,
+{
+    fn try_bind(&mut self, params: &T) -> Result<(), Error> {
+        (**self).try_bind(params)
+    }
+
+    fn try_evaluate(&mut self) -> Result<(), Error> {
+        (**self).try_evaluate()
+    }
+
+    fn try_evaluate_async(&mut self) -> Result<Vec<wgpu::CommandBuffer>, Error> {
+        (**self).try_evaluate_async()
+    }
+
+    fn try_evaluate_with_params(&mut self, params: &T) -> Result<(), Error> {
+        (**self).try_evaluate_with_params(params)
+    }
+
+    fn try_evaluate_with_params_async(&mut self, params: &T) -> Result<Vec<wgpu::CommandBuffer>, Error> {
+        (**self).try_evaluate_with_params_async(params)
+    }
+}
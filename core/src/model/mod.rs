@@ -1,10 +1,15 @@
+mod constraint_handling;
+mod fitness_policy;
 mod iteration;
+mod schedule;
 mod size;
 mod optimization_direction;
 
 pub use iteration::{
     CpuProblem, 
     Iteration, 
+    TryIteration,
+    Fallible,
     IterationSize, 
     Compiled,
     CompiledIteration,
@@ -12,8 +17,20 @@ pub use iteration::{
     ShaderProblem, 
     NotImplementedIteration, 
     CombinedIteration,
+    EveryN,
+    FirstN,
+    AfterN,
+    When,
+    Sequence,
+    MapParams,
+    Executor,
+    Submission,
+    Profiled,
     Sliced,
     SlicedIteration, 
 };
+pub use constraint_handling::ConstraintHandling;
+pub use fitness_policy::{FitnessPolicy, InfinityPolicy, NanPolicy};
 pub use optimization_direction::OptimizationDirection;
+pub use schedule::Schedule;
 pub use size::Size;
@@ -1,5 +1,9 @@
 use std::cmp::Ordering;
 
+use crate::FitnessPolicy;
+
+use super::fitness_policy::Rank;
+
 /// Direction of optimization for the genetic algorithm.
 ///
 /// Determines whether the algorithm should try to minimize
@@ -33,6 +37,8 @@ impl OptimizationDirection {
 
     /// Compares two fitness values and returns the ordering
     /// 
+    /// Uses the default [`FitnessPolicy`], so NaN is "greater" than every other value.
+    /// 
     /// # Arguments
     /// * `a` - The first fitness value
     /// * `b` - The second fitness value
@@ -42,9 +48,78 @@ impl OptimizationDirection {
     /// * `Equal` if `a` is "equal" to `b`
     /// * `Greater` if `a` is "greater" (with respect to the optimization direction) than `b`
     pub fn compare(&self, a: &f32, b: &f32) -> Ordering {
+        self.total_cmp(a, b, &FitnessPolicy::default())
+    }
+
+    /// Compares two fitness values in a total order, the better value being "less"
+    /// 
+    /// NaN and infinities are ordered by the policy, all NaNs are equal to each other.
+    /// Values rejected by the policy are ordered as the worst values, use
+    /// [`FitnessPolicy::check`] to report them.
+    /// 
+    /// # Arguments
+    /// * `a` - The first fitness value
+    /// * `b` - The second fitness value
+    /// * `policy` - How NaN and infinities are ordered
+    /// 
+    /// # Returns
+    /// * `Less` if `a` is better than `b`
+    /// * `Equal` if `a` is as good as `b`
+    /// * `Greater` if `a` is worse than `b`
+    #[must_use]
+    pub fn total_cmp(&self, a: &f32, b: &f32, policy: &FitnessPolicy) -> Ordering {
+        let (rank_a, rank_b) = (policy.rank(*a), policy.rank(*b));
+        if rank_a != rank_b || rank_a != Rank::Ordinary {
+            return rank_a.cmp(&rank_b);
+        }
+
+        let ordering = a.partial_cmp(b).unwrap_or(Ordering::Equal);
         match self {
-            OptimizationDirection::Minimize => a.partial_cmp(b).unwrap(),
-            OptimizationDirection::Maximize => b.partial_cmp(a).unwrap(),
+            Self::Minimize => ordering,
+            Self::Maximize => ordering.reverse(),
         }
     }
-}
\ No newline at end of file
+
+    /// Checks if a fitness value is strictly better than another one
+    /// 
+    /// # Arguments
+    /// * `a` - The first fitness value
+    /// * `b` - The second fitness value
+    /// * `policy` - How NaN and infinities are ordered
+    #[must_use]
+    pub fn is_better(&self, a: &f32, b: &f32, policy: &FitnessPolicy) -> bool {
+        self.total_cmp(a, b, policy) == Ordering::Less
+    }
+
+    /// Finds the best of the fitness values
+    /// 
+    /// # Arguments
+    /// * `values` - The fitness values
+    /// * `policy` - How NaN and infinities are ordered
+    /// 
+    /// # Returns
+    /// The index and value of the first best value, or `None` if there are no values
+    #[must_use]
+    pub fn best_of<I>(&self, values: I, policy: &FitnessPolicy) -> Option<(usize, f32)>
+    where
+        I: IntoIterator<Item = f32>,
+    {
+        values
+            .into_iter()
+            .enumerate()
+            .reduce(|best, value| if self.is_better(&value.1, &best.1, policy) { value } else { best })
+    }
+
+    /// Returns the worst possible ordinary fitness value
+    /// 
+    /// # Returns
+    /// * `f32::INFINITY` when minimizing
+    /// * `f32::NEG_INFINITY` when maximizing
+    #[must_use]
+    pub const fn worst_value(&self) -> f32 {
+        match self {
+            Self::Minimize => f32::INFINITY,
+            Self::Maximize => f32::NEG_INFINITY,
+        }
+    }
+}
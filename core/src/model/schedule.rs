@@ -0,0 +1,142 @@
+use std::{fmt, sync::Arc};
+
+/// A numeric parameter changing over time.
+///
+/// The value is computed from a step counter, usually the index of the generation,
+/// e.g. a mutation probability decaying while the population converges.
+/// Plain numbers convert into a [`Schedule::Constant`].
+///
+/// # Examples
+/// ```
+/// use sgrmath_core::Schedule;
+///
+/// let probability = Schedule::Linear { start: 0.1, end: 0.01, steps: 100 };
+///
+/// assert_eq!(probability.value(0), 0.1);
+/// assert_eq!(probability.value(200), 0.01);
+/// ```
+#[derive(Clone)]
+pub enum Schedule {
+    /// The same value on every step
+    Constant(f32),
+    /// Moves linearly from `start` to `end` over `steps` steps, then stays at `end`
+    Linear {
+        /// The value on step zero
+        start: f32,
+        /// The value from step `steps` on
+        end: f32,
+        /// The number of steps the transition takes
+        steps: usize,
+    },
+    /// Multiplies `start` by `rate` on every step
+    Exponential {
+        /// The value on step zero
+        start: f32,
+        /// The factor applied on every step
+        rate: f32,
+    },
+    /// Piecewise constant values as `(first step, value)` pairs sorted by step
+    ///
+    /// Steps before the first pair use its value.
+    Piecewise(Vec<(usize, f32)>),
+    /// Values computed by a function of the step
+    Custom(Arc<dyn Fn(usize) -> f32 + Send + Sync>),
+}
+
+impl Schedule {
+    /// Creates a schedule computed by a function of the step
+    pub fn custom<F>(f: F) -> Self
+    where
+        F: Fn(usize) -> f32 + Send + Sync + 'static,
+    {
+        Self::Custom(Arc::new(f))
+    }
+
+    /// Returns the value on a step
+    ///
+    /// # Arguments
+    /// * `step` - The step counter, e.g. the index of the generation
+    ///
+    /// # Returns
+    /// The value of the parameter, `0.0` for an empty piecewise schedule
+    #[must_use]
+    #[allow(clippy::cast_precision_loss)]
+    pub fn value(&self, step: usize) -> f32 {
+        match self {
+            Self::Constant(value) => *value,
+            Self::Linear { start, end, steps } => {
+                if step >= *steps {
+                    return *end;
+                }
+                (end - start).mul_add(step as f32 / *steps as f32, *start)
+            }
+            Self::Exponential { start, rate } => {
+                start * rate.powi(i32::try_from(step).unwrap_or(i32::MAX))
+            }
+            Self::Piecewise(values) => values
+                .iter()
+                .take_while(|(first, _)| *first <= step)
+                .last()
+                .or_else(|| values.first())
+                .map_or(0.0, |(_, value)| *value),
+            Self::Custom(f) => f(step),
+        }
+    }
+
+    /// Returns the lowest value over all steps
+    ///
+    /// Exponential schedules decaying towards zero return zero, the limit of their values.
+    ///
+    /// # Returns
+    /// The lowest value, `None` for custom schedules
+    #[must_use]
+    pub fn min(&self) -> Option<f32> {
+        match self {
+            Self::Constant(value) => Some(*value),
+            Self::Linear { start, end, .. } => Some(start.min(*end)),
+            Self::Exponential { start, rate } if rate.abs() <= 1.0 => {
+                // The first two values are the largest of their signs, the others decay towards zero
+                let limit = if rate.abs() < 1.0 { 0.0 } else { f32::INFINITY };
+                Some(start.min(start * rate).min(limit))
+            }
+            Self::Exponential { start, rate } => Some(
+                if *start >= 0.0 && (*rate > 1.0 || *start <= 0.0) { *start } else { f32::NEG_INFINITY }
+            ),
+            Self::Piecewise(values) => Some(values.iter().map(|(_, value)| *value).reduce(f32::min).unwrap_or(0.0)),
+            Self::Custom(_) => None,
+        }
+    }
+
+    /// Returns true if the value does not depend on the step
+    #[must_use]
+    pub const fn is_constant(&self) -> bool {
+        matches!(self, Self::Constant(_))
+    }
+}
+
+impl From<f32> for Schedule {
+    fn from(value: f32) -> Self {
+        Self::Constant(value)
+    }
+}
+
+impl fmt::Debug for Schedule {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Constant(value) => f.debug_tuple("Constant").field(value).finish(),
+            Self::Linear { start, end, steps } => f
+                .debug_struct("Linear")
+                .field("start", start)
+                .field("end", end)
+                .field("steps", steps)
+                .finish(),
+            Self::Exponential { start, rate } => f
+                .debug_struct("Exponential")
+                .field("start", start)
+                .field("rate", rate)
+                .finish(),
+            Self::Piecewise(values) => f.debug_tuple("Piecewise").field(values).finish(),
+            Self::Custom(_) => f.write_str("Custom(..)"),
+        }
+    }
+}
@@ -0,0 +1,5 @@
+mod profiler;
+mod report;
+
+pub use profiler::{ProfileScope, Profiler};
+pub use report::{ProfileEntry, ProfileKind, ProfileReport};
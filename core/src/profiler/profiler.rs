@@ -0,0 +1,277 @@
+use std::{
+    sync::{Arc, Mutex, MutexGuard, PoisonError},
+    time::{Duration, Instant},
+};
+
+use crate::{buffers::MapRequest, Error, WgpuContext};
+
+use super::{ProfileKind, ProfileReport};
+
+/// The number of timestamps recorded between reports, two per dispatch
+const QUERY_CAPACITY: u32 = 4096;
+
+/// The query set timestamps are written to and the buffers they are resolved into
+#[derive(Debug)]
+struct Timestamps {
+    query_set: wgpu::QuerySet,
+    resolve: wgpu::Buffer,
+    readback: wgpu::Buffer,
+}
+
+/// A dispatch or iteration recorded since the last report
+#[derive(Debug)]
+struct Record {
+    label: String,
+    kind: ProfileKind,
+    cpu: Duration,
+    /// The first of the two timestamps of a dispatch
+    query: Option<u32>,
+    /// The iteration records open when the record was created
+    scopes: Vec<usize>,
+}
+
+#[derive(Debug, Default)]
+struct ProfilerState {
+    enabled: bool,
+    timestamps: Option<Timestamps>,
+    queries: u32,
+    records: Vec<Record>,
+    scopes: Vec<usize>,
+}
+
+/// Records the time spent in shader dispatches and iterations of a context.
+///
+/// Every [`WgpuContext`] owns a profiler, available as `context.profiler`; it is disabled
+/// unless the context is built with
+/// [`WgpuContextBuilder::profiling`](crate::WgpuContextBuilder::profiling) or
+/// [`Profiler::set_enabled`] is called. Cloning the profiler shares it.
+///
+/// While enabled:
+/// - Every [`Shader`](crate::Shader) dispatch is recorded under the shader's label. If the
+///   device supports [`wgpu::Features::TIMESTAMP_QUERY`], the compute pass writes timestamps
+///   and the GPU time is measured. Otherwise synchronous `execute*` calls wait for the device
+///   and record the wall-clock time instead.
+/// - [`Profiled`](crate::Profiled) iterations and [`Profiler::scope`] record the CPU time
+///   spent in them and the GPU time of the dispatches encoded meanwhile.
+///
+/// [`Profiler::report`] returns the totals by label and starts a new period, e.g. a generation.
+#[derive(Clone, Debug, Default)]
+pub struct Profiler(Arc<Mutex<ProfilerState>>);
+
+impl Profiler {
+    /// Creates a profiler for the device
+    ///
+    /// Timestamps are measured if the device was created with
+    /// [`wgpu::Features::TIMESTAMP_QUERY`].
+    ///
+    /// # Arguments
+    /// * `device` - The device dispatches are executed on
+    /// * `enabled` - Whether to start recording right away
+    #[must_use]
+    pub fn new(device: &wgpu::Device, enabled: bool) -> Self {
+        let size = u64::from(QUERY_CAPACITY) * u64::from(wgpu::QUERY_SIZE);
+        let timestamps = device.features().contains(wgpu::Features::TIMESTAMP_QUERY).then(|| Timestamps {
+            query_set: device.create_query_set(&wgpu::QuerySetDescriptor {
+                label: Some("Profiler Timestamps"),
+                ty: wgpu::QueryType::Timestamp,
+                count: QUERY_CAPACITY,
+            }),
+            resolve: device.create_buffer(&wgpu::BufferDescriptor {
+                label: Some("Profiler Resolve"),
+                size,
+                usage: wgpu::BufferUsages::QUERY_RESOLVE | wgpu::BufferUsages::COPY_SRC,
+                mapped_at_creation: false,
+            }),
+            readback: device.create_buffer(&wgpu::BufferDescriptor {
+                label: Some("Profiler Readback"),
+                size,
+                usage: wgpu::BufferUsages::MAP_READ | wgpu::BufferUsages::COPY_DST,
+                mapped_at_creation: false,
+            }),
+        });
+
+        let state = ProfilerState { enabled, timestamps, ..ProfilerState::default() };
+        Self(Arc::new(Mutex::new(state)))
+    }
+
+    /// Returns true if dispatches and iterations are recorded
+    #[must_use]
+    pub fn is_enabled(&self) -> bool {
+        self.state().enabled
+    }
+
+    /// Starts or stops recording
+    pub fn set_enabled(&self, enabled: bool) {
+        self.state().enabled = enabled;
+    }
+
+    /// Returns true if GPU time is measured with timestamp queries
+    #[must_use]
+    pub fn has_timestamps(&self) -> bool {
+        self.state().timestamps.is_some()
+    }
+
+    /// Starts recording a CPU section, e.g. a selection step, until the returned guard is dropped
+    ///
+    /// Dispatches encoded while the guard lives add their GPU time to the section.
+    ///
+    /// # Arguments
+    /// * `label` - The label of the section in the report
+    #[must_use]
+    pub fn scope(&self, label: &str) -> ProfileScope {
+        let index = {
+            let mut state = self.state();
+            state.enabled.then(|| {
+                let scopes = state.scopes.clone();
+                state.records.push(Record {
+                    label: label.to_string(),
+                    kind: ProfileKind::Iteration,
+                    cpu: Duration::ZERO,
+                    query: None,
+                    scopes,
+                });
+
+                let index = state.records.len() - 1;
+                state.scopes.push(index);
+                index
+            })
+        };
+
+        ProfileScope { profiler: self.clone(), index, start: Instant::now() }
+    }
+
+    /// Returns the totals recorded since the previous report and clears the records
+    ///
+    /// Timestamps are read back from the device, so every command buffer with profiled
+    /// dispatches must have been submitted.
+    ///
+    /// # Arguments
+    /// * `context` - The context the profiled dispatches were executed on
+    ///
+    /// # Errors
+    /// Returns an error if the device is lost while reading the timestamps.
+    pub fn report(&self, context: &WgpuContext) -> Result<ProfileReport, Error> {
+        let (records, queries) = {
+            let mut state = self.state();
+            let queries = std::mem::take(&mut state.queries);
+            (std::mem::take(&mut state.records), queries)
+        };
+
+        let ticks = self.read_timestamps(context, queries)?;
+        let period = f64::from(context.queue.get_timestamp_period());
+        let gpu = |record: &Record| {
+            record.query.map(|query| {
+                let [begin, end] = [ticks[query as usize], ticks[query as usize + 1]];
+                Duration::from_nanos(end.saturating_sub(begin)).mul_f64(period)
+            })
+        };
+
+        let timestamps = self.has_timestamps();
+        let mut report = ProfileReport::default();
+        for (index, record) in records.iter().enumerate() {
+            let gpu = match record.kind {
+                ProfileKind::Dispatch => gpu(record),
+                ProfileKind::Iteration => timestamps.then(|| records
+                    .iter()
+                    .filter(|dispatch| dispatch.kind == ProfileKind::Dispatch && dispatch.scopes.contains(&index))
+                    .filter_map(gpu)
+                    .sum()),
+            };
+
+            report.add(&record.label, record.kind, record.cpu, gpu);
+        }
+
+        Ok(report)
+    }
+
+    /// Records a dispatch and returns the query set and first index of its timestamps
+    ///
+    /// Returns `None` if the profiler is disabled, timestamps are unsupported or the
+    /// query set is full until the next report.
+    pub(crate) fn begin_dispatch(&self, label: &str) -> Option<(wgpu::QuerySet, u32)> {
+        let mut state = self.state();
+        if !state.enabled {
+            return None;
+        }
+
+        let query = state.timestamps
+            .as_ref()
+            .filter(|_| state.queries + 2 <= QUERY_CAPACITY)
+            .map(|_| state.queries);
+        let scopes = state.scopes.clone();
+        state.records.push(Record { label: label.to_string(), kind: ProfileKind::Dispatch, cpu: Duration::ZERO, query, scopes });
+
+        let query = query?;
+        state.queries += 2;
+        state.timestamps.as_ref().map(|timestamps| (timestamps.query_set.clone(), query))
+    }
+
+    /// Waits for the device and adds the wall-clock time since `start` to the last dispatch
+    ///
+    /// Does nothing if the profiler is disabled or measures timestamps.
+    pub(crate) fn end_dispatch(&self, context: &WgpuContext, start: Instant) {
+        if !self.waits_for_device() {
+            return;
+        }
+
+        // A lost device shows up on the next submit, the measurement is best-effort
+        let _ = context.device.poll(wgpu::PollType::Wait);
+        if let Some(record) = self.state().records.iter_mut().rev().find(|record| record.kind == ProfileKind::Dispatch) {
+            record.cpu += start.elapsed();
+        }
+    }
+
+    /// Returns true if measurements without timestamps must wait for the device
+    pub(crate) fn waits_for_device(&self) -> bool {
+        let state = self.state();
+        state.enabled && state.timestamps.is_none()
+    }
+
+    fn end_scope(&self, index: usize, cpu: Duration) {
+        let mut state = self.state();
+        state.scopes.retain(|scope| *scope != index);
+        if let Some(record) = state.records.get_mut(index) {
+            record.cpu = cpu;
+        }
+    }
+
+    fn read_timestamps(&self, context: &WgpuContext, queries: u32) -> Result<Vec<u64>, Error> {
+        let state = self.state();
+        let Some(timestamps) = state.timestamps.as_ref().filter(|_| queries > 0) else {
+            return Ok(Vec::new());
+        };
+
+        let len = u64::from(queries) * u64::from(wgpu::QUERY_SIZE);
+        let mut encoder = context.device.create_command_encoder(
+            &wgpu::CommandEncoderDescriptor { label: Some("Profiler Resolve") }
+        );
+        encoder.resolve_query_set(&timestamps.query_set, 0..queries, &timestamps.resolve, 0);
+        encoder.copy_buffer_to_buffer(&timestamps.resolve, 0, &timestamps.readback, 0, len);
+        let request = MapRequest::submit(context, encoder, &timestamps.readback, len);
+        drop(state);
+
+        Ok(bytemuck::pod_collect_to_vec(&request.wait()?))
+    }
+
+    fn state(&self) -> MutexGuard<'_, ProfilerState> {
+        self.0.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+/// A section recorded by a [`Profiler`] until the guard is dropped.
+///
+/// Created by [`Profiler::scope`].
+#[derive(Debug)]
+pub struct ProfileScope {
+    profiler: Profiler,
+    index: Option<usize>,
+    start: Instant,
+}
+
+impl Drop for ProfileScope {
+    fn drop(&mut self) {
+        if let Some(index) = self.index {
+            self.profiler.end_scope(index, self.start.elapsed());
+        }
+    }
+}
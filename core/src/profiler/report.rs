@@ -0,0 +1,118 @@
+use std::{fmt, time::Duration};
+
+/// What a [`ProfileEntry`] measures.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ProfileKind {
+    /// An iteration wrapped in [`Profiled`](crate::Profiled)
+    Iteration,
+    /// The dispatches of a [`Shader`](crate::Shader)
+    Dispatch,
+}
+
+/// The totals of one label in a [`ProfileReport`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ProfileEntry {
+    /// The label of the iteration or shader
+    pub label: String,
+    /// What the entry measures
+    pub kind: ProfileKind,
+    /// The number of evaluations or dispatches
+    pub calls: usize,
+    /// The wall-clock time spent on the CPU
+    ///
+    /// For iterations this is the time spent in `evaluate*`. Without timestamp queries,
+    /// synchronous evaluations and dispatches wait for the device, so it includes the GPU work.
+    pub cpu: Duration,
+    /// The time measured by timestamp queries, `None` if timestamps are unsupported
+    ///
+    /// For iterations this is the total of the dispatches encoded while they were evaluated.
+    pub gpu: Option<Duration>,
+}
+
+/// The totals recorded by a [`Profiler`](crate::Profiler) since the previous report.
+///
+/// Entries are ordered by their first record. The [`fmt::Display`] implementation prints
+/// a table suitable for logs.
+///
+/// # Examples
+/// ```no_run
+/// use sgrmath_core::WgpuContext;
+///
+/// let context = WgpuContext::builder().profiling(true).build().unwrap();
+/// // ... execute shaders and iterations ...
+/// println!("{}", context.profiler.report(&context).unwrap());
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ProfileReport {
+    /// The totals by label
+    pub entries: Vec<ProfileEntry>,
+}
+
+impl ProfileReport {
+    /// Returns the entry with the given label and kind
+    #[must_use]
+    pub fn get(&self, label: &str, kind: ProfileKind) -> Option<&ProfileEntry> {
+        self.entries.iter().find(|entry| entry.label == label && entry.kind == kind)
+    }
+
+    /// Returns the total CPU time of the iterations
+    #[must_use]
+    pub fn cpu(&self) -> Duration {
+        self.entries
+            .iter()
+            .filter(|entry| entry.kind == ProfileKind::Iteration)
+            .map(|entry| entry.cpu)
+            .sum()
+    }
+
+    /// Returns the total GPU time of the dispatches, `None` if timestamps are unsupported
+    #[must_use]
+    pub fn gpu(&self) -> Option<Duration> {
+        self.entries
+            .iter()
+            .filter(|entry| entry.kind == ProfileKind::Dispatch)
+            .filter_map(|entry| entry.gpu)
+            .reduce(|total, gpu| total + gpu)
+    }
+
+    /// Adds a record to the entry of the label, creating it if needed
+    pub(crate) fn add(&mut self, label: &str, kind: ProfileKind, cpu: Duration, gpu: Option<Duration>) {
+        let index = self.entries
+            .iter()
+            .position(|entry| entry.label == label && entry.kind == kind)
+            .unwrap_or_else(|| {
+                self.entries.push(ProfileEntry { label: label.to_string(), kind, calls: 0, cpu: Duration::ZERO, gpu: None });
+                self.entries.len() - 1
+            });
+
+        let entry = &mut self.entries[index];
+        entry.calls += 1;
+        entry.cpu += cpu;
+        entry.gpu = match (entry.gpu, gpu) {
+            (Some(total), Some(gpu)) => Some(total + gpu),
+            (total, gpu) => total.or(gpu),
+        };
+    }
+}
+
+impl fmt::Display for ProfileReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let milliseconds = |duration: Duration| duration.as_secs_f64() * 1000.0;
+        let width = self.entries.iter().map(|entry| entry.label.len()).max().unwrap_or(0).max(5);
+
+        write!(f, "{:<width$}  {:<9}  {:>6}  {:>10}  {:>10}", "label", "kind", "calls", "cpu ms", "gpu ms")?;
+        for entry in &self.entries {
+            let kind = match entry.kind {
+                ProfileKind::Iteration => "iteration",
+                ProfileKind::Dispatch => "dispatch",
+            };
+            write!(f, "\n{:<width$}  {kind:<9}  {:>6}  {:>10.3}", entry.label, entry.calls, milliseconds(entry.cpu))?;
+            match entry.gpu {
+                Some(gpu) => write!(f, "  {:>10.3}", milliseconds(gpu))?,
+                None => write!(f, "  {:>10}", "-")?,
+            }
+        }
+
+        Ok(())
+    }
+}
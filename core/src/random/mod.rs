@@ -0,0 +1,5 @@
+mod random;
+mod stream;
+
+pub use random::{philox4x32, Random};
+pub use stream::{RandomKey, RandomStream, RandomStreams};
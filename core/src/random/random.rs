@@ -0,0 +1,106 @@
+use crate::RandomKey;
+
+/// The multipliers of the Philox4x32 rounds
+const PHILOX_M: [u32; 2] = [0xD251_1F53, 0xCD9E_8D57];
+/// The increments of the Philox4x32 round keys
+const PHILOX_W: [u32; 2] = [0x9E37_79B9, 0xBB67_AE85];
+
+/// Returns four random words for a counter and a key with the Philox4x32-10 generator
+///
+/// The same function as `random_philox` of the `sgrmath::random` shader module.
+///
+/// # Arguments
+/// * `counter` - The counter, every counter gives other words
+/// * `key` - The key, usually the seed
+#[must_use]
+#[allow(clippy::cast_lossless)]
+pub const fn philox4x32(counter: [u32; 4], key: [u32; 2]) -> [u32; 4] {
+    let (mut state, mut key) = (counter, key);
+    let mut round = 0;
+    while round < 10 {
+        let product0 = PHILOX_M[0] as u64 * state[0] as u64;
+        let product1 = PHILOX_M[1] as u64 * state[2] as u64;
+        state = [
+            (product1 >> 32) as u32 ^ state[1] ^ key[0],
+            product1 as u32,
+            (product0 >> 32) as u32 ^ state[3] ^ key[1],
+            product0 as u32,
+        ];
+        key = [key[0].wrapping_add(PHILOX_W[0]), key[1].wrapping_add(PHILOX_W[1])];
+        round += 1;
+    }
+
+    state
+}
+
+/// The random generator of a shader invocation, on the CPU.
+///
+/// Draws the same numbers as the `sgrmath::random` shader module for the same key and
+/// invocation index, e.g. to test a shader or to continue its stream on the CPU. Words,
+/// `[0, 1)` variates and integers are equal bit for bit, ranges up to rounding and normal
+/// variates up to the precision of the GPU's `log` and `cos`.
+///
+/// # Examples
+/// ```
+/// use sgrmath_core::{Random, RandomStream};
+///
+/// let key = RandomStream::new(1, 2).next_key();
+/// let mut random = Random::new(key, 0);
+///
+/// assert!(random.below(6) < 6);
+/// assert!((-1.0..1.0).contains(&random.range(-1.0, 1.0)));
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Random {
+    key: [u32; 2],
+    counter: [u32; 4],
+    values: [u32; 4],
+    used: usize,
+}
+
+impl Random {
+    /// Creates the generator of an invocation
+    ///
+    /// # Arguments
+    /// * `key` - The key of the dispatch
+    /// * `index` - The index of the invocation, unique in the dispatch
+    #[must_use]
+    pub const fn new(key: RandomKey, index: u32) -> Self {
+        Self { key: key.seed, counter: [index, 0, key.stream, key.counter], values: [0; 4], used: 4 }
+    }
+
+    /// Returns a uniformly distributed word
+    pub fn next_u32(&mut self) -> u32 {
+        if self.used == 4 {
+            self.values = philox4x32(self.counter, self.key);
+            self.counter[1] = self.counter[1].wrapping_add(1);
+            self.used = 0;
+        }
+        self.used += 1;
+        self.values[self.used - 1]
+    }
+
+    /// Returns a uniformly distributed value in `[0, 1)`
+    #[allow(clippy::cast_precision_loss)]
+    pub fn uniform(&mut self) -> f32 {
+        (self.next_u32() >> 8) as f32 * (1.0 / 16_777_216.0)
+    }
+
+    /// Returns a uniformly distributed value in `[min, max)`
+    #[allow(clippy::suboptimal_flops)]
+    pub fn range(&mut self, min: f32, max: f32) -> f32 {
+        min + (max - min) * self.uniform()
+    }
+
+    /// Returns a uniformly distributed integer in `[0, n)`
+    pub fn below(&mut self, n: u32) -> u32 {
+        ((u64::from(self.next_u32()) * u64::from(n)) >> 32) as u32
+    }
+
+    /// Returns a normally distributed value with zero mean and unit variance
+    pub fn normal(&mut self) -> f32 {
+        let radius = (-2.0 * (1.0 - self.uniform()).ln()).sqrt();
+        let angle = std::f32::consts::TAU * self.uniform();
+        radius * angle.cos()
+    }
+}
@@ -0,0 +1,131 @@
+use std::hash::{BuildHasher, Hasher};
+
+/// The key of a dispatch drawing random numbers with the `sgrmath::random` shader module.
+///
+/// Matches the WGSL struct `RandomKey`. Pass it to the shader, e.g. in its push constants,
+/// and start the generators of the invocations with `random_init(key, index)`. As push
+/// constants emulated by a uniform buffer require it, place it at the start of the struct.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, bytemuck::Pod, bytemuck::Zeroable)]
+pub struct RandomKey {
+    /// The seed of the generator, low word first
+    pub seed: [u32; 2],
+    /// The stream of the operator drawing the numbers
+    pub stream: u32,
+    /// The counter of the dispatch inside the stream
+    pub counter: u32,
+}
+
+/// A stream of random numbers for one operator.
+///
+/// Streams with the same seed and another id never share numbers, and every dispatch
+/// takes a new key with [`RandomStream::next_key`], so repeated dispatches of an operator
+/// draw new numbers without any state on the GPU.
+///
+/// # Examples
+/// ```
+/// use sgrmath_core::{Random, RandomStream};
+///
+/// let mut stream = RandomStream::new(42, 0);
+/// let first = stream.next_key();
+/// let second = stream.next_key();
+///
+/// assert_ne!(first, second);
+/// // The numbers of the invocation 7 of the first dispatch, as drawn by the shader module
+/// let value = Random::new(first, 7).uniform();
+/// assert!((0.0..1.0).contains(&value));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct RandomStream {
+    key: RandomKey,
+}
+
+impl RandomStream {
+    /// Creates a stream
+    ///
+    /// # Arguments
+    /// * `seed` - The seed shared by the streams of a run
+    /// * `stream` - The id of the stream
+    #[must_use]
+    pub const fn new(seed: u64, stream: u32) -> Self {
+        Self { key: RandomKey { seed: [seed as u32, (seed >> 32) as u32], stream, counter: 0 } }
+    }
+
+    /// Returns the key of the next dispatch without advancing the stream
+    #[must_use]
+    pub const fn key(&self) -> RandomKey {
+        self.key
+    }
+
+    /// Returns the key of the next dispatch and advances the stream
+    ///
+    /// The counter wraps around after `u32::MAX` dispatches.
+    pub fn next_key(&mut self) -> RandomKey {
+        let key = self.key;
+        self.key.counter = self.key.counter.wrapping_add(1);
+        key
+    }
+
+    /// Returns the number of dispatches taken from the stream
+    #[must_use]
+    pub const fn counter(&self) -> u32 {
+        self.key.counter
+    }
+
+    /// Moves the stream to a dispatch, e.g. to replay a run from a checkpoint
+    pub fn set_counter(&mut self, counter: u32) {
+        self.key.counter = counter;
+    }
+}
+
+/// Hands out the random streams of a run from a single seed.
+///
+/// # Examples
+/// ```
+/// use sgrmath_core::RandomStreams;
+///
+/// let mut streams = RandomStreams::new(42);
+/// let crossover = streams.stream();
+/// let mutation = streams.stream();
+///
+/// assert_ne!(crossover.key(), mutation.key());
+/// assert_eq!(RandomStreams::new(42).stream(), crossover);
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RandomStreams {
+    seed: u64,
+    next_stream: u32,
+}
+
+impl RandomStreams {
+    /// Creates the streams of a seed
+    #[must_use]
+    pub const fn new(seed: u64) -> Self {
+        Self { seed, next_stream: 0 }
+    }
+
+    /// Creates the streams of a seed taken from the randomly keyed hasher of the standard library
+    #[must_use]
+    pub fn from_entropy() -> Self {
+        Self::new(std::collections::hash_map::RandomState::new().build_hasher().finish())
+    }
+
+    /// Returns the seed of the streams
+    #[must_use]
+    pub const fn seed(&self) -> u64 {
+        self.seed
+    }
+
+    /// Returns the next unused stream
+    pub fn stream(&mut self) -> RandomStream {
+        let stream = self.stream_with_id(self.next_stream);
+        self.next_stream = self.next_stream.wrapping_add(1);
+        stream
+    }
+
+    /// Returns the stream with an id, independently of the streams handed out before
+    #[must_use]
+    pub const fn stream_with_id(&self, id: u32) -> RandomStream {
+        RandomStream::new(self.seed, id)
+    }
+}
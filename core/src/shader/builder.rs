@@ -0,0 +1,440 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::{CompositionError, Error, Shader, WgpuContext};
+
+use super::{shader::ENTRY_POINT, shader_cache::PipelineOptions};
+
+/// Modules every builder can include
+const BUILTIN_INCLUDES: [(&str, &str); 2] = [
+    ("sgrmath::dispatch", include_str!("wgsl/dispatch.wgsl")),
+    ("sgrmath::random", include_str!("wgsl/random.wgsl")),
+];
+
+/// A compile-time constant emitted as a WGSL `const` declaration.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ShaderConstant {
+    /// A `bool` constant
+    Bool(bool),
+    /// An `i32` constant
+    I32(i32),
+    /// A `u32` constant
+    U32(u32),
+    /// An `f32` constant
+    F32(f32),
+}
+
+impl ShaderConstant {
+    /// Returns the WGSL declaration of the constant
+    ///
+    /// # Arguments
+    /// * `name` - The name of the constant
+    #[must_use]
+    pub fn declaration(&self, name: &str) -> String {
+        match self {
+            Self::Bool(value) => format!("const {name}: bool = {value};"),
+            Self::I32(value) => format!("const {name}: i32 = {value}i;"),
+            Self::U32(value) => format!("const {name}: u32 = {value}u;"),
+            Self::F32(value) => format!("const {name}: f32 = {value:?}f;"),
+        }
+    }
+}
+
+impl From<bool> for ShaderConstant {
+    fn from(value: bool) -> Self {
+        Self::Bool(value)
+    }
+}
+
+impl From<i32> for ShaderConstant {
+    fn from(value: i32) -> Self {
+        Self::I32(value)
+    }
+}
+
+impl From<u32> for ShaderConstant {
+    fn from(value: u32) -> Self {
+        Self::U32(value)
+    }
+}
+
+impl From<f32> for ShaderConstant {
+    fn from(value: f32) -> Self {
+        Self::F32(value)
+    }
+}
+
+/// A builder composing a [`Shader`] from WGSL modules.
+///
+/// The source is run through a small preprocessor before it is compiled:
+///
+/// - `#include "name"` inserts a module registered with [`ShaderBuilder::include`].
+///   Every module is inserted once, so shared structs can be included by several modules.
+/// - `#ifdef NAME`, `#ifndef NAME`, `#else` and `#endif` keep or drop lines depending
+///   on the names set with [`ShaderBuilder::define`].
+/// - Constants set with [`ShaderBuilder::constant`] are declared as WGSL `const`s in front
+///   of the source, so they can size arrays and workgroups.
+/// - Overrides set with [`ShaderBuilder::override_constant`] are passed to the pipeline
+///   for the `override` declarations of the source.
+/// - Push constants set with [`ShaderBuilder::push_constants`] are declared after the source.
+///
+/// The built-in module `sgrmath::dispatch` provides `dispatch_index`, `dispatch_in_bounds`
+/// and `dispatch_position`, which recover the linear index of an invocation in a grid
+/// dispatched by [`Shader`] (see [`ShaderBuilder::workgroup_size`]).
+///
+/// The built-in module `sgrmath::random` provides a counter-based generator: start it with
+/// `random_init(key, index)` from a [`RandomKey`](crate::RandomKey) and draw with `random_u32`,
+/// `random_uniform`, `random_range`, `random_below` and `random_normal`
+/// (see [`RandomStream`](crate::RandomStream)).
+///
+/// Directives must be the only content of their line.
+///
+/// # Examples
+/// ```no_run
+/// use sgrmath_core::{Error, Shader, WgpuContext};
+///
+/// fn example(context: &WgpuContext) -> Result<Shader, Error> {
+///     Shader::builder(
+///         "scale",
+///         r#"
+///         #include "options"
+///
+///         @group(0) @binding(0) var<uniform> options: Options;
+///         @group(0) @binding(1) var<storage, read_write> values: array<f32, LENGTH>;
+///
+///         @compute @workgroup_size(1)
+///         fn main(@builtin(global_invocation_id) global_id: vec3<u32>) {
+///         #ifdef NEGATE
+///             values[global_id.x] = -values[global_id.x] * options.factor;
+///         #else
+///             values[global_id.x] = values[global_id.x] * options.factor;
+///         #endif
+///         }
+///         "#
+///     )
+///         .include("options", "struct Options { factor: f32 }")
+///         .constant("LENGTH", 16_u32)
+///         .define("NEGATE")
+///         .build(context)
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct ShaderBuilder {
+    label: String,
+    source: String,
+    includes: HashMap<String, String>,
+    constants: Vec<(String, ShaderConstant)>,
+    overrides: Vec<(String, f64)>,
+    defines: HashSet<String>,
+    entry_point: String,
+    push_constants: Option<(String, String)>,
+}
+
+impl ShaderBuilder {
+    /// Creates a builder for a shader with the given label and WGSL source
+    ///
+    /// # Arguments
+    /// * `label` - A label for debugging purposes
+    /// * `source` - The WGSL source containing the `main` entry point
+    #[must_use]
+    pub fn new<L, S>(label: L, source: S) -> Self
+    where
+        L: Into<String>,
+        S: Into<String>,
+    {
+        Self {
+            label: label.into(),
+            source: source.into(),
+            includes: BUILTIN_INCLUDES
+                .iter()
+                .map(|(name, source)| ((*name).to_string(), (*source).to_string()))
+                .collect(),
+            constants: Vec::new(),
+            overrides: Vec::new(),
+            defines: HashSet::new(),
+            entry_point: ENTRY_POINT.to_string(),
+            push_constants: None,
+        }
+    }
+
+    /// Selects the compute entry point, `main` by default
+    ///
+    /// Sources with several entry points can be built once per entry point;
+    /// every entry point gets its own pipeline and bindings.
+    #[must_use]
+    pub fn entry_point<N>(mut self, name: N) -> Self
+    where
+        N: Into<String>,
+    {
+        self.entry_point = name.into();
+        self
+    }
+
+    /// Declares a push constant variable set per dispatch with [`Shader::set_push_constants`]
+    ///
+    /// With [`wgpu::Features::PUSH_CONSTANTS`] the variable is declared as
+    /// `var<push_constant> name: type_name;`. Other devices get
+    /// `@group(3) @binding(0) var<uniform> name: type_name;` backed by a uniform buffer bound
+    /// with a dynamic offset per dispatch, so the source must not use
+    /// [`Shader::PUSH_CONSTANT_GROUP`] for its own bindings.
+    ///
+    /// # Arguments
+    /// * `name` - The name of the variable
+    /// * `type_name` - The WGSL type of the variable, usually a struct
+    #[must_use]
+    pub fn push_constants<N, T>(mut self, name: N, type_name: T) -> Self
+    where
+        N: Into<String>,
+        T: Into<String>,
+    {
+        self.push_constants = Some((name.into(), type_name.into()));
+        self
+    }
+
+    /// Registers a module that can be inserted with `#include "name"`
+    ///
+    /// Registering a name again replaces the module.
+    #[must_use]
+    pub fn include<N, S>(mut self, name: N, source: S) -> Self
+    where
+        N: Into<String>,
+        S: Into<String>,
+    {
+        self.includes.insert(name.into(), source.into());
+        self
+    }
+
+    /// Declares a WGSL `const` in front of the source
+    ///
+    /// Setting a name again replaces the value.
+    #[must_use]
+    pub fn constant<N, V>(mut self, name: N, value: V) -> Self
+    where
+        N: Into<String>,
+        V: Into<ShaderConstant>,
+    {
+        let name = name.into();
+        let value = value.into();
+        match self.constants.iter_mut().find(|(current, _)| *current == name) {
+            Some(constant) => constant.1 = value,
+            None => self.constants.push((name, value)),
+        }
+        self
+    }
+
+    /// Declares the `WORKGROUP_SIZE_X`, `WORKGROUP_SIZE_Y` and `WORKGROUP_SIZE_Z` constants
+    ///
+    /// The entry point uses them as
+    /// `@workgroup_size(WORKGROUP_SIZE_X, WORKGROUP_SIZE_Y, WORKGROUP_SIZE_Z)`, and the
+    /// `sgrmath::dispatch` helpers use them to recover the linear index:
+    ///
+    /// ```wgsl
+    /// #include "sgrmath::dispatch"
+    ///
+    /// @compute @workgroup_size(WORKGROUP_SIZE_X, WORKGROUP_SIZE_Y, WORKGROUP_SIZE_Z)
+    /// fn main(
+    ///     @builtin(global_invocation_id) global_id: vec3<u32>,
+    ///     @builtin(num_workgroups) num_workgroups: vec3<u32>,
+    /// ) {
+    ///     let size = vec3<u32>(options.width, options.height, 1u);
+    ///     let index = dispatch_index(global_id, num_workgroups);
+    ///     if (!dispatch_in_bounds(index, size)) {
+    ///         return;
+    ///     }
+    ///
+    ///     let position = dispatch_position(index, size);
+    /// }
+    /// ```
+    #[must_use]
+    pub fn workgroup_size(self, x: u32, y: u32, z: u32) -> Self {
+        self
+            .constant("WORKGROUP_SIZE_X", x)
+            .constant("WORKGROUP_SIZE_Y", y)
+            .constant("WORKGROUP_SIZE_Z", z)
+    }
+
+    /// Sets the value of a pipeline-overridable `override` constant
+    ///
+    /// The value may represent any of WGSL's concrete scalar types.
+    #[must_use]
+    pub fn override_constant<N>(mut self, name: N, value: f64) -> Self
+    where
+        N: Into<String>,
+    {
+        self.overrides.push((name.into(), value));
+        self
+    }
+
+    /// Defines a name checked by `#ifdef` and `#ifndef`
+    #[must_use]
+    pub fn define<N>(mut self, name: N) -> Self
+    where
+        N: Into<String>,
+    {
+        self.defines.insert(name.into());
+        self
+    }
+
+    /// Defines a name if `enabled` is true, e.g. to toggle a feature from a flag
+    #[must_use]
+    pub fn define_if<N>(self, name: N, enabled: bool) -> Self
+    where
+        N: Into<String>,
+    {
+        if enabled {
+            self.define(name)
+        } else {
+            self
+        }
+    }
+
+    /// Returns the WGSL source after includes, conditionals and constants are resolved
+    ///
+    /// Push constants are declared in their uniform form, see [`ShaderBuilder::push_constants`].
+    ///
+    /// # Errors
+    /// Returns [`Error::ShaderComposition`] if a directive is unknown or malformed,
+    /// a conditional is unbalanced or an included module is not registered.
+    pub fn compose(&self) -> Result<String, Error> {
+        self.compose_with(false)
+    }
+
+    /// Composes the source, declaring push constants natively or as a dynamic uniform
+    fn compose_with(&self, native_push_constants: bool) -> Result<String, Error> {
+        let mut output = String::new();
+        for (name, value) in &self.constants {
+            output.push_str(&value.declaration(name));
+            output.push('\n');
+        }
+
+        let mut included = HashSet::new();
+        self.expand(&mut output, &self.label, &self.source, &mut included)?;
+
+        if let Some((name, type_name)) = &self.push_constants {
+            let declaration = if native_push_constants {
+                format!("var<push_constant> {name}: {type_name};")
+            } else {
+                format!("@group({}) @binding(0) var<uniform> {name}: {type_name};", Shader::PUSH_CONSTANT_GROUP)
+            };
+            output.push_str(&declaration);
+            output.push('\n');
+        }
+
+        Ok(output)
+    }
+
+    /// Composes the source and compiles the shader
+    ///
+    /// # Arguments
+    /// * `context` - The WGPU context used to create the pipeline and shader module
+    ///
+    /// # Errors
+    /// * [`Error::ShaderComposition`] if the source cannot be composed
+    /// * [`Error::ShaderCompilation`] if the composed source cannot be parsed or validated
+    pub fn build(&self, context: &WgpuContext) -> Result<Shader, Error> {
+        let native = context.device.features().contains(wgpu::Features::PUSH_CONSTANTS)
+            && context.device.limits().max_push_constant_size > 0;
+        let source = self.compose_with(native)?;
+        let overrides = self.overrides
+            .iter()
+            .map(|(name, value)| (name.as_str(), *value))
+            .collect::<Vec<_>>();
+
+        Shader::create(context, &self.label, source, &PipelineOptions {
+            entry_point: &self.entry_point,
+            constants: &overrides,
+            dynamic_group: (self.push_constants.is_some() && !native).then_some(Shader::PUSH_CONSTANT_GROUP),
+        })
+    }
+
+    fn expand<'a>(
+        &'a self,
+        output: &mut String,
+        module: &str,
+        source: &'a str,
+        included: &mut HashSet<&'a str>,
+    ) -> Result<(), Error> {
+        let mut blocks: Vec<Block> = Vec::new();
+
+        for (index, line) in source.lines().enumerate() {
+            let error = |reason| Error::ShaderComposition {
+                label: self.label.clone(),
+                module: module.to_string(),
+                line: index + 1,
+                reason,
+            };
+            let active = blocks.last().map_or(true, Block::active);
+
+            let Some(directive) = line.trim().strip_prefix('#') else {
+                if active {
+                    output.push_str(line);
+                    output.push('\n');
+                }
+                continue;
+            };
+
+            let mut parts = directive.splitn(2, char::is_whitespace);
+            let keyword = parts.next().unwrap_or_default();
+            let argument = parts.next().map(str::trim).filter(|argument| !argument.is_empty());
+
+            match keyword {
+                "ifdef" | "ifndef" => {
+                    let name = argument.ok_or_else(|| error(CompositionError::MissingArgument(keyword.to_string())))?;
+                    blocks.push(Block {
+                        parent_active: active,
+                        condition: self.defines.contains(name) == (keyword == "ifdef"),
+                        in_else: false,
+                    });
+                }
+                "else" => match blocks.last_mut() {
+                    Some(block) if !block.in_else => block.in_else = true,
+                    _ => return Err(error(CompositionError::UnexpectedDirective(keyword.to_string()))),
+                },
+                "endif" => {
+                    blocks.pop().ok_or_else(|| error(CompositionError::UnexpectedDirective(keyword.to_string())))?;
+                }
+                "include" => {
+                    let name = argument
+                        .map(|argument| argument.trim_matches('"'))
+                        .ok_or_else(|| error(CompositionError::MissingArgument(keyword.to_string())))?;
+                    if !active {
+                        continue;
+                    }
+
+                    let (name, include) = self.includes
+                        .get_key_value(name)
+                        .ok_or_else(|| error(CompositionError::UnknownInclude(name.to_string())))?;
+                    if included.insert(name) {
+                        self.expand(output, name, include, included)?;
+                    }
+                }
+                _ => return Err(error(CompositionError::UnknownDirective(keyword.to_string()))),
+            }
+        }
+
+        if blocks.is_empty() {
+            Ok(())
+        } else {
+            Err(Error::ShaderComposition {
+                label: self.label.clone(),
+                module: module.to_string(),
+                line: source.lines().count(),
+                reason: CompositionError::UnterminatedConditional,
+            })
+        }
+    }
+}
+
+/// An open `#ifdef` or `#ifndef` block
+#[derive(Debug)]
+struct Block {
+    parent_active: bool,
+    condition: bool,
+    in_else: bool,
+}
+
+impl Block {
+    /// Returns true if the lines of the current branch are kept
+    const fn active(&self) -> bool {
+        self.parent_active && self.condition != self.in_else
+    }
+}
@@ -1,3 +1,10 @@
+mod builder;
+mod push_constants;
+mod reflection;
 mod shader;
+mod shader_cache;
 
+pub use builder::{ShaderBuilder, ShaderConstant};
+pub use reflection::{Access, AddressSpace, BindingInfo};
 pub use shader::*;
+pub use shader_cache::ShaderCache;
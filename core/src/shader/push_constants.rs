@@ -0,0 +1,98 @@
+use std::{
+    num::NonZeroU64,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc,
+    },
+};
+
+use crate::WgpuContext;
+
+/// The number of uniform slots cycled through by the push-constant fallback
+const UNIFORM_SLOTS: u64 = 256;
+
+/// A uniform buffer emulating push constants through dynamic offsets
+#[derive(Clone, Debug)]
+struct UniformSlots {
+    group: u32,
+    buffer: wgpu::Buffer,
+    bind_group: wgpu::BindGroup,
+    stride: u64,
+    /// The next slot, shared by the clones of a shader as they share the buffer
+    next: Arc<AtomicU64>,
+}
+
+/// The push constants of a shader and how they reach the GPU.
+///
+/// With [`wgpu::Features::PUSH_CONSTANTS`] the value is set on the compute pass.
+/// Otherwise every dispatch writes it into the next slot of a uniform buffer and binds
+/// that slot with a dynamic offset, so dispatches encoded before a submit keep their values
+/// as long as fewer than [`UNIFORM_SLOTS`] of them are pending.
+#[derive(Clone, Debug)]
+pub struct PushConstants {
+    data: Vec<u8>,
+    slots: Option<UniformSlots>,
+}
+
+impl PushConstants {
+    /// Creates zeroed push constants set on the compute pass
+    pub fn native(size: u32) -> Self {
+        Self { data: vec![0; size as usize], slots: None }
+    }
+
+    /// Creates zeroed push constants emulated by the uniform binding 0 of `group`
+    pub fn uniform(context: &WgpuContext, pipeline: &wgpu::ComputePipeline, group: u32, size: u64) -> Self {
+        let alignment = u64::from(context.device.limits().min_uniform_buffer_offset_alignment);
+        let stride = (size + alignment - 1) / alignment * alignment;
+        let buffer = context.device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("Push Constants"),
+            size: stride * UNIFORM_SLOTS,
+            usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
+        let bind_group = context.device.create_bind_group(&wgpu::BindGroupDescriptor {
+            label: Some("Push Constants"),
+            layout: &pipeline.get_bind_group_layout(group),
+            entries: &[wgpu::BindGroupEntry {
+                binding: 0,
+                resource: wgpu::BindingResource::Buffer(wgpu::BufferBinding {
+                    buffer: &buffer,
+                    offset: 0,
+                    size: NonZeroU64::new(size),
+                }),
+            }],
+        });
+
+        Self {
+            data: vec![0; size as usize],
+            slots: Some(UniformSlots { group, buffer, bind_group, stride, next: Arc::new(AtomicU64::new(0)) }),
+        }
+    }
+
+    /// Returns the size of the push constants in bytes
+    pub fn size(&self) -> usize {
+        self.data.len()
+    }
+
+    /// Returns the bind group emulating the push constants, if any
+    pub fn group(&self) -> Option<u32> {
+        self.slots.as_ref().map(|slots| slots.group)
+    }
+
+    /// Replaces the value used by the following dispatches
+    pub fn set(&mut self, data: &[u8]) {
+        self.data.copy_from_slice(data);
+    }
+
+    /// Makes the current value visible to the dispatches of the pass
+    pub fn apply(&self, context: &WgpuContext, pass: &mut wgpu::ComputePass<'_>) {
+        match &self.slots {
+            None => pass.set_push_constants(0, &self.data),
+            Some(slots) => {
+                let offset = slots.next.fetch_add(1, Ordering::Relaxed) % UNIFORM_SLOTS * slots.stride;
+                context.queue.write_buffer(&slots.buffer, offset, &self.data);
+                pass.set_bind_group(slots.group, &slots.bind_group, &[offset as u32]);
+            }
+        }
+    }
+}
@@ -0,0 +1,113 @@
+use naga::valid::{Capabilities, ValidationFlags, Validator};
+
+/// The address space of a buffer binding.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum AddressSpace {
+    /// `var<uniform>`, requires a buffer with [`wgpu::BufferUsages::UNIFORM`]
+    Uniform,
+    /// `var<storage>`, requires a buffer with [`wgpu::BufferUsages::STORAGE`]
+    Storage,
+}
+
+/// The access mode of a buffer binding.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Access {
+    /// The shader only reads the buffer (`var<uniform>` or `var<storage, read>`)
+    Read,
+    /// The shader reads and writes the buffer (`var<storage, read_write>`)
+    ReadWrite,
+}
+
+/// A buffer binding reflected from the WGSL module of a [`Shader`](crate::Shader).
+///
+/// Only bindings used by the shader's entry point are reflected, as only those
+/// are part of the pipeline layout.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct BindingInfo {
+    /// The name of the global variable
+    pub name: String,
+    /// The bind group index (`@group`)
+    pub group: u32,
+    /// The binding index inside the group (`@binding`)
+    pub binding: u32,
+    /// The address space of the variable
+    pub space: AddressSpace,
+    /// The access mode of the variable
+    pub access: Access,
+    /// The minimum size in bytes a bound buffer must have
+    ///
+    /// For runtime-sized arrays this is the size of a single element.
+    pub min_size: u64,
+}
+
+impl BindingInfo {
+    /// Returns the buffer usage a buffer bound to this binding must have
+    #[must_use]
+    pub const fn required_usage(&self) -> wgpu::BufferUsages {
+        match self.space {
+            AddressSpace::Uniform => wgpu::BufferUsages::UNIFORM,
+            AddressSpace::Storage => wgpu::BufferUsages::STORAGE,
+        }
+    }
+}
+
+/// The interface of a compute entry point
+#[derive(Debug)]
+pub struct Reflection {
+    /// The buffer bindings used by the entry point, sorted by group and binding index
+    pub bindings: Vec<BindingInfo>,
+    /// The declared `@workgroup_size`
+    pub workgroup_size: [u32; 3],
+    /// The size in bytes of the `var<push_constant>` used by the entry point, if any
+    pub push_constant_size: Option<u32>,
+}
+
+/// Parses and validates a WGSL module and reflects the interface of a compute entry point
+///
+/// # Returns
+/// The reflected interface, or the formatted naga error
+pub fn reflect(source: &str, entry_point: &str) -> Result<Reflection, String> {
+    let module = naga::front::wgsl::parse_str(source)
+        .map_err(|error| error.emit_to_string(source))?;
+    let info = Validator::new(ValidationFlags::all(), Capabilities::all())
+        .validate(&module)
+        .map_err(|error| error.emit_to_string(source))?;
+
+    let index = module.entry_points
+        .iter()
+        .position(|point| point.name == entry_point && point.stage == naga::ShaderStage::Compute)
+        .ok_or_else(|| format!("compute entry point `{entry_point}` not found"))?;
+    let uses = info.get_entry_point(index);
+
+    let used_globals = || module.global_variables.iter().filter(|(handle, _)| !uses[*handle].is_empty());
+    let push_constant_size = used_globals()
+        .find(|(_, variable)| variable.space == naga::AddressSpace::PushConstant)
+        .map(|(_, variable)| module.types[variable.ty].inner.size(module.to_ctx()));
+
+    let mut bindings = used_globals()
+        .filter_map(|(_, variable)| {
+            let binding = variable.binding.as_ref()?;
+            let (space, access) = match variable.space {
+                naga::AddressSpace::Uniform => (AddressSpace::Uniform, Access::Read),
+                naga::AddressSpace::Storage { access } if access.contains(naga::StorageAccess::STORE) => {
+                    (AddressSpace::Storage, Access::ReadWrite)
+                }
+                naga::AddressSpace::Storage { .. } => (AddressSpace::Storage, Access::Read),
+                _ => return None,
+            };
+
+            Some(BindingInfo {
+                name: variable.name.clone().unwrap_or_default(),
+                group: binding.group,
+                binding: binding.binding,
+                space,
+                access,
+                min_size: u64::from(module.types[variable.ty].inner.size(module.to_ctx())),
+            })
+        })
+        .collect::<Vec<_>>();
+
+    bindings.sort_by_key(|binding| (binding.group, binding.binding));
+
+    Ok(Reflection { bindings, workgroup_size: module.entry_points[index].workgroup_size, push_constant_size })
+}
@@ -1,24 +1,61 @@
-use std::borrow::Cow;
+use std::{borrow::Cow, time::Instant};
 
-use wgpu::{BindGroup, Buffer, ComputePipeline, PipelineCompilationOptions};
+use wgpu::{BindGroup, ComputePipeline};
 
-use crate::{Size, WgpuContext};
+use crate::{Bindable, BindingError, Error, Size, WgpuContext};
+
+use super::{
+    push_constants::PushConstants,
+    reflection::{Access, BindingInfo},
+    shader_cache::PipelineOptions,
+    ShaderBuilder,
+};
+
+/// The entry point shaders are compiled with unless the builder selects another
+pub const ENTRY_POINT: &str = "main";
 
 /// A wrapper around a wgpu compute pipeline that manages an optional bind group for resource binding.
 ///
-/// The `Shader` struct allows you to execute compute shaders with either a persistent bind group 
+/// The `Shader` struct allows you to execute compute shaders with either a persistent bind group
 /// (set via `bind`) or with parameters provided per-dispatch.
 ///
 /// - Use `bind` to set a bind group for repeated executions with the same resources.
 /// - Use `execute_with_params` or `execute_with_params_async` to dispatch with custom resources each time.
-/// - Use `is_bound` to check if a bind group is currently set.
+/// - Use `bind_group` to set the groups other than 0, e.g. static resources shared by every dispatch.
+/// - Use `set_push_constants` to pass small per-dispatch values (see [`ShaderBuilder::push_constants`]).
+/// - Use `is_bound` to check if all bind groups are currently set.
+/// - Enable `context.profiler` to record the time of every dispatch (see [`crate::Profiler`]).
+///
+/// The buffer bindings of the WGSL module are reflected when the shader is created
+/// (see [`Shader::bindings`]). Buffers passed to `bind` and `execute_with_params` are
+/// validated against them, so a wrong count, usage or size is reported as an [`Error`]
+/// naming the binding instead of a wgpu validation panic.
+///
+/// Dispatch sizes are numbers of logical invocations, not workgroups. The shader divides
+/// them by its declared `@workgroup_size` and folds counts above the device limit into
+/// the next dimension (see [`Shader::workgroup_count`]). Kernels therefore read their
+/// linear index with the `sgrmath::dispatch` helpers and bounds-check it
+/// (see [`ShaderBuilder::workgroup_size`]).
 ///
 /// This design provides flexibility for both reusable and dynamic resource binding scenarios.
 #[derive(Debug, Clone)]
-pub struct Shader(pub ComputePipeline, pub Option<BindGroup>);
+pub struct Shader {
+    /// The compute pipeline
+    pub pipeline: ComputePipeline,
+    /// The bind groups set by `bind` and `bind_group`, indexed by group
+    pub bind_groups: Vec<Option<BindGroup>>,
+    label: String,
+    bindings: Option<Vec<BindingInfo>>,
+    workgroup_size: [u32; 3],
+    push_constants: Option<PushConstants>,
+}
 
 impl Shader {
-    /// Creates a new `Shader` from WGSL source code and an optional pipeline layout.
+    /// The bind group emulating push constants on devices without
+    /// [`wgpu::Features::PUSH_CONSTANTS`]. Shaders declaring push constants must not use it.
+    pub const PUSH_CONSTANT_GROUP: u32 = 3;
+
+    /// Creates a new `Shader` from WGSL source code.
     ///
     /// # Arguments
     /// * `context` - The WGPU context used to create the pipeline and shader module.
@@ -27,164 +64,496 @@ impl Shader {
     ///
     /// # Returns
     /// A new `Shader` instance with no bind group set.
+    ///
+    /// # Panics
+    /// Panics with the compiler message if the WGSL source is invalid.
+    /// Use [`Shader::try_new`] to handle the error.
+    #[allow(clippy::panic)]
     pub fn new<'a, S>(
-        context: &WgpuContext, 
-        label: &str, 
+        context: &WgpuContext,
+        label: &str,
         source: S
-    ) -> Self  
+    ) -> Self
     where
         S: Into<Cow<'a, str>>,
     {
-        Self(
-            context.device.create_compute_pipeline(
-                &wgpu::ComputePipelineDescriptor {
-                    label: Some(&format!("Compute Pipeline: {label}")),
-                    layout: None,
-                    module: &context.device.create_shader_module(wgpu::ShaderModuleDescriptor {
-                        label: Some(label),
-                        source: wgpu::ShaderSource::Wgsl(source.into()),
-                    }),
-                    entry_point: Some("main"),
-                    compilation_options: PipelineCompilationOptions::default(),
-                    cache: None
-                }
-            ),
-            None
-        )
+        Self::try_new(context, label, source).unwrap_or_else(|error| panic!("{error}"))
+    }
+
+    /// Creates a new `Shader` from WGSL source code, reflecting its buffer bindings.
+    ///
+    /// # Arguments
+    /// * `context` - The WGPU context used to create the pipeline and shader module.
+    /// * `label` - A label for debugging purposes.
+    /// * `source` - The WGSL source code for the compute shader.
+    ///
+    /// # Errors
+    /// Returns [`Error::ShaderCompilation`] if the WGSL source cannot be parsed or validated.
+    pub fn try_new<'a, S>(
+        context: &WgpuContext,
+        label: &str,
+        source: S
+    ) -> Result<Self, Error>
+    where
+        S: Into<Cow<'a, str>>,
+    {
+        Self::create(context, label, source, &PipelineOptions { entry_point: ENTRY_POINT, constants: &[], dynamic_group: None })
+    }
+
+    /// Creates a builder composing a shader from WGSL modules,
+    /// with includes, compile-time constants and conditional blocks.
+    ///
+    /// # Arguments
+    /// * `label` - A label for debugging purposes.
+    /// * `source` - The WGSL source containing the `main` entry point.
+    ///
+    /// # Returns
+    /// A [`ShaderBuilder`] for the source.
+    #[must_use]
+    pub fn builder<L, S>(label: L, source: S) -> ShaderBuilder
+    where
+        L: Into<String>,
+        S: Into<String>,
+    {
+        ShaderBuilder::new(label, source)
+    }
+
+    /// Compiles the shader, reusing the pipeline cached by the context
+    /// if the same source was compiled before
+    pub(crate) fn create<'a, S>(
+        context: &WgpuContext,
+        label: &str,
+        source: S,
+        options: &PipelineOptions<'_>
+    ) -> Result<Self, Error>
+    where
+        S: Into<Cow<'a, str>>,
+    {
+        let source = source.into();
+        let cached = context.cache.get_or_create(context, label, &source, options)?;
+
+        let emulated = options.dynamic_group.and_then(|group| {
+            cached.bindings.iter().find(|binding| binding.group == group).map(|binding| (group, binding.min_size))
+        });
+        let push_constants = match (cached.push_constant_size, emulated) {
+            (Some(size), _) => Some(PushConstants::native(size)),
+            (None, Some((group, size))) => Some(PushConstants::uniform(context, &cached.pipeline, group, size)),
+            (None, None) => None,
+        };
+
+        // Groups without bindings are part of the layout too and are bound to empty groups
+        let group_count = cached.bindings.iter().map(|binding| binding.group as usize + 1).max().unwrap_or(0);
+        let bind_groups = (0..group_count as u32)
+            .map(|group| {
+                let empty = !cached.bindings.iter().any(|binding| binding.group == group);
+                empty.then(|| context.device.create_bind_group(&wgpu::BindGroupDescriptor {
+                    label: Some(label),
+                    layout: &cached.pipeline.get_bind_group_layout(group),
+                    entries: &[],
+                }))
+            })
+            .collect();
+
+        Ok(Self {
+            pipeline: cached.pipeline,
+            bind_groups,
+            label: label.to_string(),
+            bindings: Some(cached.bindings),
+            workgroup_size: cached.workgroup_size,
+            push_constants,
+        })
+    }
+
+    /// Returns the label of the shader.
+    #[must_use]
+    pub fn label(&self) -> &str {
+        &self.label
+    }
+
+    /// Returns the buffer bindings reflected from the WGSL module, sorted by group and binding.
+    ///
+    /// Shaders created from a bare [`ComputePipeline`] have no reflected bindings.
+    #[must_use]
+    pub fn bindings(&self) -> &[BindingInfo] {
+        self.bindings.as_deref().unwrap_or_default()
+    }
+
+    /// Returns the `@workgroup_size` declared by the entry point.
+    ///
+    /// Shaders created from a bare [`ComputePipeline`] are assumed to use `(1, 1, 1)`.
+    #[must_use]
+    pub const fn workgroup_size(&self) -> [u32; 3] {
+        self.workgroup_size
+    }
+
+    /// Returns the workgroup counts dispatched for a number of logical invocations.
+    ///
+    /// The invocations are divided by the workgroup size into a number of workgroups,
+    /// which is laid out along x and folded into y and z when it exceeds the device's
+    /// `max_compute_workgroups_per_dimension`. The grid may hold more invocations than
+    /// requested, so kernels bounds-check their linear index.
+    ///
+    /// # Arguments
+    /// * `context` - The WGPU context providing the device limits.
+    /// * `size` - The number of logical invocations.
+    ///
+    /// # Returns
+    /// The workgroup counts along x, y and z.
+    ///
+    /// # Panics
+    /// Panics if the invocations do not fit in the largest grid the device can dispatch.
+    pub fn workgroup_count<S>(&self, context: &WgpuContext, size: S) -> [u32; 3]
+    where
+        S: Into<Size>,
+    {
+        let [x, y, z] = self.workgroup_size;
+        let invocations = u64::from(x) * u64::from(y) * u64::from(z);
+        let groups = (size.into().len() as u64 + invocations - 1) / invocations;
+        if groups == 0 {
+            return [0, 0, 0];
+        }
+
+        let max = u64::from(context.device.limits().max_compute_workgroups_per_dimension);
+        let x = groups.min(max);
+        let rest = (groups + x - 1) / x;
+        let y = rest.min(max);
+        let z = (rest + y - 1) / y;
+        assert!(z <= max, "Dispatch of {groups} workgroups exceeds the device limits");
+
+        [x as u32, y as u32, z as u32]
     }
 
-    /// Sets the bind group for this shader, allowing repeated execution with the same resources.
+    /// Returns the size in bytes of the push constants used by the entry point, if any.
+    #[must_use]
+    pub fn push_constant_size(&self) -> Option<usize> {
+        self.push_constants.as_ref().map(PushConstants::size)
+    }
+
+    /// Sets the push constants used by the following dispatches.
+    ///
+    /// Dispatches encoded by `execute_async` keep the value set when they were encoded.
+    ///
+    /// # Arguments
+    /// * `value` - The value of the push constant variable, with the layout of its WGSL type.
+    ///
+    /// # Panics
+    /// Panics if the entry point uses no push constants or their size differs from `T`.
+    pub fn set_push_constants<T>(&mut self, value: &T)
+    where
+        T: bytemuck::Pod,
+    {
+        let push_constants = self.push_constants
+            .as_mut()
+            .expect("The shader does not use push constants");
+        assert_eq!(
+            push_constants.size(),
+            std::mem::size_of::<T>(),
+            "Push constants of shader `{}` have a different size",
+            self.label
+        );
+
+        push_constants.set(bytemuck::bytes_of(value));
+    }
+
+    /// Sets bind group 0 for this shader, allowing repeated execution with the same resources.
+    ///
+    /// # Arguments
+    /// * `context` - The WGPU context used to create the bind group.
+    /// * `params` - The buffer resources to bind, in binding order.
+    ///
+    /// # Errors
+    /// Returns an error naming the binding if the buffers do not match the shader bindings.
+    pub fn bind(&mut self, context: &WgpuContext, params: &[&dyn Bindable]) -> Result<(), Error> {
+        self.bind_group(context, 0, params)
+    }
+
+    /// Sets a bind group for this shader, used by every following dispatch.
+    ///
+    /// Group 0 is replaced by the buffers passed to `execute_with_params`, so per-call
+    /// resources go to group 0 and static resources to the other groups.
     ///
     /// # Arguments
     /// * `context` - The WGPU context used to create the bind group.
-    /// * `params` - The buffer resources to bind.
-    pub fn bind(&mut self, context: &WgpuContext, params: &[&Buffer]) {
-        self.1 = Some(self.create_bind_group(context, params));
+    /// * `group` - The bind group index (`@group`).
+    /// * `params` - The buffer resources to bind, in binding order.
+    ///
+    /// # Errors
+    /// * [`Error::InvalidGroup`] if the entry point does not use the group
+    /// * An error naming the binding if the buffers do not match the shader bindings
+    pub fn bind_group(&mut self, context: &WgpuContext, group: u32, params: &[&dyn Bindable]) -> Result<(), Error> {
+        let bind_group = self.create_bind_group(context, group, params)?;
+        let index = group as usize;
+        if self.bind_groups.len() <= index {
+            self.bind_groups.resize(index + 1, None);
+        }
+
+        self.bind_groups[index] = Some(bind_group);
+        Ok(())
     }
 
-    /// Removes the currently set bind group from this shader.
+    /// Removes the bind groups set by `bind` and `bind_group` from this shader.
     pub fn unbind(&mut self) {
-        self.1 = None;
+        let bindings = self.bindings.as_deref();
+        for (group, bind_group) in self.bind_groups.iter_mut().enumerate() {
+            if bindings.map_or(true, |bindings| bindings.iter().any(|binding| binding.group as usize == group)) {
+                *bind_group = None;
+            }
+        }
     }
 
     /// Executes the compute shader using the currently bound bind group.
     ///
     /// # Arguments
     /// * `context` - The WGPU context used for command submission.
-    /// * `size` - The dispatch size (workgroup counts) for the compute shader.
+    /// * `size` - The number of logical invocations.
     ///
     /// # Panics
     /// Panics if no bind group is currently set. Use `bind` to set one.
-    pub fn execute<S>(&self, context: &WgpuContext, size: S) 
+    pub fn execute<S>(&self, context: &WgpuContext, size: S)
     where
         S: Into<Size>,
     {
+        let start = Instant::now();
         context.queue.submit(Some(self.execute_async(context, size)));
+        context.profiler.end_dispatch(context, start);
     }
 
     /// Executes the compute shader with a custom set of buffer parameters, creating a bind group on the fly.
     ///
     /// # Arguments
     /// * `context` - The WGPU context used for command submission.
-    /// * `size` - The dispatch size (workgroup counts) for the compute shader.
-    /// * `params` - The buffer resources to bind for this execution.
-    pub fn execute_with_params<S>(&self, context: &WgpuContext, size: S, params: &[&Buffer]) 
+    /// * `size` - The number of logical invocations.
+    /// * `params` - The buffer resources to bind for this execution, in binding order.
+    ///
+    /// # Errors
+    /// Returns an error naming the binding if the buffers do not match the shader bindings.
+    pub fn execute_with_params<S>(&self, context: &WgpuContext, size: S, params: &[&dyn Bindable]) -> Result<(), Error>
     where
         S: Into<Size>,
     {
-        context.queue.submit(Some(self.execute_with_params_async(context, size, params)));
+        let start = Instant::now();
+        context.queue.submit(Some(self.execute_with_params_async(context, size, params)?));
+        context.profiler.end_dispatch(context, start);
+        Ok(())
     }
 
     /// Returns a command buffer for executing the compute shader with the currently bound bind group.
     ///
     /// # Arguments
     /// * `context` - The WGPU context used for command encoding.
-    /// * `size` - The dispatch size (workgroup counts) for the compute shader.
+    /// * `size` - The number of logical invocations.
     ///
     /// # Returns
     /// A `wgpu::CommandBuffer` ready for submission.
     ///
     /// # Panics
-    /// Panics if no bind group is currently set. Use `bind` to set one.
+    /// Panics if a bind group is not set. Use `bind` and `bind_group` to set them.
     #[must_use]
-    #[allow(clippy::panic)]
     pub fn execute_async<S>(
-        &self, 
-        context: &WgpuContext, 
+        &self,
+        context: &WgpuContext,
         size: S
     ) -> wgpu::CommandBuffer
     where
         S: Into<Size>,
     {
-        self.1
-            .as_ref()
-            .map_or_else(
-                || panic!("No bind group found. Use `bind` to bind parameters to the shader."), 
-                |bind_group| self.execute_with_bind_group(context, size, bind_group)
-            )
-    }
-    
+        self.encode(context, size, None)
+    }
+
     /// Returns a command buffer for executing the compute shader with a custom set of buffer parameters.
     ///
     /// # Arguments
     /// * `context` - The WGPU context used for command encoding.
-    /// * `size` - The dispatch size (workgroup counts) for the compute shader.
-    /// * `params` - The buffer resources to bind for this execution.
+    /// * `size` - The number of logical invocations.
+    /// * `params` - The buffer resources to bind for this execution, in binding order.
     ///
     /// # Returns
     /// A `wgpu::CommandBuffer` ready for submission.
+    ///
+    /// # Errors
+    /// Returns an error naming the binding if the buffers do not match the shader bindings.
+    ///
+    /// # Panics
+    /// Panics if a bind group other than 0 is not set. Use `bind_group` to set them.
     pub fn execute_with_params_async<S>(
-        &self, 
-        context: &WgpuContext, 
-        size: S, 
-        params: &[&Buffer]
-    ) -> wgpu::CommandBuffer
+        &self,
+        context: &WgpuContext,
+        size: S,
+        params: &[&dyn Bindable]
+    ) -> Result<wgpu::CommandBuffer, Error>
     where
         S: Into<Size>,
     {
-        self.execute_with_bind_group(context, size, &self.create_bind_group(context, params))
+        Ok(self.encode(context, size, Some(&self.create_bind_group(context, 0, params)?)))
     }
 
-    fn execute_with_bind_group<S>(&self, context: &WgpuContext, size: S, bind_group: &BindGroup) -> wgpu::CommandBuffer
+    /// Checks the buffers against the reflected bindings of group 0.
+    ///
+    /// See [`Shader::validate_group`].
+    ///
+    /// # Errors
+    /// * [`Error::BindingCount`] if the number of buffers does not match the bindings
+    /// * [`Error::InvalidBinding`] if a buffer lacks the required usage, is too small,
+    ///   or is bound twice while one of the bindings is `read_write`
+    pub fn validate(&self, params: &[&dyn Bindable]) -> Result<(), Error> {
+        self.validate_group(0, params)
+    }
+
+    /// Checks the buffers against the reflected bindings of a bind group.
+    ///
+    /// `params[i]` is bound to `@binding(i)` of the group. Buffers at indices the entry
+    /// point does not use are ignored.
+    ///
+    /// # Errors
+    /// * [`Error::InvalidGroup`] if the entry point does not use the group
+    /// * [`Error::BindingCount`] if the number of buffers does not match the bindings
+    /// * [`Error::InvalidBinding`] if a buffer lacks the required usage, is too small,
+    ///   or is bound twice while one of the bindings is `read_write`
+    pub fn validate_group(&self, group: u32, params: &[&dyn Bindable]) -> Result<(), Error> {
+        let Some(bindings) = &self.bindings else {
+            return Ok(());
+        };
+        let emulated = self.push_constants.as_ref().and_then(PushConstants::group);
+        let bindings = bindings.iter().filter(|binding| binding.group == group).collect::<Vec<_>>();
+        if bindings.is_empty() || emulated == Some(group) {
+            return Err(Error::InvalidGroup { shader: self.label.clone(), group });
+        }
+
+        let expected = bindings.last().map_or(0, |binding| binding.binding as usize + 1);
+        if params.len() != expected {
+            return Err(Error::BindingCount { shader: self.label.clone(), group, expected, actual: params.len() });
+        }
+
+        for (position, binding) in bindings.iter().enumerate() {
+            let buffer = params[binding.binding as usize];
+
+            let required = binding.required_usage();
+            let actual = buffer.buffer().usage();
+            if !actual.contains(required) {
+                return Err(self.invalid_binding(binding, BindingError::MissingUsage { required, actual }));
+            }
+
+            let size = buffer.binding_size();
+            if size < binding.min_size {
+                return Err(self.invalid_binding(binding, BindingError::TooSmall { size, min_size: binding.min_size }));
+            }
+
+            let aliased = bindings[..position].iter().find(|other| {
+                params[other.binding as usize].buffer() == buffer.buffer()
+                    && (other.access == Access::ReadWrite || binding.access == Access::ReadWrite)
+            });
+            if let Some(other) = aliased {
+                return Err(self.invalid_binding(binding, BindingError::Aliased { other: other.binding }));
+            }
+        }
+
+        Ok(())
+    }
+
+    fn invalid_binding(&self, binding: &BindingInfo, reason: BindingError) -> Error {
+        Error::InvalidBinding {
+            shader: self.label.clone(),
+            group: binding.group,
+            binding: binding.binding,
+            name: binding.name.clone(),
+            reason
+        }
+    }
+
+    /// Encodes a dispatch, binding `group_zero` instead of the bind group set for group 0
+    #[allow(clippy::panic)]
+    fn encode<S>(&self, context: &WgpuContext, size: S, group_zero: Option<&BindGroup>) -> wgpu::CommandBuffer
     where
         S: Into<Size>,
     {
-        let s = size.into();
-        
+        let [x, y, z] = self.workgroup_count(context, size);
+        let emulated = self.push_constants.as_ref().and_then(PushConstants::group);
+
+        let timestamps = context.profiler.begin_dispatch(&self.label);
         let mut encoder = context.device.create_command_encoder(&wgpu::CommandEncoderDescriptor::default());
-        
+
         {
-            let mut compute_pass = encoder.begin_compute_pass(&wgpu::ComputePassDescriptor::default());
-            compute_pass.set_pipeline(&self.0);
-            compute_pass.set_bind_group(0, bind_group, &[]);
-            compute_pass.dispatch_workgroups(s.width as u32, s.height as u32, s.depth as u32);
+            let mut compute_pass = encoder.begin_compute_pass(&wgpu::ComputePassDescriptor {
+                label: Some(&self.label),
+                timestamp_writes: timestamps.as_ref().map(|(query_set, index)| wgpu::ComputePassTimestampWrites {
+                    query_set,
+                    beginning_of_pass_write_index: Some(*index),
+                    end_of_pass_write_index: Some(index + 1),
+                }),
+            });
+            compute_pass.set_pipeline(&self.pipeline);
+
+            for (group, bind_group) in self.bind_groups.iter().enumerate() {
+                if emulated == Some(group as u32) {
+                    continue;
+                }
+
+                let bind_group = group_zero
+                    .filter(|_| group == 0)
+                    .or(bind_group.as_ref())
+                    .unwrap_or_else(|| panic!(
+                        "No bind group found for group {group}. Use `bind` or `bind_group` to bind parameters to the shader."
+                    ));
+                compute_pass.set_bind_group(group as u32, bind_group, &[]);
+            }
+            if let Some(push_constants) = &self.push_constants {
+                push_constants.apply(context, &mut compute_pass);
+            }
+
+            compute_pass.dispatch_workgroups(x, y, z);
         }
 
         encoder.finish()
     }
 
-    fn create_bind_group(&self, context: &WgpuContext, params: &[&Buffer]) -> BindGroup {
-        context.device.create_bind_group(&wgpu::BindGroupDescriptor {
-            label: None,
-            layout: &self.0.get_bind_group_layout(0),
-            entries: params
+    fn create_bind_group(&self, context: &WgpuContext, group: u32, params: &[&dyn Bindable]) -> Result<BindGroup, Error> {
+        self.validate_group(group, params)?;
+
+        // Only bindings used by the entry point are part of the layout
+        let entries = self.bindings.as_ref().map_or_else(
+            || params
                 .iter()
                 .enumerate()
-                .map(|(i, buffer)| wgpu::BindGroupEntry { binding: i as u32, resource: buffer.as_entire_binding() })
+                .map(|(i, buffer)| wgpu::BindGroupEntry { binding: i as u32, resource: buffer.as_binding() })
+                .collect::<Vec<_>>(),
+            |bindings| bindings
+                .iter()
+                .filter(|binding| binding.group == group)
+                .map(|binding| wgpu::BindGroupEntry {
+                    binding: binding.binding,
+                    resource: params[binding.binding as usize].as_binding()
+                })
                 .collect::<Vec<_>>()
-                .as_slice(),
-        })
+        );
+
+        Ok(context.device.create_bind_group(&wgpu::BindGroupDescriptor {
+            label: Some(&self.label),
+            layout: &self.pipeline.get_bind_group_layout(group),
+            entries: &entries,
+        }))
     }
 
-    /// Returns `true` if a bind group is currently set for this shader.
+    /// Returns `true` if every bind group used by the shader is currently set.
     #[must_use]
-    pub const fn is_bound(&self) -> bool {
-        self.1.is_some()
+    pub fn is_bound(&self) -> bool {
+        let emulated = self.push_constants.as_ref().and_then(PushConstants::group);
+
+        !self.bind_groups.is_empty() && self.bind_groups
+            .iter()
+            .enumerate()
+            .all(|(group, bind_group)| bind_group.is_some() || emulated == Some(group as u32))
     }
 }
 
 impl From<wgpu::ComputePipeline> for Shader {
     fn from(pipeline: wgpu::ComputePipeline) -> Self {
-        Self(pipeline, None)
+        Self {
+            pipeline,
+            bind_groups: vec![None],
+            label: String::new(),
+            bindings: None,
+            workgroup_size: [1, 1, 1],
+            push_constants: None,
+        }
     }
-}
\ No newline at end of file
+}
@@ -0,0 +1,307 @@
+use std::{
+    collections::{hash_map::DefaultHasher, HashMap},
+    hash::{Hash, Hasher},
+    num::NonZeroU64,
+    path::{Path, PathBuf},
+    sync::{Arc, Mutex},
+};
+
+use wgpu::PipelineCompilationOptions;
+
+use crate::{Access, AddressSpace, BindingInfo, Error, WgpuContext};
+
+use super::reflection::{reflect, Reflection};
+
+/// Identifies a compiled pipeline
+#[derive(Debug, PartialEq, Eq, Hash)]
+struct PipelineKey {
+    source: u64,
+    entry_point: String,
+    /// Pipeline-overridable constants, with the values as bits
+    constants: Vec<(String, u64)>,
+    dynamic_group: Option<u32>,
+}
+
+/// How a pipeline is compiled
+#[derive(Clone, Copy, Debug)]
+pub struct PipelineOptions<'a> {
+    /// The compute entry point
+    pub entry_point: &'a str,
+    /// Values of pipeline-overridable constants
+    pub constants: &'a [(&'a str, f64)],
+    /// The bind group whose bindings use dynamic offsets
+    pub dynamic_group: Option<u32>,
+}
+
+/// A compiled pipeline together with the reflected interface of its entry point
+#[derive(Clone, Debug)]
+pub struct CachedPipeline {
+    /// The source, kept to tell hash collisions apart
+    source: Arc<str>,
+    /// The compiled pipeline
+    pub pipeline: wgpu::ComputePipeline,
+    /// The buffer bindings used by the entry point
+    pub bindings: Vec<BindingInfo>,
+    /// The declared `@workgroup_size`
+    pub workgroup_size: [u32; 3],
+    /// The size in bytes of the push constants used by the entry point
+    pub push_constant_size: Option<u32>,
+}
+
+/// A `wgpu::PipelineCache` persisted to a file
+#[derive(Debug)]
+struct PersistentCache {
+    cache: wgpu::PipelineCache,
+    path: PathBuf,
+}
+
+impl PersistentCache {
+    fn save(&self) -> Result<(), Error> {
+        let Some(data) = self.cache.get_data() else {
+            return Ok(());
+        };
+
+        // Written next to the target first, so a crash never leaves a truncated blob
+        let temporary = self.path.with_extension("tmp");
+        std::fs::write(&temporary, data)
+            .and_then(|()| std::fs::rename(&temporary, &self.path))
+            .map_err(|source| Error::PipelineCache { path: self.path.clone(), source })
+    }
+}
+
+#[derive(Debug, Default)]
+struct CacheState {
+    pipelines: HashMap<PipelineKey, CachedPipeline>,
+    persistent: Option<PersistentCache>,
+}
+
+impl Drop for CacheState {
+    fn drop(&mut self) {
+        if let Some(persistent) = &self.persistent {
+            // Errors cannot be reported here, use `ShaderCache::save` to handle them
+            let _ = persistent.save();
+        }
+    }
+}
+
+/// A cache of compiled compute pipelines shared by the shaders of a context.
+///
+/// Pipelines are keyed by a hash of the WGSL source, the entry point and the
+/// pipeline-overridable constants, so creating the same [`Shader`](crate::Shader) again,
+/// e.g. in every clone of a problem, reuses the compiled pipeline and its reflection.
+///
+/// Every [`WgpuContext`] owns a cache, available as `context.cache`. Cloning the cache
+/// shares it.
+///
+/// When the context is built with
+/// [`WgpuContextBuilder::pipeline_cache_dir`](crate::WgpuContextBuilder::pipeline_cache_dir)
+/// and the adapter supports [`wgpu::Features::PIPELINE_CACHE`], the driver's pipeline cache
+/// is loaded from that directory and written back by [`ShaderCache::save`] and when the
+/// last clone of the cache is dropped, so later runs compile faster.
+#[derive(Clone, Debug, Default)]
+pub struct ShaderCache(Arc<Mutex<CacheState>>);
+
+impl ShaderCache {
+    /// Creates an empty in-memory cache
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a cache backed by a `wgpu::PipelineCache` persisted in a directory
+    ///
+    /// The blob is stored in a file named by [`wgpu::util::pipeline_cache_key`], so
+    /// caches of different adapters and drivers do not overwrite each other. Falls back
+    /// to an in-memory cache if the device lacks [`wgpu::Features::PIPELINE_CACHE`] or
+    /// the adapter has no cache key.
+    ///
+    /// # Arguments
+    /// * `adapter` - The adapter the device was created from
+    /// * `device` - The device pipelines are created on
+    /// * `directory` - The directory holding the cache files, created if missing
+    ///
+    /// # Errors
+    /// Returns [`Error::PipelineCache`] if the directory cannot be created
+    /// or an existing cache file cannot be read.
+    pub fn persistent(adapter: &wgpu::Adapter, device: &wgpu::Device, directory: &Path) -> Result<Self, Error> {
+        let key = wgpu::util::pipeline_cache_key(&adapter.get_info());
+        let Some(key) = key.filter(|_| device.features().contains(wgpu::Features::PIPELINE_CACHE)) else {
+            return Ok(Self::new());
+        };
+
+        let error = |path: &Path| {
+            let path = path.to_path_buf();
+            move |source| Error::PipelineCache { path, source }
+        };
+        std::fs::create_dir_all(directory).map_err(error(directory))?;
+
+        let path = directory.join(key);
+        let data = match std::fs::read(&path) {
+            Ok(data) => Some(data),
+            Err(source) if source.kind() == std::io::ErrorKind::NotFound => None,
+            Err(source) => return Err(error(&path)(source)),
+        };
+
+        // SAFETY: the data was written by `PersistentCache::save` from `PipelineCache::get_data`
+        // of an adapter with the same cache key, and invalid data falls back to an empty cache
+        let cache = unsafe {
+            device.create_pipeline_cache(&wgpu::PipelineCacheDescriptor {
+                label: Some("sgrmath pipeline cache"),
+                data: data.as_deref(),
+                fallback: true,
+            })
+        };
+
+        let state = CacheState { pipelines: HashMap::new(), persistent: Some(PersistentCache { cache, path }) };
+        Ok(Self(Arc::new(Mutex::new(state))))
+    }
+
+    /// Returns true if the cache is persisted to a directory
+    #[must_use]
+    pub fn is_persistent(&self) -> bool {
+        self.0.lock().is_ok_and(|state| state.persistent.is_some())
+    }
+
+    /// Returns the number of cached pipelines
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.0.lock().map_or(0, |state| state.pipelines.len())
+    }
+
+    /// Returns true if no pipeline is cached
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Releases all cached pipelines
+    ///
+    /// Shaders created from them keep working; the persisted driver cache is kept.
+    pub fn clear(&self) {
+        if let Ok(mut state) = self.0.lock() {
+            state.pipelines.clear();
+        }
+    }
+
+    /// Writes the driver's pipeline cache to its file
+    ///
+    /// Does nothing for in-memory caches.
+    ///
+    /// # Errors
+    /// Returns [`Error::PipelineCache`] if the file cannot be written.
+    ///
+    /// # Panics
+    /// Panics if a thread panicked while holding the cache lock.
+    pub fn save(&self) -> Result<(), Error> {
+        let state = self.0.lock().expect("Shader cache lock poisoned");
+        state.persistent.as_ref().map_or(Ok(()), PersistentCache::save)
+    }
+
+    /// Returns the cached pipeline for the source, compiling it on a miss
+    pub(crate) fn get_or_create(
+        &self,
+        context: &WgpuContext,
+        label: &str,
+        source: &str,
+        options: &PipelineOptions<'_>,
+    ) -> Result<CachedPipeline, Error> {
+        let mut hasher = DefaultHasher::new();
+        source.hash(&mut hasher);
+        let key = PipelineKey {
+            source: hasher.finish(),
+            entry_point: options.entry_point.to_string(),
+            constants: options.constants
+                .iter()
+                .map(|(name, value)| ((*name).to_string(), value.to_bits()))
+                .collect(),
+            dynamic_group: options.dynamic_group,
+        };
+
+        let mut state = self.0.lock().expect("Shader cache lock poisoned");
+        if let Some(cached) = state.pipelines.get(&key).filter(|cached| &*cached.source == source) {
+            return Ok(cached.clone());
+        }
+
+        let Reflection { bindings, workgroup_size, push_constant_size } = reflect(source, options.entry_point)
+            .map_err(|message| Error::ShaderCompilation { label: label.to_string(), message })?;
+
+        // The automatic layout has neither push constants nor dynamic offsets
+        let layout = (push_constant_size.is_some() || options.dynamic_group.is_some())
+            .then(|| create_layout(context, label, &bindings, push_constant_size, options.dynamic_group));
+
+        let pipeline = context.device.create_compute_pipeline(
+            &wgpu::ComputePipelineDescriptor {
+                label: Some(&format!("Compute Pipeline: {label}")),
+                layout: layout.as_ref(),
+                module: &context.device.create_shader_module(wgpu::ShaderModuleDescriptor {
+                    label: Some(label),
+                    source: wgpu::ShaderSource::Wgsl(source.into()),
+                }),
+                entry_point: Some(options.entry_point),
+                compilation_options: PipelineCompilationOptions {
+                    constants: options.constants,
+                    ..PipelineCompilationOptions::default()
+                },
+                cache: state.persistent.as_ref().map(|persistent| &persistent.cache),
+            }
+        );
+
+        let cached = CachedPipeline { source: source.into(), pipeline, bindings, workgroup_size, push_constant_size };
+        state.pipelines.insert(key, cached.clone());
+        drop(state);
+
+        Ok(cached)
+    }
+}
+
+/// Creates a pipeline layout from the reflected bindings
+///
+/// Groups without bindings get empty layouts, the bindings of `dynamic_group` use dynamic offsets.
+fn create_layout(
+    context: &WgpuContext,
+    label: &str,
+    bindings: &[BindingInfo],
+    push_constant_size: Option<u32>,
+    dynamic_group: Option<u32>,
+) -> wgpu::PipelineLayout {
+    let group_count = bindings.iter().map(|binding| binding.group + 1).max().unwrap_or(0);
+    let group_layouts = (0..group_count)
+        .map(|group| {
+            let entries = bindings
+                .iter()
+                .filter(|binding| binding.group == group)
+                .map(|binding| wgpu::BindGroupLayoutEntry {
+                    binding: binding.binding,
+                    visibility: wgpu::ShaderStages::COMPUTE,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: match binding.space {
+                            AddressSpace::Uniform => wgpu::BufferBindingType::Uniform,
+                            AddressSpace::Storage => wgpu::BufferBindingType::Storage {
+                                read_only: binding.access == Access::Read,
+                            },
+                        },
+                        has_dynamic_offset: dynamic_group == Some(group),
+                        min_binding_size: NonZeroU64::new(binding.min_size),
+                    },
+                    count: None,
+                })
+                .collect::<Vec<_>>();
+
+            context.device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+                label: Some(label),
+                entries: &entries,
+            })
+        })
+        .collect::<Vec<_>>();
+
+    let push_constant_ranges = push_constant_size
+        .map(|size| wgpu::PushConstantRange { stages: wgpu::ShaderStages::COMPUTE, range: 0..size })
+        .into_iter()
+        .collect::<Vec<_>>();
+
+    context.device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+        label: Some(label),
+        bind_group_layouts: &group_layouts.iter().collect::<Vec<_>>(),
+        push_constant_ranges: &push_constant_ranges,
+    })
+}
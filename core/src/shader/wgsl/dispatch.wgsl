@@ -0,0 +1,23 @@
+// Linear invocation indices for grids dispatched by `Shader`.
+//
+// `Shader` lays the workgroups of a dispatch out along x and folds them into y and z
+// above the device limit, so `global_invocation_id` does not map to the logical size.
+// Requires the `WORKGROUP_SIZE_X`, `WORKGROUP_SIZE_Y` and `WORKGROUP_SIZE_Z` constants
+// declared by `ShaderBuilder::workgroup_size`.
+
+// Returns the linear index of an invocation in the dispatched grid
+fn dispatch_index(global_id: vec3<u32>, num_workgroups: vec3<u32>) -> u32 {
+    let width = num_workgroups.x * WORKGROUP_SIZE_X;
+    let height = num_workgroups.y * WORKGROUP_SIZE_Y;
+    return global_id.x + width * (global_id.y + height * global_id.z);
+}
+
+// Returns true if a linear index lies inside a logical grid of the given size
+fn dispatch_in_bounds(index: u32, size: vec3<u32>) -> bool {
+    return index < size.x * size.y * size.z;
+}
+
+// Returns the position of a linear index inside a logical grid, x varying fastest
+fn dispatch_position(index: u32, size: vec3<u32>) -> vec3<u32> {
+    return vec3<u32>(index % size.x, (index / size.x) % size.y, index / (size.x * size.y));
+}
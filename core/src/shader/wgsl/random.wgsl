@@ -0,0 +1,95 @@
+// Counter-based pseudo-random numbers (Philox4x32-10).
+//
+// Every invocation starts its own generator from the `RandomKey` of the dispatch and its
+// index, so no state is stored between dispatches. The Rust side (`RandomStream`) hands
+// out a new key counter for every dispatch and `sgrmath_core::Random` reproduces the
+// numbers on the CPU.
+
+// The key of a dispatch, matches `sgrmath_core::RandomKey`
+struct RandomKey {
+    seed: vec2<u32>,
+    stream: u32,
+    counter: u32,
+}
+
+// The generator of an invocation
+struct Random {
+    key: vec2<u32>,
+    counter: vec4<u32>,
+    values: vec4<u32>,
+    used: u32,
+}
+
+// Returns the high and low words of the 64-bit product of two words
+fn random_mulhilo(a: u32, b: u32) -> vec2<u32> {
+    let a_low = a & 0xffffu;
+    let a_high = a >> 16u;
+    let b_low = b & 0xffffu;
+    let b_high = b >> 16u;
+
+    let low_low = a_low * b_low;
+    let high_low = a_high * b_low;
+    let low_high = a_low * b_high;
+    let cross = (low_low >> 16u) + (high_low & 0xffffu) + low_high;
+
+    let high = a_high * b_high + (high_low >> 16u) + (cross >> 16u);
+    let low = (cross << 16u) | (low_low & 0xffffu);
+    return vec2<u32>(high, low);
+}
+
+// Returns four random words for a counter and a key
+fn random_philox(counter: vec4<u32>, key: vec2<u32>) -> vec4<u32> {
+    var state = counter;
+    var round_key = key;
+    for (var round = 0u; round < 10u; round = round + 1u) {
+        let product0 = random_mulhilo(0xD2511F53u, state.x);
+        let product1 = random_mulhilo(0xCD9E8D57u, state.z);
+        state = vec4<u32>(
+            product1.x ^ state.y ^ round_key.x,
+            product1.y,
+            product0.x ^ state.w ^ round_key.y,
+            product0.y,
+        );
+        round_key = round_key + vec2<u32>(0x9E3779B9u, 0xBB67AE85u);
+    }
+    return state;
+}
+
+// Creates the generator of an invocation, `index` must be unique in the dispatch
+fn random_init(key: RandomKey, index: u32) -> Random {
+    return Random(key.seed, vec4<u32>(index, 0u, key.stream, key.counter), vec4<u32>(0u), 4u);
+}
+
+// Returns a uniformly distributed word
+fn random_u32(random: ptr<function, Random>) -> u32 {
+    if ((*random).used == 4u) {
+        (*random).values = random_philox((*random).counter, (*random).key);
+        (*random).counter.y = (*random).counter.y + 1u;
+        (*random).used = 0u;
+    }
+    let value = (*random).values[(*random).used];
+    (*random).used = (*random).used + 1u;
+    return value;
+}
+
+// Returns a uniformly distributed value in [0, 1)
+fn random_uniform(random: ptr<function, Random>) -> f32 {
+    return f32(random_u32(random) >> 8u) * (1.0 / 16777216.0);
+}
+
+// Returns a uniformly distributed value in [min, max)
+fn random_range(random: ptr<function, Random>, min: f32, max: f32) -> f32 {
+    return min + (max - min) * random_uniform(random);
+}
+
+// Returns a uniformly distributed integer in [0, n)
+fn random_below(random: ptr<function, Random>, n: u32) -> u32 {
+    return random_mulhilo(random_u32(random), n).x;
+}
+
+// Returns a normally distributed value with zero mean and unit variance (Box-Muller)
+fn random_normal(random: ptr<function, Random>) -> f32 {
+    let radius = sqrt(-2.0 * log(1.0 - random_uniform(random)));
+    let angle = 6.2831855 * random_uniform(random);
+    return radius * cos(angle);
+}
@@ -0,0 +1,255 @@
+use std::{path::PathBuf, thread};
+
+use tokio::runtime::Runtime;
+use wgpu::{Backends, Features, Limits, PowerPreference};
+
+use crate::{BufferPool, Error, Profiler, ShaderCache, WgpuContext};
+
+/// A builder for [`WgpuContext`] that selects the backend, adapter and device configuration.
+///
+/// Requested limits are clamped to what the selected adapter supports, so asking for
+/// more than the hardware provides never fails device creation. The defaults match
+/// [`WgpuContext::new`]: all backends, the default power preference, no extra features
+/// and 1GB buffer limits.
+///
+/// # Examples
+/// ```no_run
+/// use sgrmath_core::WgpuContext;
+///
+/// fn software_context() -> Result<WgpuContext, sgrmath_core::Error> {
+///     // Use a software adapter, e.g. on CI machines without a GPU
+///     WgpuContext::builder()
+///         .force_fallback_adapter(true)
+///         .build()
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct WgpuContextBuilder {
+    backends: Backends,
+    power_preference: PowerPreference,
+    force_fallback_adapter: bool,
+    required_features: Features,
+    required_limits: Limits,
+    pipeline_cache_dir: Option<PathBuf>,
+    profiling: bool,
+}
+
+impl Default for WgpuContextBuilder {
+    fn default() -> Self {
+        Self {
+            backends: Backends::all(),
+            power_preference: PowerPreference::default(),
+            force_fallback_adapter: false,
+            required_features: Features::empty(),
+            required_limits: Limits {
+                max_buffer_size: 1_000_000_000, // 1GB
+                max_storage_buffer_binding_size: 1_000_000_000, // 1GB
+                max_uniform_buffer_binding_size: 1_000_000_000, // 1GB
+                ..Limits::default()
+            },
+            pipeline_cache_dir: None,
+            profiling: false,
+        }
+    }
+}
+
+impl WgpuContextBuilder {
+    /// Sets the backends the adapter may be selected from.
+    #[must_use]
+    pub const fn backends(mut self, backends: Backends) -> Self {
+        self.backends = backends;
+        self
+    }
+
+    /// Sets the power preference used when selecting the adapter.
+    #[must_use]
+    pub const fn power_preference(mut self, power_preference: PowerPreference) -> Self {
+        self.power_preference = power_preference;
+        self
+    }
+
+    /// Forces the selection of a fallback (software) adapter.
+    #[must_use]
+    pub const fn force_fallback_adapter(mut self, force_fallback_adapter: bool) -> Self {
+        self.force_fallback_adapter = force_fallback_adapter;
+        self
+    }
+
+    /// Sets the features the device must support.
+    ///
+    /// Building fails with [`Error::UnsupportedFeatures`] if the adapter lacks any of them.
+    #[must_use]
+    pub const fn required_features(mut self, features: Features) -> Self {
+        self.required_features = features;
+        self
+    }
+
+    /// Sets the limits requested for the device.
+    ///
+    /// Limits above what the adapter supports are clamped to the adapter's values.
+    #[must_use]
+    pub const fn required_limits(mut self, limits: Limits) -> Self {
+        self.required_limits = limits;
+        self
+    }
+
+    /// Persists compiled pipelines in a directory, so later runs start faster.
+    ///
+    /// If the adapter supports [`Features::PIPELINE_CACHE`], the feature is enabled and the
+    /// driver's pipeline cache is loaded from and saved to the directory
+    /// (see [`ShaderCache`]). Otherwise pipelines are only cached in memory.
+    #[must_use]
+    pub fn pipeline_cache_dir<P>(mut self, directory: P) -> Self
+    where
+        P: Into<PathBuf>,
+    {
+        self.pipeline_cache_dir = Some(directory.into());
+        self
+    }
+
+    /// Enables the [`Profiler`] of the context.
+    ///
+    /// If the adapter supports [`Features::TIMESTAMP_QUERY`], the feature is enabled and
+    /// the GPU time of shader dispatches is measured. Otherwise the profiler falls back to
+    /// wall-clock time.
+    #[must_use]
+    pub const fn profiling(mut self, enabled: bool) -> Self {
+        self.profiling = enabled;
+        self
+    }
+
+    /// Creates the context synchronously.
+    ///
+    /// The context is created in a separate thread, so this method can be called
+    /// from inside an async runtime as well.
+    ///
+    /// # Errors
+    /// Returns an error if no adapter matches, a required feature is missing,
+    /// or device creation fails.
+    pub fn build(self) -> Result<WgpuContext, Error> {
+        thread::spawn(move || Runtime::new()?.block_on(self.build_async()))
+            .join()
+            .map_err(|_| Error::ThreadPanicked)?
+    }
+
+    /// Creates the context.
+    ///
+    /// This method will:
+    /// 1. Create a WGPU instance for the selected backends
+    /// 2. Request an adapter with the selected power preference
+    /// 3. Create a device and queue with the required features and the clamped limits
+    /// 4. Load the persisted pipeline cache, if a directory was set
+    /// 5. Create the profiler, enabled if profiling was requested
+    ///
+    /// # Errors
+    /// Returns an error if no adapter matches, a required feature is missing,
+    /// device creation fails, or the pipeline cache cannot be read.
+    pub async fn build_async(self) -> Result<WgpuContext, Error> {
+        let instance = wgpu::Instance::new(&wgpu::InstanceDescriptor {
+            backends: self.backends,
+            ..Default::default()
+        });
+        let adapter = instance
+            .request_adapter(&wgpu::RequestAdapterOptions {
+                power_preference: self.power_preference,
+                compatible_surface: None,
+                force_fallback_adapter: self.force_fallback_adapter,
+            })
+            .await?;
+
+        let missing = self.required_features - adapter.features();
+        if !missing.is_empty() {
+            return Err(Error::UnsupportedFeatures { adapter: adapter.get_info().name, missing });
+        }
+
+        let mut required_features = self.required_features;
+        if self.pipeline_cache_dir.is_some() && adapter.features().contains(Features::PIPELINE_CACHE) {
+            required_features |= Features::PIPELINE_CACHE;
+        }
+        if self.profiling && adapter.features().contains(Features::TIMESTAMP_QUERY) {
+            required_features |= Features::TIMESTAMP_QUERY;
+        }
+
+        let (device, queue) = adapter
+            .request_device(
+                &wgpu::DeviceDescriptor {
+                    label: None,
+                    required_features,
+                    required_limits: clamp_limits(&self.required_limits, &adapter.limits()),
+                    memory_hints: wgpu::MemoryHints::default(),
+                    trace: wgpu::Trace::Off,
+                },
+            )
+            .await?;
+
+        let pool = BufferPool::new(&device);
+        let cache = match &self.pipeline_cache_dir {
+            Some(directory) => ShaderCache::persistent(&adapter, &device, directory)?,
+            None => ShaderCache::new(),
+        };
+
+        let profiler = Profiler::new(&device, self.profiling);
+
+        Ok(WgpuContext { adapter, device, queue, pool, cache, profiler })
+    }
+}
+
+/// Clamps every requested limit to the value supported by the adapter.
+///
+/// `max_*` limits are lowered to the supported maximum, `min_*` alignments are raised
+/// to the supported minimum.
+fn clamp_limits(requested: &Limits, supported: &Limits) -> Limits {
+    let mut limits = requested.clone();
+
+    macro_rules! clamp {
+        (max: $($name:ident),* $(,)?) => { $( limits.$name = limits.$name.min(supported.$name); )* };
+        (min: $($name:ident),* $(,)?) => { $( limits.$name = limits.$name.max(supported.$name); )* };
+    }
+
+    clamp!(max:
+        max_texture_dimension_1d,
+        max_texture_dimension_2d,
+        max_texture_dimension_3d,
+        max_texture_array_layers,
+        max_bind_groups,
+        max_bindings_per_bind_group,
+        max_dynamic_uniform_buffers_per_pipeline_layout,
+        max_dynamic_storage_buffers_per_pipeline_layout,
+        max_sampled_textures_per_shader_stage,
+        max_samplers_per_shader_stage,
+        max_storage_buffers_per_shader_stage,
+        max_storage_textures_per_shader_stage,
+        max_uniform_buffers_per_shader_stage,
+        max_binding_array_elements_per_shader_stage,
+        max_binding_array_sampler_elements_per_shader_stage,
+        max_uniform_buffer_binding_size,
+        max_storage_buffer_binding_size,
+        max_vertex_buffers,
+        max_buffer_size,
+        max_vertex_attributes,
+        max_vertex_buffer_array_stride,
+        max_inter_stage_shader_components,
+        max_color_attachments,
+        max_color_attachment_bytes_per_sample,
+        max_compute_workgroup_storage_size,
+        max_compute_invocations_per_workgroup,
+        max_compute_workgroup_size_x,
+        max_compute_workgroup_size_y,
+        max_compute_workgroup_size_z,
+        max_compute_workgroups_per_dimension,
+        max_push_constant_size,
+        max_non_sampler_bindings,
+    );
+    clamp!(min:
+        min_uniform_buffer_offset_alignment,
+        min_storage_buffer_offset_alignment,
+    );
+
+    // Zero subgroup sizes mean "no requirement" and are left untouched
+    if limits.min_subgroup_size > 0 && limits.max_subgroup_size > 0 {
+        clamp!(min: min_subgroup_size);
+        clamp!(max: max_subgroup_size);
+    }
+
+    limits
+}
@@ -0,0 +1,7 @@
+mod builder;
+mod report;
+mod wgpu_context;
+
+pub use builder::WgpuContextBuilder;
+pub use report::AdapterReport;
+pub use wgpu_context::WgpuContext;
@@ -0,0 +1,39 @@
+use std::fmt;
+
+/// A description of the adapter and device a [`WgpuContext`](crate::WgpuContext) runs on.
+///
+/// Created by [`WgpuContext::report`](crate::WgpuContext::report). The [`fmt::Display`]
+/// implementation prints a short multi-line summary suitable for job logs.
+///
+/// # Examples
+/// ```no_run
+/// use sgrmath_core::WgpuContext;
+///
+/// let context = WgpuContext::new();
+/// println!("{}", context.report());
+/// ```
+#[derive(Clone, Debug)]
+pub struct AdapterReport {
+    /// Information about the adapter (name, vendor, backend, driver)
+    pub info: wgpu::AdapterInfo,
+    /// The features enabled on the device
+    pub features: wgpu::Features,
+    /// The limits the device was created with
+    pub limits: wgpu::Limits,
+    /// The best limits the adapter supports
+    pub adapter_limits: wgpu::Limits,
+}
+
+impl fmt::Display for AdapterReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Adapter: {} ({:?}, {:?})", self.info.name, self.info.backend, self.info.device_type)?;
+        writeln!(f, "  vendor: {:#06x}, device: {:#06x}", self.info.vendor, self.info.device)?;
+        writeln!(f, "  driver: {} {}", self.info.driver, self.info.driver_info)?;
+        writeln!(f, "  features: {:?}", self.features)?;
+        writeln!(f, "  max_buffer_size: {}", self.limits.max_buffer_size)?;
+        writeln!(f, "  max_storage_buffer_binding_size: {}", self.limits.max_storage_buffer_binding_size)?;
+        writeln!(f, "  max_uniform_buffer_binding_size: {}", self.limits.max_uniform_buffer_binding_size)?;
+        writeln!(f, "  max_compute_workgroups_per_dimension: {}", self.limits.max_compute_workgroups_per_dimension)?;
+        write!(f, "  max_compute_invocations_per_workgroup: {}", self.limits.max_compute_invocations_per_workgroup)
+    }
+}
@@ -0,0 +1,132 @@
+use std::ops::Deref;
+
+use wgpu::{Adapter, Device, Queue};
+
+use crate::{BufferPool, Profiler, ShaderCache};
+
+use super::{AdapterReport, WgpuContextBuilder};
+
+/// A wrapper around WGPU device and queue that provides GPU computation capabilities.
+///
+/// This struct encapsulates the core WGPU components needed for GPU computation:
+/// - An [`Adapter`] describing the physical device the context runs on
+/// - A [`Device`] for creating GPU resources and pipelines
+/// - A [`Queue`] for submitting commands to the GPU
+/// - A [`BufferPool`] for transient buffers shared by everything using the context
+/// - A [`ShaderCache`] deduplicating the compiled pipelines of its shaders
+/// - A [`Profiler`] recording the time spent in shaders and iterations, if enabled
+///
+/// Use [`WgpuContext::builder`] to choose the backend, adapter and device limits,
+/// or [`WgpuContext::new`] for the defaults.
+///
+/// # Example
+/// ```no_run
+/// use sgrmath_core::WgpuContext;
+///
+/// fn example() {
+///     // Create a new GPU context
+///     let context = WgpuContext::new();
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct WgpuContext {
+    /// The WGPU adapter the device was created from.
+    pub adapter: Adapter,
+    /// The WGPU device used for creating GPU resources.
+    pub device: Device,
+    /// The WGPU queue used for submitting commands to the GPU.
+    pub queue: Queue,
+    /// The pool of transient buffers allocated on the device.
+    pub pool: BufferPool,
+    /// The cache of pipelines compiled on the device.
+    pub cache: ShaderCache,
+    /// The profiler of shader dispatches and iterations.
+    pub profiler: Profiler,
+}
+
+impl WgpuContext {
+    /// Creates a new WGPU context synchronously with the default builder settings.
+    ///
+    /// This method will create a new WGPU context in a separate thread and return it.
+    ///
+    /// # Returns
+    /// A new `WgpuContext` containing the initialized device and queue.
+    ///
+    /// # Panics
+    /// Panics if no suitable adapter is found or device creation fails.
+    /// Use [`WgpuContext::builder`] to handle these errors.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::builder().build().expect("Failed to create WGPU context")
+    }
+
+    /// Creates a new WGPU context with the default builder settings.
+    ///
+    /// # Returns
+    /// A new `WgpuContext` containing the initialized device and queue.
+    ///
+    /// # Panics
+    /// Panics if no suitable adapter is found or device creation fails.
+    /// Use [`WgpuContext::builder`] to handle these errors.
+    pub async fn new_async() -> Self {
+        Self::builder().build_async().await.expect("Failed to create WGPU context")
+    }
+
+    /// Returns a builder for configuring the backend, adapter and device of a new context.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// use sgrmath_core::WgpuContext;
+    ///
+    /// fn example() -> Result<WgpuContext, sgrmath_core::Error> {
+    ///     WgpuContext::builder()
+    ///         .backends(wgpu::Backends::VULKAN | wgpu::Backends::METAL)
+    ///         .power_preference(wgpu::PowerPreference::HighPerformance)
+    ///         .build()
+    /// }
+    /// ```
+    #[must_use]
+    pub fn builder() -> WgpuContextBuilder {
+        WgpuContextBuilder::default()
+    }
+
+    /// Returns information about the adapter this context runs on.
+    #[must_use]
+    pub fn adapter_info(&self) -> wgpu::AdapterInfo {
+        self.adapter.get_info()
+    }
+
+    /// Returns the limits the device was created with.
+    #[must_use]
+    pub fn limits(&self) -> wgpu::Limits {
+        self.device.limits()
+    }
+
+    /// Returns a report describing the adapter, enabled features and device limits.
+    ///
+    /// The report implements [`std::fmt::Display`] and is intended for logging
+    /// which device a job ran on.
+    #[must_use]
+    pub fn report(&self) -> AdapterReport {
+        AdapterReport {
+            info: self.adapter.get_info(),
+            features: self.device.features(),
+            limits: self.device.limits(),
+            adapter_limits: self.adapter.limits(),
+        }
+    }
+}
+
+impl Default for WgpuContext {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Deref for WgpuContext {
+    type Target = Device;
+
+    fn deref(&self) -> &Self::Target {
+        &self.device
+    }
+}
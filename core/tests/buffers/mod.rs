@@ -21,8 +21,8 @@ macro_rules! buffer_tests {
         fn len() {
             let context = sgrmath_core::WgpuContext::new();
             
-            assert_eq!($constructor(&context, 100).len::<f32>(), 100);
-            assert_eq!($constructor(&context, 89).len::<f32>(), 89);
+            assert_eq!($constructor(&context, 100).len(), 100);
+            assert_eq!($constructor(&context, 89).len(), 89);
         }
     }
 }
@@ -30,57 +30,74 @@ macro_rules! buffer_tests {
 mod storage_buffer {
     buffer_tests!(
         storage_buffer,
-        sgrmath_core::StorageBuffer::new::<f32, _>, 
+        sgrmath_core::StorageBuffer::<f32>::new, 
         wgpu::BufferUsages::STORAGE | wgpu::BufferUsages::COPY_DST | wgpu::BufferUsages::COPY_SRC
     );
 
     #[test]
     fn update_buffer_range() {
         let context = sgrmath_core::WgpuContext::new();
-        let storage = sgrmath_core::StorageBuffer::new::<i32, _>(&context, 100);
-        let readback = sgrmath_core::ReadbackBuffer::new::<i32, _>(&context, 100);
+        let storage = sgrmath_core::StorageBuffer::<i32>::new(&context, 100);
+        let readback = sgrmath_core::ReadbackBuffer::<i32>::new(&context, 100);
 
-        storage.update_buffer_range::<i32>(&context, &[1, 2, 3, 4, 5], 0);
-        storage.update_buffer_range::<i32>(&context, &[6, 7, 8], 20);
+        storage.update_buffer_range(&context, &[1, 2, 3, 4, 5], 0);
+        storage.update_buffer_range(&context, &[6, 7, 8], 20);
 
-        assert_eq!(readback.read::<i32>(&context, &storage.0, 0, 10), vec![1, 2, 3, 4, 5, 0, 0, 0, 0, 0]);
-        assert_eq!(readback.read::<i32>(&context, &storage.0, 20, 10), vec![6, 7, 8, 0, 0, 0, 0, 0, 0, 0]);
+        assert_eq!(readback.read(&context, &storage, 0, 10), vec![1, 2, 3, 4, 5, 0, 0, 0, 0, 0]);
+        assert_eq!(readback.read(&context, &storage, 20, 10), vec![6, 7, 8, 0, 0, 0, 0, 0, 0, 0]);
     }
 
     #[test]
     fn update_buffer_range_async() {
         let context = sgrmath_core::WgpuContext::new();
-        let storage = sgrmath_core::StorageBuffer::new::<i32, _>(&context, 100);
-        let readback = sgrmath_core::ReadbackBuffer::new::<i32, _>(&context, 100);
+        let storage = sgrmath_core::StorageBuffer::<i32>::new(&context, 100);
+        let readback = sgrmath_core::ReadbackBuffer::<i32>::new(&context, 100);
 
-        storage.update_buffer_range_async::<i32>(&context, &[1, 2, 3, 4, 5], 0);
-        storage.update_buffer_range_async::<i32>(&context, &[6, 7, 8], 20);
+        storage.update_buffer_range_async(&context, &[1, 2, 3, 4, 5], 0);
+        storage.update_buffer_range_async(&context, &[6, 7, 8], 20);
 
         context.device.poll(wgpu::MaintainBase::Wait).unwrap();
 
-        assert_eq!(readback.read::<i32>(&context, &storage.0, 0, 10), vec![1, 2, 3, 4, 5, 0, 0, 0, 0, 0]);
-        assert_eq!(readback.read::<i32>(&context, &storage.0, 20, 10), vec![6, 7, 8, 0, 0, 0, 0, 0, 0, 0]);
+        assert_eq!(readback.read(&context, &storage, 0, 10), vec![1, 2, 3, 4, 5, 0, 0, 0, 0, 0]);
+        assert_eq!(readback.read(&context, &storage, 20, 10), vec![6, 7, 8, 0, 0, 0, 0, 0, 0, 0]);
+    }
+
+    #[test]
+    fn try_update_buffer_range() {
+        let context = sgrmath_core::WgpuContext::new();
+        let storage = sgrmath_core::StorageBuffer::<i32>::new(&context, 10);
+
+        storage.try_update_buffer_range(&context, &[1, 2, 3], 7).unwrap();
+
+        assert!(matches!(
+            storage.try_update_buffer_range(&context, &[1, 2, 3], 8),
+            Err(sgrmath_core::Error::OutOfBounds { offset: 32, len: 12, size: 40 })
+        ));
+        assert!(matches!(
+            storage.try_update_buffer_range(&context, &[], 0),
+            Err(sgrmath_core::Error::EmptyData)
+        ));
     }
 }
 
 mod readback_buffer {
     buffer_tests!(
         readback_buffer,
-        sgrmath_core::ReadbackBuffer::new::<f32, _>, 
+        sgrmath_core::ReadbackBuffer::<f32>::new, 
         wgpu::BufferUsages::MAP_READ | wgpu::BufferUsages::COPY_DST
     );
 
     #[test]
     fn scale() {
         let context = sgrmath_core::WgpuContext::new();
-        let mut buffer = sgrmath_core::ReadbackBuffer::new::<f32, _>(&context, 100);
+        let mut buffer = sgrmath_core::ReadbackBuffer::<f32>::new(&context, 100);
 
-        buffer.scale::<f32, _>(&context, 20);
-        buffer.scale::<f32, _>(&context, 99);
+        buffer.scale(&context, 20);
+        buffer.scale(&context, 99);
 
         assert_eq!(buffer.0.size(), 400);
         
-        buffer.scale::<f32, _>(&context, 200);
+        buffer.scale(&context, 200);
 
         assert_eq!(buffer.0.size(), 800);
     }
@@ -88,10 +105,10 @@ mod readback_buffer {
     #[test]
     fn read() {
         let context = sgrmath_core::WgpuContext::new();
-        let storage = sgrmath_core::StorageBuffer::new::<i32, _>(&context, 100);
-        let readback = sgrmath_core::ReadbackBuffer::new::<i32, _>(&context, 100);
+        let storage = sgrmath_core::StorageBuffer::<i32>::new(&context, 100);
+        let readback = sgrmath_core::ReadbackBuffer::<i32>::new(&context, 100);
 
-        storage.update_buffer_range::<i32>(
+        storage.update_buffer_range(
             &context, 
             (0_i32..100)
                 .collect::<Vec<_>>()
@@ -100,21 +117,198 @@ mod readback_buffer {
         );
 
         assert_eq!(
-            readback.read::<i32>(&context, &storage.0, 0, 20), 
+            readback.read(&context, &storage, 0, 20), 
             (0_i32..20).collect::<Vec<_>>()
         );
         assert_eq!(
-            readback.read::<i32>(&context, &storage.0, 42, 27), 
+            readback.read(&context, &storage, 42, 27), 
             (42_i32..69).collect::<Vec<_>>()
         );
     }
+
+    #[test]
+    fn read_async() {
+        let context = sgrmath_core::WgpuContext::new();
+        let storage = sgrmath_core::StorageBuffer::init(&context, &(0_i32..100).collect::<Vec<_>>());
+        let mut readback = sgrmath_core::ReadbackBuffer::<i32>::new(&context, 100);
+
+        assert_eq!(
+            tokio_test::block_on(readback.read_async(&context, &storage, 10, 20)).unwrap(), 
+            (10_i32..30).collect::<Vec<_>>()
+        );
+        assert_eq!(
+            tokio_test::block_on(readback.read_async(&context, &storage, 0, 5)).unwrap(), 
+            (0_i32..5).collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn read_async_dropped() {
+        let context = sgrmath_core::WgpuContext::new();
+        let storage = sgrmath_core::StorageBuffer::init(&context, &(0_i32..100).collect::<Vec<_>>());
+        let mut readback = sgrmath_core::ReadbackBuffer::<i32>::new(&context, 100);
+
+        drop(readback.read_async(&context, &storage, 0, 100));
+
+        assert_eq!(readback.read(&context, &storage, 0, 3), vec![0, 1, 2]);
+    }
+
+    #[test]
+    fn try_read() {
+        let context = sgrmath_core::WgpuContext::new();
+        let storage = sgrmath_core::StorageBuffer::init(&context, &(0_i32..100).collect::<Vec<_>>());
+        let readback = sgrmath_core::ReadbackBuffer::<i32>::new(&context, 10);
+
+        assert_eq!(readback.try_read(&context, &storage, 95, 5).unwrap(), (95_i32..100).collect::<Vec<_>>());
+        assert!(matches!(
+            readback.try_read(&context, &storage, 95, 6),
+            Err(sgrmath_core::Error::OutOfBounds { offset: 380, len: 24, size: 400 })
+        ));
+        assert!(matches!(
+            readback.try_read(&context, &storage, 0, 20),
+            Err(sgrmath_core::Error::OutOfBounds { offset: 0, len: 80, size: 40 })
+        ));
+    }
+}
+
+mod readback_batch {
+    use sgrmath_core::{ReadbackBatch, StorageBuffer, WgpuContext};
+
+    #[test]
+    fn read() {
+        let context = WgpuContext::new();
+        let integers = StorageBuffer::init(&context, &(0_u32..100).collect::<Vec<_>>());
+        let floats = StorageBuffer::init(&context, &(0..50).map(|x| x as f32 / 2.0).collect::<Vec<_>>());
+        let mut batch = ReadbackBatch::new();
+
+        let first = batch.push(&integers, 3, 5);
+        let second = batch.push(&floats, 10, 3);
+        let third = batch.push(&integers, 90, 10);
+        assert_eq!(batch.len(), 3);
+
+        let results = batch.read(&context);
+
+        assert!(batch.is_empty());
+        assert_eq!(results.get(first), &[3, 4, 5, 6, 7]);
+        assert_eq!(results.get(second), &[5.0, 5.5, 6.0]);
+        assert_eq!(results.get(third), (90_u32..100).collect::<Vec<_>>().as_slice());
+    }
+
+    #[test]
+    fn read_reuses_staging() {
+        let context = WgpuContext::new();
+        let values = StorageBuffer::init(&context, &(0_u32..100).collect::<Vec<_>>());
+        let mut batch = ReadbackBatch::new();
+
+        let slot = batch.push(&values, 0, 100);
+        assert_eq!(batch.read(&context).get(slot).len(), 100);
+
+        let slot = batch.push(&values, 50, 2);
+        assert_eq!(batch.read(&context).get(slot), &[50, 51]);
+    }
+
+    #[test]
+    fn read_async() {
+        let context = WgpuContext::new();
+        let integers = StorageBuffer::init(&context, &[1_i32, 2, 3, 4]);
+        let floats = StorageBuffer::init(&context, &[0.5_f32, 1.5]);
+        let mut batch = ReadbackBatch::new();
+
+        let first = batch.push(&integers, 1, 3);
+        let second = batch.push(&floats, 0, 2);
+        let results = tokio_test::block_on(batch.read_async(&context)).unwrap();
+
+        assert_eq!(results.get(first), &[2, 3, 4]);
+        assert_eq!(results.get(second), &[0.5, 1.5]);
+    }
+
+    #[test]
+    fn read_empty() {
+        let context = WgpuContext::new();
+        let mut batch = ReadbackBatch::new();
+
+        batch.read(&context);
+        tokio_test::block_on(batch.read_async(&context)).unwrap();
+    }
+
+    #[test]
+    #[should_panic(expected = "Read would go beyond source buffer bounds")]
+    fn push_out_of_bounds() {
+        let context = WgpuContext::new();
+        let values = StorageBuffer::<u32>::new(&context, 10);
+
+        ReadbackBatch::new().push(&values, 5, 6);
+    }
+}
+
+mod buffer_pool {
+    use sgrmath_core::{Bindable, WgpuContext};
+
+    #[test]
+    fn storage() {
+        let context = WgpuContext::new();
+        let lease = context.pool.storage::<f32, _>(100);
+
+        assert_eq!(lease.len(), 100);
+        assert_eq!(lease.binding_size(), 400);
+        assert_eq!(lease.size(), 512);
+        assert_eq!(lease.usage(), wgpu::BufferUsages::STORAGE | wgpu::BufferUsages::COPY_DST | wgpu::BufferUsages::COPY_SRC);
+    }
+
+    #[test]
+    fn reuse() {
+        let context = WgpuContext::new();
+        let first = context.pool.storage::<u32, _>(1000);
+        let raw = first.0.clone();
+
+        assert_eq!(context.pool.idle_count(), 0);
+        drop(first);
+        assert_eq!(context.pool.idle_count(), 1);
+
+        // Same size class, different element type
+        let second = context.pool.storage::<f32, _>(600);
+        assert_eq!(second.0, raw);
+        assert_eq!(second.len(), 600);
+        assert_eq!(context.pool.idle_count(), 0);
+
+        // Different usage
+        let readback = context.pool.readback::<u32, _>(1000);
+        assert_ne!(readback.0, raw);
+    }
+
+    #[test]
+    fn clone_shares_lease() {
+        let context = WgpuContext::new();
+        let first = context.pool.storage::<u32, _>(10);
+        let second = first.clone();
+
+        drop(first);
+        assert_eq!(context.pool.idle_count(), 0);
+
+        drop(second);
+        assert_eq!(context.pool.idle_count(), 1);
+
+        context.pool.clear();
+        assert_eq!(context.pool.idle_count(), 0);
+    }
+
+    #[test]
+    fn read() {
+        let context = WgpuContext::new();
+        let storage = context.pool.storage::<i32, _>(10);
+        let readback = context.pool.readback::<i32, _>(10);
+
+        storage.update_buffer_range(&context, &(0..10).collect::<Vec<_>>(), 0);
+
+        assert_eq!(readback.read(&context, &storage, 2, 3), vec![2, 3, 4]);
+    }
 }
 
 mod value_buffer {
     #[test]
     fn new() {
         let context = sgrmath_core::WgpuContext::new();
-        let value = sgrmath_core::ValueBuffer::new::<Test>(&context);
+        let value = sgrmath_core::ValueBuffer::<Test>::new(&context);
 
         assert_eq!(value.size(), 8);
     }
@@ -122,32 +316,32 @@ mod value_buffer {
     #[test]
     fn init() {
         let context = sgrmath_core::WgpuContext::new();
-        let value = sgrmath_core::ValueBuffer::init::<Test>(&context, &Test(1.0, 2.0));
-        let storage = sgrmath_core::StorageBuffer::new::<Test, _>(&context, 1);
-        let readback = sgrmath_core::ReadbackBuffer::new::<Test, _>(&context, 1);
+        let value = sgrmath_core::ValueBuffer::init(&context, &Test(1.0, 2.0));
+        let storage = sgrmath_core::StorageBuffer::<Test>::new(&context, 1);
+        let readback = sgrmath_core::ReadbackBuffer::<Test>::new(&context, 1);
         let copy_shader = sgrmath_core::Shader::new(&context, "copy", include_str!("copy.wgsl"));
 
         assert_eq!(value.size(), 8);
 
-        copy_shader.execute_with_params(&context, 2, &[ &value, &storage ]);
-        assert_eq!(readback.read::<f32>(&context, &storage, 0, 2), vec![1.0, 2.0]);
+        copy_shader.execute_with_params(&context, 2, &[ &value, &storage ]).unwrap();
+        assert_eq!(readback.read(&context, &storage, 0, 1), vec![Test(1.0, 2.0)]);
     }
 
     #[test]
     fn set() {
         let context = sgrmath_core::WgpuContext::new();
-        let value = sgrmath_core::ValueBuffer::init::<Test>(&context, &Test(1.0, 2.0));
-        let storage = sgrmath_core::StorageBuffer::new::<Test, _>(&context, 1);
-        let readback = sgrmath_core::ReadbackBuffer::new::<Test, _>(&context, 1);
+        let value = sgrmath_core::ValueBuffer::init(&context, &Test(1.0, 2.0));
+        let storage = sgrmath_core::StorageBuffer::<Test>::new(&context, 1);
+        let readback = sgrmath_core::ReadbackBuffer::<Test>::new(&context, 1);
         let copy_shader = sgrmath_core::Shader::new(&context, "copy", include_str!("copy.wgsl"));
 
         value.set(&context, &Test(3.0, 4.0));
 
-        copy_shader.execute_with_params(&context, 2, &[ &value, &storage ]);
-        assert_eq!(readback.read::<f32>(&context, &storage, 0, 2), vec![3.0, 4.0]);
+        copy_shader.execute_with_params(&context, 2, &[ &value, &storage ]).unwrap();
+        assert_eq!(readback.read(&context, &storage, 0, 1), vec![Test(3.0, 4.0)]);
     }
 
     #[repr(C)]
-    #[derive(Copy, Clone, Debug, bytemuck::Pod, bytemuck::Zeroable)]
+    #[derive(Copy, Clone, Debug, PartialEq, bytemuck::Pod, bytemuck::Zeroable)]
     struct Test(f32, f32);
 }
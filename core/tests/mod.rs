@@ -1,4 +1,6 @@
 mod buffers;
 mod model;
+mod profiler;
+mod random;
 mod shader;
 mod wgpu_context;
\ No newline at end of file
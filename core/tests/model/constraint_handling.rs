@@ -0,0 +1,52 @@
+use std::cmp::Ordering;
+
+use sgrmath_core::{ConstraintHandling, FitnessPolicy, OptimizationDirection};
+
+#[test]
+fn feasibility() {
+    let (handling, policy) = (ConstraintHandling::Feasibility, FitnessPolicy::default());
+    let direction = OptimizationDirection::Maximize;
+
+    assert_eq!(handling.total_cmp(&direction, (2.0, 0.0), (1.0, 0.0), &policy), Ordering::Less);
+    assert_eq!(handling.total_cmp(&direction, (1.0, 0.0), (9.0, 0.1), &policy), Ordering::Less);
+    assert_eq!(handling.total_cmp(&direction, (9.0, 0.2), (1.0, 0.1), &policy), Ordering::Greater);
+    assert_eq!(handling.total_cmp(&direction, (9.0, 0.1), (1.0, 0.1), &policy), Ordering::Equal);
+    assert_eq!(handling.total_cmp(&direction, (9.0, f32::NAN), (1.0, 100.0), &policy), Ordering::Greater);
+}
+
+#[test]
+fn penalty() {
+    let (handling, policy) = (ConstraintHandling::Penalty(10.0), FitnessPolicy::default());
+
+    assert_eq!(handling.penalized(&OptimizationDirection::Minimize, 1.0, 0.5), 6.0);
+    assert_eq!(handling.penalized(&OptimizationDirection::Maximize, 1.0, 0.5), -4.0);
+    assert_eq!(handling.penalized(&OptimizationDirection::Minimize, 1.0, 0.0), 1.0);
+
+    assert_eq!(
+        handling.total_cmp(&OptimizationDirection::Minimize, (1.0, 0.1), (2.5, 0.0), &policy),
+        Ordering::Less
+    );
+    assert_eq!(
+        handling.total_cmp(&OptimizationDirection::Minimize, (1.0, 0.2), (2.5, 0.0), &policy),
+        Ordering::Greater
+    );
+}
+
+#[test]
+fn best_of() {
+    let policy = FitnessPolicy::default();
+    let values = [ (0.0, 1.0), (3.0, 0.0), (2.0, 0.0), (1.0, 0.05) ];
+
+    assert_eq!(
+        ConstraintHandling::Feasibility.best_of(&OptimizationDirection::Minimize, values, &policy),
+        Some((2, 2.0))
+    );
+    assert_eq!(
+        ConstraintHandling::Penalty(10.0).best_of(&OptimizationDirection::Minimize, values, &policy),
+        Some((3, 1.0))
+    );
+    assert_eq!(
+        ConstraintHandling::Feasibility.best_of(&OptimizationDirection::Minimize, [], &policy),
+        None
+    );
+}
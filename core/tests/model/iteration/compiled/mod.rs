@@ -0,0 +1,168 @@
+use std::{cell::RefCell, rc::Rc};
+
+use sgrmath_core::{Compiled, CompiledIteration, Error, Iteration, TryIteration};
+
+#[test]
+fn compiles_lazily() {
+    let (log, mut iteration) = prepare();
+    assert_eq!(iteration.compiled_count(), 0);
+    assert!(iteration.iteration().is_none());
+
+    iteration.bind(&(0..10));
+    iteration.evaluate();
+    iteration.bind(&(10..20));
+    iteration.evaluate();
+
+    assert_eq!(iteration.compiled_count(), 1);
+    assert_eq!(*log.borrow(), vec![ "compile 10", "evaluate 10 0..10", "evaluate 10 10..20" ]);
+}
+
+#[test]
+fn recompiles_on_key_change() {
+    let (log, mut iteration) = prepare();
+    iteration.bind(&(0..10));
+    iteration.evaluate_with_params(&(10..15));
+    iteration.evaluate();
+    iteration.evaluate_with_params_async(&(15..20));
+
+    assert_eq!(iteration.compiled_count(), 2);
+    assert_eq!(iteration.iteration().map(|compiled| compiled.len), Some(10));
+    assert_eq!(
+        *log.borrow(), 
+        vec![ "compile 10", "compile 5", "evaluate 5 10..15", "evaluate 10 0..10", "evaluate 5 15..20" ]
+    );
+}
+
+#[test]
+fn keeps_bound_and_last_compiled() {
+    let (log, mut iteration) = prepare();
+    iteration.bind(&(0..10));
+    iteration.evaluate_with_params(&(10..15));
+    iteration.evaluate_with_params(&(15..21));
+    iteration.evaluate_with_params(&(21..28));
+    iteration.evaluate();
+
+    assert_eq!(iteration.compiled_count(), 2);
+    assert_eq!(iteration.iteration().map(|compiled| compiled.len), Some(10));
+
+    iteration.bind(&(0..7));
+    iteration.evaluate_with_params(&(0..5));
+
+    assert_eq!(iteration.compiled_count(), 2);
+    assert_eq!(iteration.iteration().map(|compiled| compiled.len), Some(7));
+    assert_eq!(
+        log.borrow().iter().filter(|entry| entry.starts_with("compile")).collect::<Vec<_>>(),
+        vec![ "compile 10", "compile 5", "compile 6", "compile 7", "compile 5" ]
+    );
+}
+
+#[test]
+#[should_panic(expected = "CompiledIteration::evaluate called before bind")]
+fn evaluate_before_bind() {
+    let (_, mut iteration) = prepare();
+    iteration.evaluate_with_params(&(0..10));
+    iteration.evaluate();
+}
+
+#[test]
+fn try_evaluate_before_bind() {
+    let (log, mut iteration) = prepare();
+    iteration.try_evaluate_with_params(&(0..10)).unwrap();
+
+    assert!(matches!(
+        iteration.try_evaluate(),
+        Err(Error::NotBound(method)) if method == "CompiledIteration::try_evaluate"
+    ));
+
+    iteration.try_bind(&(10..20)).unwrap();
+    iteration.try_evaluate().unwrap();
+    assert_eq!(*log.borrow(), vec![ "compile 10", "evaluate 10 0..10", "evaluate 10 10..20" ]);
+}
+
+type Log = Rc<RefCell<Vec<String>>>;
+type Range = std::ops::Range<usize>;
+
+fn prepare() -> (Log, CompiledIteration<Options, Ranged, Range>) {
+    let log = Rc::new(RefCell::new(vec![]));
+    (log.clone(), CompiledIteration::new(Options { log }))
+}
+
+/// Compiles iterations for ranges of a fixed length
+struct Options {
+    log: Log,
+}
+
+impl Compiled<Range, Ranged> for Options {
+    fn compile(&self, params: &Range) -> Ranged {
+        self.log.borrow_mut().push(format!("compile {}", params.len()));
+        Ranged { len: params.len(), log: self.log.clone(), bound: None }
+    }
+
+    fn compile_key(&self, params: &Range) -> Vec<usize> {
+        vec![ params.len() ]
+    }
+}
+
+struct Ranged {
+    len: usize,
+    log: Log,
+    bound: Option<Range>,
+}
+
+impl Ranged {
+    fn execute(&self, params: &Range) {
+        assert_eq!(params.len(), self.len, "range of another length");
+        self.log.borrow_mut().push(format!("evaluate {} {params:?}", self.len));
+    }
+}
+
+impl Iteration<Range> for Ranged {
+    fn bind(&mut self, params: &Range) {
+        self.bound = Some(params.clone());
+    }
+
+    fn evaluate(&mut self) {
+        self.execute(self.bound.as_ref().unwrap());
+    }
+
+    fn evaluate_async(&mut self) -> Vec<wgpu::CommandBuffer> {
+        self.evaluate();
+        vec![]
+    }
+
+    fn evaluate_with_params(&mut self, params: &Range) {
+        self.execute(params);
+    }
+
+    fn evaluate_with_params_async(&mut self, params: &Range) -> Vec<wgpu::CommandBuffer> {
+        self.execute(params);
+        vec![]
+    }
+}
+
+impl TryIteration<Range> for Ranged {
+    fn try_bind(&mut self, params: &Range) -> Result<(), Error> {
+        self.bind(params);
+        Ok(())
+    }
+
+    fn try_evaluate(&mut self) -> Result<(), Error> {
+        let params = self.bound.clone().ok_or_else(|| Error::NotBound("Ranged::try_evaluate".to_string()))?;
+        self.execute(&params);
+        Ok(())
+    }
+
+    fn try_evaluate_async(&mut self) -> Result<Vec<wgpu::CommandBuffer>, Error> {
+        self.try_evaluate().map(|()| vec![])
+    }
+
+    fn try_evaluate_with_params(&mut self, params: &Range) -> Result<(), Error> {
+        self.execute(params);
+        Ok(())
+    }
+
+    fn try_evaluate_with_params_async(&mut self, params: &Range) -> Result<Vec<wgpu::CommandBuffer>, Error> {
+        self.execute(params);
+        Ok(vec![])
+    }
+}
@@ -0,0 +1,118 @@
+use std::{cell::RefCell, rc::Rc};
+
+use sgrmath_core::{AfterN, EveryN, FirstN, Iteration, When};
+
+#[test]
+fn every_n() {
+    let (log, mut iteration) = prepare(|counter| EveryN::new(counter, 3));
+    for i in 0..7 {
+        iteration.evaluate_with_params(&i);
+    }
+
+    assert_eq!(*log.borrow(), vec![ 2, 5 ]);
+    assert_eq!(iteration.count(), 7);
+}
+
+#[test]
+fn every_zero() {
+    let (log, mut iteration) = prepare(|counter| EveryN::new(counter, 0));
+    for i in 0..5 {
+        iteration.evaluate_with_params(&i);
+    }
+
+    assert!(log.borrow().is_empty());
+}
+
+#[test]
+fn first_n() {
+    let (log, mut iteration) = prepare(|counter| FirstN::new(counter, 2));
+    for i in 0..5 {
+        iteration.evaluate_with_params(&i);
+    }
+    iteration.reset();
+    iteration.evaluate_with_params(&5);
+
+    assert_eq!(*log.borrow(), vec![ 0, 1, 5 ]);
+}
+
+#[test]
+fn after_n() {
+    let (log, mut iteration) = prepare(|counter| AfterN::new(counter, 2));
+    iteration.bind(&10);
+    for _ in 0..4 {
+        iteration.evaluate();
+    }
+
+    assert_eq!(*log.borrow(), vec![ 10, 10 ]);
+}
+
+#[test]
+fn async_skipped() {
+    let (log, mut iteration) = prepare(|counter| AfterN::new(counter, 1));
+    iteration.bind(&1);
+
+    assert!(iteration.evaluate_async().is_empty());
+    assert!(iteration.evaluate_with_params_async(&2).is_empty());
+    assert_eq!(*log.borrow(), vec![ 2 ]);
+}
+
+#[test]
+fn when() {
+    let (log, mut iteration) = prepare(|counter| When::new(counter, |params: &usize| params % 2 == 0));
+    for i in 0..5 {
+        iteration.evaluate_with_params(&i);
+    }
+    iteration.bind(&7);
+    iteration.evaluate();
+    iteration.bind(&8);
+    iteration.evaluate_async();
+
+    assert_eq!(*log.borrow(), vec![ 0, 2, 4, 8 ]);
+}
+
+#[test]
+#[should_panic(expected = "before the parameters were bound")]
+fn when_unbound() {
+    let (_, mut iteration) = prepare(|counter| When::new(counter, |_: &usize| true));
+    iteration.evaluate();
+}
+
+type Log = Rc<RefCell<Vec<usize>>>;
+
+fn prepare<I, F>(wrap: F) -> (Log, I)
+where
+    F: FnOnce(Counter) -> I,
+{
+    let log = Rc::new(RefCell::new(vec![]));
+    (log.clone(), wrap(Counter { log, params: 0 }))
+}
+
+/// Logs the parameters of every evaluation that reaches it
+struct Counter {
+    log: Log,
+    params: usize,
+}
+
+impl Iteration<usize> for Counter {
+    fn bind(&mut self, params: &usize) {
+        self.params = *params;
+    }
+
+    fn evaluate(&mut self) {
+        self.log.borrow_mut().push(self.params);
+    }
+
+    fn evaluate_async(&mut self) -> Vec<wgpu::CommandBuffer> {
+        self.evaluate();
+        vec![]
+    }
+
+    fn evaluate_with_params(&mut self, params: &usize) {
+        self.log.borrow_mut().push(*params);
+    }
+
+    fn evaluate_with_params_async(&mut self, params: &usize) -> Vec<wgpu::CommandBuffer> {
+        self.evaluate_with_params(params);
+        vec![]
+    }
+}
@@ -0,0 +1,131 @@
+use sgrmath_core::{CombinedIteration, Error, Executor, Fallible, Iteration, NotImplementedIteration, ReadbackBuffer, Shader, StorageBuffer, WgpuContext};
+
+#[test]
+fn submit_in_order() {
+    let (context, values) = prepare();
+    let mut double = Step::new(&context, &values, "values[i] = values[i] * 2u;");
+    let mut increment = Step::new(&context, &values, "values[i] = values[i] + 1u;");
+
+    let mut executor = Executor::new(&context);
+    executor.evaluate(&mut double).evaluate(&mut increment);
+    assert_eq!(executor.len(), 2);
+
+    executor.submit().wait().unwrap();
+    assert!(executor.is_empty());
+    assert_eq!(read(&context, &values), vec![3; 4]);
+
+    executor.evaluate(&mut increment).evaluate(&mut double).submit().wait().unwrap();
+    assert_eq!(read(&context, &values), vec![8; 4]);
+}
+
+#[test]
+fn iteration_tree() {
+    let (context, values) = prepare();
+    let mut combined = CombinedIteration::<()>::new(&context)
+        .add(Box::new(Step::new(&context, &values, "values[i] = values[i] * 2u;")))
+        .add(Box::new(
+            CombinedIteration::<()>::new(&context)
+                .add(Box::new(Step::new(&context, &values, "values[i] = values[i] + 1u;")))
+                .add(Box::new(Step::new(&context, &values, "values[i] = values[i] * 3u;")))
+        ));
+
+    let mut executor = Executor::new(&context);
+    executor.evaluate_with_params(&mut combined, &());
+    assert_eq!(executor.len(), 3);
+
+    executor.submit().wait().unwrap();
+    assert_eq!(read(&context, &values), vec![9; 4]);
+}
+
+#[test]
+fn push() {
+    let (context, values) = prepare();
+    let mut double = Step::new(&context, &values, "values[i] = values[i] * 2u;");
+
+    Executor::new(&context).push(double.evaluate_async()).push(double.evaluate_async()).submit().wait().unwrap();
+
+    assert_eq!(read(&context, &values), vec![4; 4]);
+}
+
+#[test]
+fn try_evaluate() {
+    let (context, values) = prepare();
+    let mut double = Fallible::new("double", Step::new(&context, &values, "values[i] = values[i] * 2u;"));
+    let mut missing = NotImplementedIteration::new("missing");
+
+    let mut executor = Executor::new(&context);
+    executor.try_evaluate(&mut double).unwrap();
+    assert!(matches!(executor.try_evaluate::<(), _>(&mut missing), Err(Error::NotImplemented(_))));
+    assert_eq!(executor.len(), 1);
+
+    executor.try_evaluate_with_params(&mut double, &()).unwrap().submit().wait().unwrap();
+    assert_eq!(read(&context, &values), vec![4; 4]);
+}
+
+#[test]
+fn combined_evaluate_submits() {
+    let (context, values) = prepare();
+    let mut combined = CombinedIteration::<()>::new(&context)
+        .add(Box::new(Step::new(&context, &values, "values[i] = values[i] * 2u;")))
+        .add(Box::new(Step::new(&context, &values, "values[i] = values[i] + 1u;")));
+
+    combined.evaluate();
+    assert_eq!(read(&context, &values), vec![3; 4]);
+
+    combined.evaluate_with_params(&());
+    assert_eq!(read(&context, &values), vec![7; 4]);
+}
+
+struct Step {
+    context: WgpuContext,
+    shader: Shader,
+}
+
+impl Step {
+    fn new(context: &WgpuContext, values: &StorageBuffer<u32>, statement: &str) -> Self {
+        let source = format!(
+            "@group(0) @binding(0) var<storage, read_write> values: array<u32>;
+
+            @compute @workgroup_size(1)
+            fn main(@builtin(global_invocation_id) global_id: vec3<u32>) {{
+                let i = global_id.x;
+                {statement}
+            }}"
+        );
+        let mut shader = Shader::new(context, "step", source);
+        shader.bind(context, &[values]).unwrap();
+
+        Self { context: context.clone(), shader }
+    }
+}
+
+impl Iteration<()> for Step {
+    fn bind(&mut self, _params: &()) {}
+
+    fn evaluate(&mut self) {
+        self.shader.execute(&self.context, 4);
+    }
+
+    fn evaluate_async(&mut self) -> Vec<wgpu::CommandBuffer> {
+        vec![self.shader.execute_async(&self.context, 4)]
+    }
+
+    fn evaluate_with_params(&mut self, _params: &()) {
+        self.evaluate();
+    }
+
+    fn evaluate_with_params_async(&mut self, _params: &()) -> Vec<wgpu::CommandBuffer> {
+        self.evaluate_async()
+    }
+}
+
+fn prepare() -> (WgpuContext, StorageBuffer<u32>) {
+    let context = WgpuContext::new();
+    let values = StorageBuffer::<u32>::init(&context, &[1; 4]);
+
+    (context, values)
+}
+
+fn read(context: &WgpuContext, values: &StorageBuffer<u32>) -> Vec<u32> {
+    ReadbackBuffer::<u32>::new(context, 4).read(context, values, 0, 4)
+}
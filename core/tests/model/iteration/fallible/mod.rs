@@ -0,0 +1,83 @@
+use sgrmath_core::{Error, Fallible, Iteration, NotImplementedIteration, TryIteration};
+
+#[test]
+fn forwards() {
+    let mut iteration = Fallible::new("counter", Counter(0));
+
+    iteration.try_bind(&2).unwrap();
+    iteration.try_evaluate().unwrap();
+    iteration.try_evaluate_with_params(&3).unwrap();
+    assert!(iteration.try_evaluate_async().unwrap().is_empty());
+
+    assert_eq!(iteration.label(), "counter");
+    assert_eq!(iteration.into_inner().0, 7);
+}
+
+#[test]
+fn panics() {
+    let mut iteration = Fallible::new("counter", Counter(0));
+    iteration.try_bind(&(usize::MAX - 1)).unwrap();
+
+    let Err(Error::IterationPanicked { label, message }) = iteration.try_evaluate_with_params(&10) else {
+        panic!("the overflow is not reported");
+    };
+    assert_eq!(label, "counter");
+    assert_eq!(message, "counter overflow");
+
+    // The iteration stays usable after an error
+    iteration.try_bind(&1).unwrap();
+}
+
+#[test]
+fn not_implemented() {
+    let mut iteration = NotImplementedIteration::new("mutation");
+
+    assert!(matches!(
+        TryIteration::<usize>::try_evaluate(&mut iteration),
+        Err(Error::NotImplemented(message)) if message == "mutation"
+    ));
+    assert!(matches!(
+        Fallible::new("mutation", NotImplementedIteration::new("mutation")).try_bind(&1),
+        Err(Error::IterationPanicked { message, .. }) if message == "mutation"
+    ));
+}
+
+#[test]
+fn boxed() {
+    let mut iteration: Box<dyn TryIteration<usize>> = Box::new(Fallible::new("counter", Counter(0)));
+
+    iteration.try_evaluate_with_params(&1).unwrap();
+    assert!(iteration.try_evaluate_with_params_async(&1).unwrap().is_empty());
+}
+
+/// Adds the parameters to a counter, panicking on overflow
+struct Counter(usize);
+
+impl Counter {
+    fn add(&mut self, value: usize) {
+        self.0 = self.0.checked_add(value).expect("counter overflow");
+    }
+}
+
+impl Iteration<usize> for Counter {
+    fn bind(&mut self, params: &usize) {
+        self.0 = *params;
+    }
+
+    fn evaluate(&mut self) {
+        self.add(2);
+    }
+
+    fn evaluate_async(&mut self) -> Vec<wgpu::CommandBuffer> {
+        vec![]
+    }
+
+    fn evaluate_with_params(&mut self, params: &usize) {
+        self.add(*params);
+    }
+
+    fn evaluate_with_params_async(&mut self, params: &usize) -> Vec<wgpu::CommandBuffer> {
+        self.add(*params);
+        vec![]
+    }
+}
@@ -1,2 +1,7 @@
+mod compiled;
+mod conditional;
+mod executor;
+mod fallible;
 mod problem;
+mod sequence;
 mod sliced;
\ No newline at end of file
@@ -1,4 +1,4 @@
-use sgrmath_core::{ReadbackBuffer, StorageBuffer, WgpuContext};
+use sgrmath_core::{Error, ReadbackBuffer, StorageBuffer, TryIteration, WgpuContext};
 
 macro_rules! problem_tests {
     ($shader:expr) => {
@@ -7,19 +7,19 @@ macro_rules! problem_tests {
             let (context, solutions, results, readback) = super::prepare();
 
             let mut problem: Box<dyn sgrmath_core::Iteration<sgrmath_core::ProblemParams>> = Box::new(($shader)(&context));
-            problem.bind(&sgrmath_core::ProblemParams {
-                context: context.clone(),
-                solutions: solutions.clone(),
-                results: results.clone(),
-                solutions_offset: 0,
-                solutions_count: 10,
-                vector_length: 1,
-            });
+            problem.bind(&sgrmath_core::ProblemParams::new(
+                context.clone(),
+                solutions.clone(),
+                results.clone(),
+                0,
+                10,
+                1,
+            ));
 
             problem.evaluate();
 
             assert_eq!(
-                readback.read::<f32>(&context, &results, 0, 10), 
+                readback.read(&context, &results, 0, 10), 
                 (0_i32..10).map(|i| if i < 5 { 2.0 * i as f32 + 1.0 } else { 10.0 }).collect::<Vec<_>>()
             );
         }
@@ -43,17 +43,17 @@ macro_rules! problem_tests {
 
             let mut problem: Box<dyn sgrmath_core::Iteration<sgrmath_core::ProblemParams>> = Box::new(($shader)(&context));
 
-            problem.evaluate_with_params(&sgrmath_core::ProblemParams {
-                context: context.clone(),
-                solutions: solutions.clone(),
-                results: results.clone(),
-                solutions_offset: 0,
-                solutions_count: 10,
-                vector_length: 1,
-            });
+            problem.evaluate_with_params(&sgrmath_core::ProblemParams::new(
+                context.clone(),
+                solutions.clone(),
+                results.clone(),
+                0,
+                10,
+                1,
+            ));
 
             assert_eq!(
-                readback.read::<f32>(&context, &results, 0, 10), 
+                readback.read(&context, &results, 0, 10), 
                 (0_i32..10).map(|i| if i < 5 { 2.0 * i as f32 + 1.0 } else { 10.0 }).collect::<Vec<_>>()
             );
         }
@@ -85,14 +85,120 @@ mod shader {
     );
 }
 
-pub fn prepare() -> (WgpuContext, StorageBuffer, StorageBuffer, ReadbackBuffer) {
+pub fn prepare() -> (WgpuContext, StorageBuffer<f32>, StorageBuffer<f32>, ReadbackBuffer<f32>) {
     let context = WgpuContext::new();
-    let solutions = StorageBuffer::new::<i32, _>(&context, 10);
-    let results = StorageBuffer::new::<i32, _>(&context, 10);
-    let readback = ReadbackBuffer::new::<i32, _>(&context, 10);
+    let solutions = StorageBuffer::<f32>::new(&context, 10);
+    let results = StorageBuffer::<f32>::new(&context, 10);
+    let readback = ReadbackBuffer::<f32>::new(&context, 10);
 
     solutions.update_buffer_range(&context, &(0_i32..10).map(|i| i as f32).collect::<Vec<_>>(), 0);
     results.update_buffer_range(&context, &(0_i32..10).map(|i| 2.0 * i as f32).collect::<Vec<_>>(), 0);
 
     (context, solutions, results, readback)
 }
+
+#[test]
+fn shader_try_evaluate() {
+    let (context, solutions, results, readback) = prepare();
+    let mut problem = sgrmath_core::ShaderProblem::new(
+        sgrmath_core::Shader::new(&context, "test", include_str!("results.wgsl")),
+        vec![]
+    );
+    let mut params = sgrmath_core::ProblemParams::new(context.clone(), solutions, results.clone(), 0, 10, 1);
+
+    assert!(matches!(
+        TryIteration::<sgrmath_core::ProblemParams>::try_evaluate(&mut problem),
+        Err(Error::NotBound(_))
+    ));
+
+    // The shader has no violations binding
+    params.violations = Some(StorageBuffer::<f32>::new(&context, 10));
+    assert!(problem.try_bind(&params).is_err());
+    assert!(TryIteration::<sgrmath_core::ProblemParams>::try_evaluate(&mut problem).is_err());
+
+    params.violations = None;
+    problem.try_bind(&params).unwrap();
+    TryIteration::<sgrmath_core::ProblemParams>::try_evaluate(&mut problem).unwrap();
+    assert_eq!(
+        readback.read(&context, &results, 0, 10), 
+        (0_i32..10).map(|i| if i < 5 { 2.0 * i as f32 + 1.0 } else { 10.0 }).collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn results_layout() {
+    let (context, solutions, results, _) = prepare();
+    let params = sgrmath_core::ProblemParams {
+        context,
+        solutions,
+        results,
+        solutions_offset: 2,
+        solutions_count: 3,
+        vector_length: 1,
+        objectives_count: 2,
+        violations: None,
+    };
+
+    assert_eq!(params.result_index(3, 1), 7);
+    assert_eq!(params.results_range(), 4..10);
+}
+
+#[test]
+fn evaluate_multi_objective() {
+    let (context, solutions, results, readback) = prepare();
+
+    let mut problem = sgrmath_core::CpuProblem::new(
+        |solutions: Vec<f32>, _, params: &sgrmath_core::ProblemParams| {
+            let mut results = vec![0.0; params.solutions_count * params.objectives_count];
+            for i in 0..params.solutions_count {
+                results[params.result_index(i, 0)] = solutions[i];
+                results[params.result_index(i, 1)] = -solutions[i];
+            }
+            results
+        },
+        ()
+    );
+
+    sgrmath_core::Iteration::evaluate_with_params(&mut problem, &sgrmath_core::ProblemParams {
+        context: context.clone(),
+        solutions,
+        results: results.clone(),
+        solutions_offset: 0,
+        solutions_count: 5,
+        vector_length: 1,
+        objectives_count: 2,
+        violations: None,
+    });
+
+    assert_eq!(
+        readback.read(&context, &results, 0, 10),
+        vec![ 0.0, -0.0, 1.0, -1.0, 2.0, -2.0, 3.0, -3.0, 4.0, -4.0 ]
+    );
+}
+
+#[test]
+fn evaluate_constrained() {
+    let (context, solutions, results, readback) = prepare();
+    let violations = StorageBuffer::<f32>::new(&context, 10);
+
+    let mut problem = sgrmath_core::CpuProblem::new(
+        |solutions: Vec<f32>, _, _: &sgrmath_core::ProblemParams| solutions,
+        ()
+    ).constraints(|solutions: &[f32], _, _| solutions.iter().map(|x| (x - 5.0).max(0.0)).collect());
+
+    sgrmath_core::Iteration::evaluate_with_params(&mut problem, &sgrmath_core::ProblemParams {
+        context: context.clone(),
+        solutions,
+        results: results.clone(),
+        solutions_offset: 0,
+        solutions_count: 10,
+        vector_length: 1,
+        objectives_count: 1,
+        violations: Some(violations.clone()),
+    });
+
+    assert_eq!(
+        readback.read(&context, &violations, 0, 10),
+        vec![ 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 1.0, 2.0, 3.0, 4.0 ]
+    );
+}
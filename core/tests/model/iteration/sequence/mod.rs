@@ -0,0 +1,99 @@
+use std::{cell::RefCell, rc::Rc};
+
+use sgrmath_core::{Iteration, MapParams, Sequence};
+
+#[test]
+fn bind() {
+    let (log, mut sequence) = prepare();
+    sequence.bind(&10);
+
+    assert_eq!(*log.borrow(), vec![ "bind 1 (10)", "bind 2 (10)", "bind 3 (\"10\")" ]);
+}
+
+#[test]
+fn evaluate() {
+    let (log, mut sequence) = prepare();
+    sequence.evaluate();
+    sequence.evaluate_async();
+
+    assert_eq!(
+        *log.borrow(), 
+        vec![ "evaluate 1", "evaluate 2", "evaluate 3", "evaluate_async 1", "evaluate_async 2", "evaluate_async 3" ]
+    );
+}
+
+#[test]
+fn evaluate_with_params() {
+    let (log, mut sequence) = prepare();
+    sequence.evaluate_with_params(&3);
+    sequence.evaluate_with_params_async(&4);
+
+    assert_eq!(
+        *log.borrow(), 
+        vec![ 
+            "evaluate 1 (3)", "evaluate 2 (3)", "evaluate 3 (\"3\")", 
+            "evaluate_async 1 (4)", "evaluate_async 2 (4)", "evaluate_async 3 (\"4\")" 
+        ]
+    );
+}
+
+#[test]
+fn edit() {
+    let (log, sequence) = prepare();
+    let mut sequence = sequence.remove(1);
+    sequence.evaluate();
+
+    assert_eq!(sequence.len(), 2);
+    assert_eq!(*log.borrow(), vec![ "evaluate 1", "evaluate 3" ]);
+    assert!(sequence.clear().is_empty());
+}
+
+type Log = Rc<RefCell<Vec<String>>>;
+
+fn prepare() -> (Log, Sequence<usize>) {
+    let log = Rc::new(RefCell::new(vec![]));
+    let sequence = Sequence::new()
+        .add(Box::new(TestIteration::new("1", log.clone())))
+        .add(Box::new(TestIteration::new("2", log.clone())))
+        .add(Box::new(MapParams::new(TestIteration::new("3", log.clone()), |params: &usize| params.to_string())));
+
+    (log, sequence)
+}
+
+struct TestIteration {
+    name: String,
+    log: Log,
+}
+
+impl TestIteration {
+    fn new(name: &str, log: Log) -> Self {
+        Self { name: name.to_string(), log }
+    }
+}
+
+impl<T> Iteration<T> for TestIteration 
+where
+    T: std::fmt::Debug
+{
+    fn bind(&mut self, params: &T) { 
+        self.log.borrow_mut().push(format!("bind {} ({params:?})", self.name)); 
+    }
+    
+    fn evaluate(&mut self) { 
+        self.log.borrow_mut().push(format!("evaluate {}", self.name));
+    }
+
+    fn evaluate_with_params(&mut self, params: &T) { 
+        self.log.borrow_mut().push(format!("evaluate {} ({params:?})", self.name));
+    }
+    
+    fn evaluate_async(&mut self) -> Vec<wgpu::CommandBuffer> { 
+        self.log.borrow_mut().push(format!("evaluate_async {}", self.name));
+        vec![] 
+    }
+    
+    fn evaluate_with_params_async(&mut self, params: &T) -> Vec<wgpu::CommandBuffer> { 
+        self.log.borrow_mut().push(format!("evaluate_async {} ({params:?})", self.name));
+        vec![] 
+    }
+}
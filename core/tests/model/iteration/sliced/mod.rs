@@ -33,6 +33,81 @@ fn distribute() {
     );
 }
 
+#[test]
+fn distribute_percentage() {
+    assert_eq!(
+        slices(&[ IterationSize::Percentage(10.0), IterationSize::Percentage(25.0), IterationSize::Proportional(1.0) ])
+            .distribute(30), 
+        &[ 3, 7, 20 ]
+    );
+}
+
+#[test]
+fn distribute_bounded() {
+    assert_eq!(
+        slices(&[ 
+            IterationSize::Bounded { weight: 1.0, min: 0, max: 4 }, 
+            IterationSize::Proportional(1.0), 
+            IterationSize::Bounded { weight: 1.0, min: 8, max: 100 },
+        ])
+            .distribute(20), 
+        &[ 4, 8, 8 ]
+    );
+    assert_eq!(
+        slices(&[ IterationSize::Bounded { weight: 1.0, min: 0, max: 4 }, IterationSize::Bounded { weight: 1.0, min: 0, max: 5 } ])
+            .distribute(20), 
+        &[ 4, 5 ]
+    );
+}
+
+#[test]
+#[should_panic(expected = "Remaining size is less than sum of minimum sizes")]
+fn distribute_bounded_minimums() {
+    slices(&[ IterationSize::Bounded { weight: 1.0, min: 15, max: 20 }, IterationSize::Bounded { weight: 2.0, min: 10, max: 20 } ])
+        .distribute(20);
+}
+
+#[test]
+fn distribute_remainder() {
+    assert_eq!(
+        slices(&[ IterationSize::Proportional(1.0), IterationSize::Proportional(1.0), IterationSize::Remainder, 2.into() ])
+            .distribute(17), 
+        &[ 7, 7, 1, 2 ]
+    );
+    assert_eq!(
+        slices(&[ IterationSize::Remainder, IterationSize::Percentage(50.0), IterationSize::Remainder ])
+            .distribute(13), 
+        &[ 3, 6, 4 ]
+    );
+}
+
+#[test]
+fn distribute_total_change() {
+    let mut iteration = slices(&[ 1.into(), IterationSize::Proportional(1.0) ]);
+
+    assert_eq!(iteration.distribute(10), &[ 1, 9 ]);
+    assert_eq!(iteration.distribute(20), &[ 1, 19 ]);
+}
+
+#[test]
+fn set_weight() {
+    let mut iteration = slices(&[ IterationSize::Proportional(1.0), IterationSize::Bounded { weight: 1.0, min: 0, max: 10 } ]);
+    assert_eq!(iteration.distribute(20), &[ 10, 10 ]);
+
+    iteration.set_weight(0, 3.0);
+    assert_eq!(iteration.distribute(20), &[ 15, 5 ]);
+
+    iteration.set_weight(1, 0.0).set_size(0, 4);
+    assert_eq!(iteration.distribute(20), &[ 4, 0 ]);
+    assert_eq!(iteration[1].0, IterationSize::Bounded { weight: 0.0, min: 0, max: 10 });
+}
+
+#[test]
+#[should_panic(expected = "Only proportional sizes have a weight")]
+fn set_weight_fixed() {
+    slices(&[ 1.into() ]).set_weight(0, 2.0);
+}
+
 #[test]
 fn bind() {
     let (params, _, mut iteration) = prepare();
@@ -44,6 +119,20 @@ fn bind() {
     );
 }
 
+#[test]
+fn set_weight_rebinds() {
+    let (params, _, mut iteration) = prepare();
+    iteration.bind(&TestParams { start: 0, end: 20 });
+    iteration.set_weight(3, 1.0);
+    params.borrow_mut().clear();
+    iteration.evaluate();
+
+    assert_eq!(
+        params.borrow().iter().map(|p| p.start..p.end).collect::<Vec<_>>(),
+        vec![ 0..1, 1..10, 10..15, 15..20 ]
+    );
+}
+
 #[test]
 fn evaluate() {
     let (_, evaluated, mut iteration) = prepare();
@@ -98,6 +187,12 @@ fn evaluate_with_params_async() {
     );
 }
 
+fn slices(sizes: &[IterationSize]) -> SlicedIteration<TestParams> {
+    sizes.iter().fold(SlicedIteration::new(), |iteration, size| {
+        iteration.add(*size, Box::new(NotImplementedIteration::new("slice")))
+    })
+}
+
 fn prepare() -> (Rc<RefCell<Vec<TestParams>>>, Rc<RefCell<Vec<String>>>, SlicedIteration<TestParams>) {
     let params = Rc::new(RefCell::new(vec![]));
     let evaluated = Rc::new(RefCell::new(vec![]));
@@ -118,6 +213,8 @@ struct TestParams {
 
 struct TestIteration {
     name: String,
+    bound: Option<TestParams>,
+    /// The params of every bind and evaluation
     params: Rc<RefCell<Vec<TestParams>>>,
     evaluated: Rc<RefCell<Vec<String>>>,
 }
@@ -132,6 +229,7 @@ impl TestIteration {
     {
         Self { 
             name: name.into(), 
+            bound: None,
             params, 
             evaluated 
         }
@@ -140,10 +238,12 @@ impl TestIteration {
 
 impl Iteration<TestParams> for TestIteration {
     fn bind(&mut self, params: &TestParams) { 
+        self.bound = Some(params.clone());
         self.params.borrow_mut().push(params.clone()); 
     }
     
     fn evaluate(&mut self) { 
+        self.params.borrow_mut().extend(self.bound.clone());
         self.evaluated.borrow_mut().push(self.name.clone());
     }
 
@@ -1 +1,4 @@
-mod iteration;
\ No newline at end of file
+mod constraint_handling;
+mod iteration;
+mod optimization_direction;
+mod schedule;
\ No newline at end of file
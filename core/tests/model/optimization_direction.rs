@@ -1,3 +1,7 @@
+use std::cmp::Ordering;
+
+use sgrmath_core::{FitnessPolicy, InfinityPolicy, NanPolicy, OptimizationDirection};
+
 #[test]
 fn compare() {
     assert_eq!(OptimizationDirection::Minimize.compare(&1.0, &2.0), Ordering::Less);
@@ -11,12 +15,70 @@ fn compare() {
 
 #[test]
 fn sort() {
-    assert_eq!(
-        vec![1.0, 3.0, 2.0].sort_by(|a, b| OptimizationDirection::Minimize.compare(a, b)),
-        vec![1.0, 2.0, 3.0]
-    );
-    assert_eq!(
-        vec![1.0, 3.0, 2.0].sort_by(|a, b| OptimizationDirection::Maximize.compare(a, b)),
-        vec![3.0, 2.0, 1.0]
-    );
-}
\ No newline at end of file
+    let mut values = vec![1.0, 3.0, 2.0];
+    values.sort_by(|a, b| OptimizationDirection::Minimize.compare(a, b));
+    assert_eq!(values, vec![1.0, 2.0, 3.0]);
+
+    values.sort_by(|a, b| OptimizationDirection::Maximize.compare(a, b));
+    assert_eq!(values, vec![3.0, 2.0, 1.0]);
+}
+
+#[test]
+fn sort_nan() {
+    for direction in [ OptimizationDirection::Minimize, OptimizationDirection::Maximize ] {
+        let mut values = [f32::NAN, 1.0, f32::NAN, 3.0];
+        values.sort_by(|a, b| direction.compare(a, b));
+
+        assert_eq!(values[..2], if direction.is_minimize() { [1.0, 3.0] } else { [3.0, 1.0] });
+        assert!(values[2..].iter().all(|value| value.is_nan()));
+    }
+}
+
+#[test]
+fn nan_policy() {
+    let best = FitnessPolicy { nan: NanPolicy::Best, ..FitnessPolicy::default() };
+    let error = FitnessPolicy { nan: NanPolicy::Error, ..FitnessPolicy::default() };
+
+    assert!(OptimizationDirection::Minimize.is_better(&f32::NAN, &f32::NEG_INFINITY, &best));
+    assert!(OptimizationDirection::Maximize.is_better(&f32::INFINITY, &f32::NAN, &error));
+    assert!(!OptimizationDirection::Minimize.is_better(&f32::NAN, &f32::NAN, &best));
+
+    assert!(best.check(f32::NAN).is_ok());
+    assert!(matches!(error.check(f32::NAN), Err(sgrmath_core::Error::InvalidFitness(value)) if value.is_nan()));
+    assert!(error.check(f32::INFINITY).is_ok());
+}
+
+#[test]
+fn infinity_policy() {
+    let ordinary = FitnessPolicy::default();
+    let worst = FitnessPolicy { infinity: InfinityPolicy::Worst, ..FitnessPolicy::default() };
+    let error = FitnessPolicy { infinity: InfinityPolicy::Error, ..FitnessPolicy::default() };
+
+    assert!(OptimizationDirection::Minimize.is_better(&f32::NEG_INFINITY, &0.0, &ordinary));
+    assert!(OptimizationDirection::Minimize.is_better(&0.0, &f32::NEG_INFINITY, &worst));
+    assert!(OptimizationDirection::Maximize.is_better(&0.0, &f32::INFINITY, &worst));
+    assert!(OptimizationDirection::Minimize.is_better(&f32::INFINITY, &f32::NAN, &worst));
+
+    assert!(error.check(f32::NEG_INFINITY).is_err());
+    assert!(error.check(1.0).is_ok());
+}
+
+#[test]
+fn best_of() {
+    let policy = FitnessPolicy::default();
+    let values = vec![2.0, f32::NAN, 1.0, 5.0, 1.0];
+
+    assert_eq!(OptimizationDirection::Minimize.best_of(values.clone(), &policy), Some((2, 1.0)));
+    assert_eq!(OptimizationDirection::Maximize.best_of(values, &policy), Some((3, 5.0)));
+    assert_eq!(OptimizationDirection::Minimize.best_of(vec![], &policy), None);
+}
+
+#[test]
+fn worst_value() {
+    let policy = FitnessPolicy::default();
+
+    for direction in [ OptimizationDirection::Minimize, OptimizationDirection::Maximize ] {
+        assert!(!direction.is_better(&direction.worst_value(), &0.0, &policy));
+        assert!(direction.is_better(&0.0, &direction.worst_value(), &policy));
+    }
+}
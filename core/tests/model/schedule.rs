@@ -0,0 +1,64 @@
+use sgrmath_core::Schedule;
+
+#[test]
+fn constant() {
+    let schedule = Schedule::from(0.5);
+
+    assert!(schedule.is_constant());
+    assert_eq!(schedule.value(0), 0.5);
+    assert_eq!(schedule.value(1000), 0.5);
+}
+
+#[test]
+fn linear() {
+    let schedule = Schedule::Linear { start: 1.0, end: 0.0, steps: 4 };
+
+    assert_eq!(
+        (0..6).map(|step| schedule.value(step)).collect::<Vec<_>>(),
+        vec![ 1.0, 0.75, 0.5, 0.25, 0.0, 0.0 ]
+    );
+}
+
+#[test]
+fn exponential() {
+    let schedule = Schedule::Exponential { start: 8.0, rate: 0.5 };
+
+    assert_eq!(
+        (0..4).map(|step| schedule.value(step)).collect::<Vec<_>>(),
+        vec![ 8.0, 4.0, 2.0, 1.0 ]
+    );
+}
+
+#[test]
+fn piecewise() {
+    let schedule = Schedule::Piecewise(vec![ (2, 0.3), (5, 0.1) ]);
+
+    assert_eq!(
+        (0..7).map(|step| schedule.value(step)).collect::<Vec<_>>(),
+        vec![ 0.3, 0.3, 0.3, 0.3, 0.3, 0.1, 0.1 ]
+    );
+    assert_eq!(Schedule::Piecewise(vec![]).value(3), 0.0);
+}
+
+#[test]
+fn min() {
+    assert_eq!(Schedule::from(0.5).min(), Some(0.5));
+    assert_eq!(Schedule::Linear { start: 1.0, end: -1.0, steps: 4 }.min(), Some(-1.0));
+    assert_eq!(Schedule::Exponential { start: 8.0, rate: 0.5 }.min(), Some(0.0));
+    assert_eq!(Schedule::Exponential { start: 8.0, rate: 1.0 }.min(), Some(8.0));
+    assert_eq!(Schedule::Exponential { start: 8.0, rate: -0.5 }.min(), Some(-4.0));
+    assert_eq!(Schedule::Exponential { start: 8.0, rate: 2.0 }.min(), Some(8.0));
+    assert_eq!(Schedule::Exponential { start: -8.0, rate: 2.0 }.min(), Some(f32::NEG_INFINITY));
+    assert_eq!(Schedule::Piecewise(vec![ (2, 0.3), (5, 0.1) ]).min(), Some(0.1));
+    assert_eq!(Schedule::Piecewise(vec![]).min(), Some(0.0));
+    assert_eq!(Schedule::custom(|step| step as f32).min(), None);
+}
+
+#[test]
+fn custom() {
+    let schedule = Schedule::custom(|step| step as f32 * 2.0);
+
+    assert!(!schedule.is_constant());
+    assert_eq!(schedule.value(3), 6.0);
+    assert_eq!(format!("{schedule:?}"), "Custom(..)");
+}
@@ -0,0 +1,119 @@
+use sgrmath_core::{Iteration, ProfileKind, Profiled, Shader, StorageBuffer, WgpuContext};
+
+#[test]
+fn disabled() {
+    let (context, shader, source, target) = prepare(false);
+
+    shader.execute_with_params(&context, 10, &[&source, &target]).unwrap();
+
+    assert!(!context.profiler.is_enabled());
+    assert!(context.profiler.report(&context).unwrap().entries.is_empty());
+}
+
+#[test]
+fn dispatches() {
+    let (context, shader, source, target) = prepare(true);
+
+    shader.execute_with_params(&context, 10, &[&source, &target]).unwrap();
+    shader.execute_with_params(&context, 10, &[&source, &target]).unwrap();
+    let report = context.profiler.report(&context).unwrap();
+
+    let entry = report.get("plus_one", ProfileKind::Dispatch).unwrap();
+    assert_eq!(report.entries.len(), 1);
+    assert_eq!(entry.calls, 2);
+    assert_eq!(entry.gpu.is_some(), context.profiler.has_timestamps());
+    if !context.profiler.has_timestamps() {
+        assert!(entry.cpu > std::time::Duration::ZERO);
+    }
+}
+
+#[test]
+fn report_clears() {
+    let (context, shader, source, target) = prepare(true);
+
+    shader.execute_with_params(&context, 10, &[&source, &target]).unwrap();
+    context.profiler.report(&context).unwrap();
+
+    assert!(context.profiler.report(&context).unwrap().entries.is_empty());
+}
+
+#[test]
+fn iterations() {
+    let (context, shader, source, target) = prepare(true);
+    let mut iteration = Profiled::new(&context, "plus", PlusOne { context: context.clone(), shader, source, target });
+
+    iteration.evaluate();
+    iteration.evaluate_with_params(&());
+    context.queue.submit(iteration.evaluate_async());
+    let report = context.profiler.report(&context).unwrap();
+
+    let labels = report.entries.iter().map(|entry| (entry.label.as_str(), entry.kind)).collect::<Vec<_>>();
+    assert_eq!(labels, vec![("plus", ProfileKind::Iteration), ("plus_one", ProfileKind::Dispatch)]);
+
+    let plus = report.get("plus", ProfileKind::Iteration).unwrap();
+    let plus_one = report.get("plus_one", ProfileKind::Dispatch).unwrap();
+    assert_eq!(plus.calls, 3);
+    assert_eq!(plus_one.calls, 3);
+    assert_eq!(plus.gpu, plus_one.gpu);
+    assert_eq!(report.cpu(), plus.cpu);
+}
+
+#[test]
+fn scopes() {
+    let (context, shader, source, target) = prepare(true);
+
+    {
+        let _outer = context.profiler.scope("outer");
+        shader.execute_with_params(&context, 10, &[&source, &target]).unwrap();
+
+        let _inner = context.profiler.scope("inner");
+        shader.execute_with_params(&context, 10, &[&source, &target]).unwrap();
+    }
+    let report = context.profiler.report(&context).unwrap();
+
+    assert_eq!(report.get("outer", ProfileKind::Iteration).unwrap().calls, 1);
+    assert_eq!(report.get("inner", ProfileKind::Iteration).unwrap().calls, 1);
+    assert!(report.get("outer", ProfileKind::Iteration).unwrap().cpu >= report.get("inner", ProfileKind::Iteration).unwrap().cpu);
+    assert_eq!(report.gpu(), report.get("outer", ProfileKind::Iteration).unwrap().gpu);
+
+    let output = report.to_string();
+    assert!(output.starts_with("label"));
+    assert!(output.contains("outer"));
+    assert!(output.contains("plus_one"));
+}
+
+struct PlusOne {
+    context: WgpuContext,
+    shader: Shader,
+    source: StorageBuffer<i32>,
+    target: StorageBuffer<i32>,
+}
+
+impl Iteration<()> for PlusOne {
+    fn bind(&mut self, _params: &()) {}
+
+    fn evaluate(&mut self) {
+        self.shader.execute_with_params(&self.context, 10, &[&self.source, &self.target]).unwrap();
+    }
+
+    fn evaluate_async(&mut self) -> Vec<wgpu::CommandBuffer> {
+        vec![self.shader.execute_with_params_async(&self.context, 10, &[&self.source, &self.target]).unwrap()]
+    }
+
+    fn evaluate_with_params(&mut self, _params: &()) {
+        self.evaluate();
+    }
+
+    fn evaluate_with_params_async(&mut self, _params: &()) -> Vec<wgpu::CommandBuffer> {
+        self.evaluate_async()
+    }
+}
+
+fn prepare(profiling: bool) -> (WgpuContext, Shader, StorageBuffer<i32>, StorageBuffer<i32>) {
+    let context = WgpuContext::builder().profiling(profiling).build().unwrap();
+    let shader = Shader::new(&context, "plus_one", include_str!("../shader/plus_one.wgsl"));
+    let source = StorageBuffer::<i32>::new(&context, 10);
+    let target = StorageBuffer::<i32>::new(&context, 10);
+
+    (context, shader, source, target)
+}
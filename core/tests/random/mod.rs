@@ -0,0 +1,151 @@
+use sgrmath_core::{philox4x32, Random, RandomKey, RandomStream, RandomStreams, ReadbackBuffer, Shader, StorageBuffer, WgpuContext};
+
+const SOURCE: &str = r#"
+#include "sgrmath::random"
+
+struct Dispatch {
+    random: RandomKey,
+    bound: u32,
+    _padding: u32,
+}
+
+@group(0) @binding(0) var<storage, read_write> words: array<u32>;
+@group(0) @binding(1) var<storage, read_write> values: array<f32>;
+
+@compute @workgroup_size(8)
+fn main(@builtin(global_invocation_id) global_id: vec3<u32>) {
+    var rng = random_init(dispatch.random, global_id.x);
+    for (var i = 0u; i < 5u; i = i + 1u) {
+        words[global_id.x * 10u + i] = random_u32(&rng);
+    }
+    for (var i = 5u; i < 10u; i = i + 1u) {
+        words[global_id.x * 10u + i] = random_below(&rng, dispatch.bound);
+    }
+    values[global_id.x * 4u] = random_uniform(&rng);
+    values[global_id.x * 4u + 1u] = random_range(&rng, -2.0, 3.0);
+    values[global_id.x * 4u + 2u] = random_normal(&rng);
+    values[global_id.x * 4u + 3u] = random_normal(&rng);
+}
+"#;
+
+#[repr(C)]
+#[derive(Clone, Copy, bytemuck::Pod, bytemuck::Zeroable)]
+struct Dispatch {
+    random: RandomKey,
+    bound: u32,
+    _padding: u32,
+}
+
+#[test]
+fn philox_known_answers() {
+    assert_eq!(philox4x32([0; 4], [0; 2]), [0x6627_e8d5, 0xe169_c58d, 0xbc57_ac4c, 0x9b00_dbd8]);
+    assert_eq!(
+        philox4x32([u32::MAX; 4], [u32::MAX; 2]),
+        [0x408f_276d, 0x41c8_3b0e, 0xa20b_c7c6, 0x6d54_51fd]
+    );
+    assert_eq!(
+        philox4x32([0x243f_6a88, 0x85a3_08d3, 0x1319_8a2e, 0x0370_7344], [0xa409_3822, 0x299f_31d0]),
+        [0xd16c_fe09, 0x94fd_cceb, 0x5001_e420, 0x2412_6ea1]
+    );
+}
+
+#[test]
+fn cpu_draws() {
+    let key = RandomStream::new(7, 3).next_key();
+    let mut random = Random::new(key, 5);
+
+    let words = (0..6).map(|_| random.next_u32()).collect::<Vec<_>>();
+    let first = philox4x32([5, 0, 3, 0], [7, 0]);
+    let second = philox4x32([5, 1, 3, 0], [7, 0]);
+    assert_eq!(words, [first[0], first[1], first[2], first[3], second[0], second[1]]);
+
+    for _ in 0..1000 {
+        assert!((0.0..1.0).contains(&random.uniform()));
+        assert!((-1.0..2.0).contains(&random.range(-1.0, 2.0)));
+        assert!(random.below(3) < 3);
+        assert!(random.normal().is_finite());
+    }
+}
+
+#[test]
+fn cpu_moments() {
+    let mut random = Random::new(RandomStream::new(11, 0).next_key(), 0);
+    let count = 20_000;
+
+    let uniform = (0..count).map(|_| random.uniform()).sum::<f32>() / count as f32;
+    let normals = (0..count).map(|_| random.normal()).collect::<Vec<_>>();
+    let mean = normals.iter().sum::<f32>() / count as f32;
+    let variance = normals.iter().map(|value| (value - mean).powi(2)).sum::<f32>() / count as f32;
+    let mut histogram = [0; 4];
+    (0..count).for_each(|_| histogram[random.below(4) as usize] += 1);
+
+    assert!((uniform - 0.5).abs() < 0.01);
+    assert!(mean.abs() < 0.03);
+    assert!((variance - 1.0).abs() < 0.05);
+    assert!(histogram.iter().all(|&bin| (4700..5300).contains(&bin)));
+}
+
+#[test]
+fn shader_matches_cpu() {
+    let context = WgpuContext::new();
+    let mut shader = Shader::builder("random", SOURCE)
+        .push_constants("dispatch", "Dispatch")
+        .build(&context)
+        .unwrap();
+    let words = StorageBuffer::<u32>::new(&context, 160);
+    let values = StorageBuffer::<f32>::new(&context, 64);
+
+    let mut stream = RandomStream::new(0x1234_5678_9abc_def0, 4);
+    stream.set_counter(u32::MAX);
+    for _ in 0..2 {
+        let key = stream.next_key();
+        shader.set_push_constants(&Dispatch { random: key, bound: 10, _padding: 0 });
+        shader.execute_with_params(&context, 16, &[&words, &values]).unwrap();
+
+        let gpu_words = ReadbackBuffer::<u32>::new(&context, 160).read(&context, &words, 0, 160);
+        let gpu_values = ReadbackBuffer::<f32>::new(&context, 64).read(&context, &values, 0, 64);
+        for index in 0..16 {
+            let mut random = Random::new(key, index as u32);
+            let mut cpu_words = (0..5).map(|_| random.next_u32()).collect::<Vec<_>>();
+            cpu_words.extend((0..5).map(|_| random.below(10)));
+            let cpu_values = [random.uniform(), random.range(-2.0, 3.0), random.normal(), random.normal()];
+
+            assert_eq!(gpu_words[index * 10..index * 10 + 10], cpu_words);
+            assert_eq!(gpu_values[index * 4], cpu_values[0]);
+            for (gpu, cpu) in gpu_values[index * 4 + 1..index * 4 + 4].iter().zip(&cpu_values[1..]) {
+                assert!((gpu - cpu).abs() < 1e-4, "{gpu} != {cpu}");
+            }
+        }
+    }
+}
+
+#[test]
+fn stream() {
+    let mut stream = RandomStream::new(0x0000_0002_0000_0001, 9);
+
+    assert_eq!(stream.key(), RandomKey { seed: [1, 2], stream: 9, counter: 0 });
+    assert_eq!(stream.next_key(), RandomKey { seed: [1, 2], stream: 9, counter: 0 });
+    assert_eq!(stream.next_key().counter, 1);
+    assert_eq!(stream.counter(), 2);
+
+    stream.set_counter(u32::MAX);
+    assert_eq!(stream.next_key().counter, u32::MAX);
+    assert_eq!(stream.counter(), 0);
+}
+
+#[test]
+fn streams() {
+    let mut streams = RandomStreams::new(5);
+    let first = streams.stream();
+    let second = streams.stream();
+
+    assert_eq!(streams.seed(), 5);
+    assert_eq!(first, RandomStream::new(5, 0));
+    assert_eq!(second, RandomStream::new(5, 1));
+    assert_eq!(streams.stream_with_id(1), second);
+    assert_eq!(streams.stream(), RandomStream::new(5, 2));
+    assert_ne!(
+        Random::new(first.key(), 0).next_u32(),
+        Random::new(second.key(), 0).next_u32()
+    );
+}
@@ -1,14 +1,17 @@
-use sgrmath_core::{ReadbackBuffer, Shader, StorageBuffer, WgpuContext};
+use sgrmath_core::{
+    Access, AddressSpace, BindingError, BindingInfo, CompositionError, Error, ReadbackBuffer, Shader, StorageBuffer,
+    WgpuContext
+};
 
 #[test]
 fn execute_bounded() {
     let (context, mut shader, source, target, readback) = prepare();
 
-    shader.bind(&context, &[&source, &target]);
+    shader.bind(&context, &[&source, &target]).unwrap();
     shader.execute(&context, 5);
 
     assert_eq!(
-        readback.read::<i32>(&context, &target, 0, 10), 
+        readback.read(&context, &target, 0, 10), 
         (0_i32..10).map(|i| if i < 5 { 2 * i + 1 } else { 10 }).collect::<Vec<_>>()
     );
 }
@@ -28,20 +31,569 @@ fn execute_unbounded() {
 fn execute_with_params() {
     let (context, shader, source, target, readback) = prepare();
 
-    shader.execute_with_params(&context, 5, &[&source, &target]);
+    shader.execute_with_params(&context, 5, &[&source, &target]).unwrap();
 
     assert_eq!(
-        readback.read::<i32>(&context, &target, 0, 10), 
+        readback.read(&context, &target, 0, 10), 
         (0_i32..10).map(|i| if i < 5 { 2 * i + 1 } else { 10 }).collect::<Vec<_>>()
     );
 }
 
+#[test]
+fn bindings() {
+    let (_, shader, _, _, _) = prepare();
+
+    assert_eq!(
+        shader.bindings(),
+        &[
+            BindingInfo {
+                name: "source_buffer".to_string(),
+                group: 0,
+                binding: 0,
+                space: AddressSpace::Storage,
+                access: Access::Read,
+                min_size: 4,
+            },
+            BindingInfo {
+                name: "target_buffer".to_string(),
+                group: 0,
+                binding: 1,
+                space: AddressSpace::Storage,
+                access: Access::ReadWrite,
+                min_size: 4,
+            },
+        ]
+    );
+}
+
+#[test]
+fn bindings_unused() {
+    let context = WgpuContext::new();
+    let shader = Shader::new(
+        &context,
+        "unused",
+        r"
+        @group(0) @binding(0) var<uniform> options: vec4<f32>;
+        @group(0) @binding(1) var<storage, read_write> values: array<f32, 16>;
+
+        @compute @workgroup_size(1)
+        fn main(@builtin(global_invocation_id) global_id: vec3<u32>) {
+            values[global_id.x] = 1.0;
+        }
+        "
+    );
+
+    assert_eq!(shader.bindings().len(), 1);
+    assert_eq!(shader.bindings()[0].name, "values");
+    assert_eq!(shader.bindings()[0].min_size, 64);
+}
+
+#[test]
+fn try_new_invalid() {
+    let context = WgpuContext::new();
+    let result = Shader::try_new(&context, "invalid", "fn main() { let x: u32 = 1.0; }");
+
+    assert!(matches!(result, Err(Error::ShaderCompilation { label, .. }) if label == "invalid"));
+}
+
+#[test]
+fn try_new_missing_entry_point() {
+    let context = WgpuContext::new();
+    let result = Shader::try_new(&context, "no_main", "@compute @workgroup_size(1) fn other() {}");
+
+    assert!(matches!(result, Err(Error::ShaderCompilation { .. })));
+}
+
+#[test]
+fn binding_count() {
+    let (context, mut shader, source, _, _) = prepare();
+
+    assert!(matches!(
+        shader.bind(&context, &[&source]),
+        Err(Error::BindingCount { expected: 2, actual: 1, .. })
+    ));
+    assert!(!shader.is_bound());
+}
+
+#[test]
+fn binding_usage() {
+    let (_, shader, source, _, readback) = prepare();
+
+    let Err(Error::InvalidBinding { binding, name, reason, .. }) = shader.validate(&[&source, &readback.0]) else {
+        panic!("expected an invalid binding");
+    };
+
+    assert_eq!(binding, 1);
+    assert_eq!(name, "target_buffer");
+    assert!(matches!(reason, BindingError::MissingUsage { required, .. } if required == wgpu::BufferUsages::STORAGE));
+}
+
+#[test]
+fn binding_size() {
+    let context = WgpuContext::new();
+    let shader = Shader::new(
+        &context,
+        "fixed",
+        r"
+        @group(0) @binding(0) var<storage, read_write> values: array<f32, 16>;
+
+        @compute @workgroup_size(1)
+        fn main(@builtin(global_invocation_id) global_id: vec3<u32>) {
+            values[global_id.x] = 1.0;
+        }
+        "
+    );
+    let values = StorageBuffer::<f32>::new(&context, 8);
+
+    assert!(matches!(
+        shader.execute_with_params(&context, 1, &[&values]),
+        Err(Error::InvalidBinding { reason: BindingError::TooSmall { size: 32, min_size: 64 }, .. })
+    ));
+}
+
+#[test]
+fn binding_aliased() {
+    let (context, shader, source, _, _) = prepare();
+
+    assert!(matches!(
+        shader.execute_with_params_async(&context, 1, &[&source, &source]),
+        Err(Error::InvalidBinding { binding: 1, reason: BindingError::Aliased { other: 0 }, .. })
+    ));
+}
+
+mod builder {
+    use super::*;
+
+    const SOURCE: &str = r#"
+#include "buffers"
+
+@compute @workgroup_size(1)
+fn main(@builtin(global_invocation_id) global_id: vec3<u32>) {
+#ifdef DOUBLE
+    target_buffer[global_id.x] = source_buffer[global_id.x] * FACTOR;
+#else
+    target_buffer[global_id.x] = source_buffer[global_id.x] + FACTOR;
+#endif
+}
+"#;
+
+    const BUFFERS: &str = r#"
+#include "types"
+@group(0) @binding(0) var<storage, read> source_buffer: array<Value, LENGTH>;
+@group(0) @binding(1) var<storage, read_write> target_buffer: array<Value, LENGTH>;
+"#;
+
+    #[test]
+    fn compose() {
+        let source = Shader::builder("test", SOURCE)
+            .include("buffers", BUFFERS)
+            .include("types", "alias Value = i32;")
+            .constant("LENGTH", 10_u32)
+            .constant("FACTOR", 3_i32)
+            .compose()
+            .unwrap();
+
+        assert!(source.starts_with("const LENGTH: u32 = 10u;\nconst FACTOR: i32 = 3i;\n"));
+        assert!(source.contains("alias Value = i32;"));
+        assert!(source.contains("+ FACTOR"));
+        assert!(!source.contains("* FACTOR"));
+        assert!(!source.contains('#'));
+    }
+
+    #[test]
+    fn build() {
+        let (context, _, source, target, readback) = prepare();
+
+        let shader = Shader::builder("test", SOURCE)
+            .include("buffers", BUFFERS)
+            .include("types", "alias Value = i32;")
+            .constant("LENGTH", 10_u32)
+            .constant("FACTOR", 3_i32)
+            .define_if("DOUBLE", true)
+            .build(&context)
+            .unwrap();
+        assert_eq!(shader.bindings()[0].min_size, 40);
+
+        shader.execute_with_params(&context, 5, &[&source, &target]).unwrap();
+        assert_eq!(
+            readback.read(&context, &target, 0, 10),
+            (0_i32..10).map(|i| if i < 5 { 6 * i } else { 10 }).collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn override_constant() {
+        let (context, _, source, target, readback) = prepare();
+
+        let shader = Shader::builder(
+            "override",
+            r"
+            override offset: i32;
+
+            @group(0) @binding(0) var<storage, read> source_buffer: array<i32>;
+            @group(0) @binding(1) var<storage, read_write> target_buffer: array<i32>;
+
+            @compute @workgroup_size(1)
+            fn main(@builtin(global_invocation_id) global_id: vec3<u32>) {
+                target_buffer[global_id.x] = source_buffer[global_id.x] + offset;
+            }
+            "
+        )
+            .override_constant("offset", 5.0)
+            .build(&context)
+            .unwrap();
+
+        shader.execute_with_params(&context, 10, &[&source, &target]).unwrap();
+        assert_eq!(readback.read(&context, &target, 0, 10), (0_i32..10).map(|i| 2 * i + 5).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn include_once() {
+        let source = Shader::builder("test", "#include \"a\"\n#include \"b\"\n#include \"a\"")
+            .include("a", "#include \"b\"\nstruct A { value: B }")
+            .include("b", "#include \"a\"\nstruct B { value: u32 }")
+            .compose()
+            .unwrap();
+
+        assert_eq!(source, "struct B { value: u32 }\nstruct A { value: B }\n");
+    }
+
+    #[test]
+    fn nested_conditionals() {
+        let source = Shader::builder("test", "#ifndef A\n#ifdef B\nb\n#else\nnot_b\n#endif\n#else\na\n#endif")
+            .define("B")
+            .compose()
+            .unwrap();
+
+        assert_eq!(source, "b\n");
+    }
+
+    #[test]
+    fn errors() {
+        let compose = |source: &str| match Shader::builder("test", source).compose() {
+            Err(Error::ShaderComposition { line, reason, .. }) => (line, reason),
+            result => panic!("unexpected result {result:?}"),
+        };
+
+        assert_eq!(compose("\n#include \"missing\""), (2, CompositionError::UnknownInclude("missing".to_string())));
+        assert_eq!(compose("#define A"), (1, CompositionError::UnknownDirective("define".to_string())));
+        assert_eq!(compose("#ifdef"), (1, CompositionError::MissingArgument("ifdef".to_string())));
+        assert_eq!(compose("#endif"), (1, CompositionError::UnexpectedDirective("endif".to_string())));
+        assert_eq!(compose("#ifdef A\n#else\n#else"), (3, CompositionError::UnexpectedDirective("else".to_string())));
+        assert_eq!(compose("#ifdef A\nx"), (2, CompositionError::UnterminatedConditional));
+    }
+
+    #[test]
+    fn include_inactive() {
+        let source = Shader::builder("test", "#ifdef A\n#include \"missing\"\n#endif\nmain")
+            .compose()
+            .unwrap();
+
+        assert_eq!(source, "main\n");
+    }
+}
+
+mod dispatch {
+    use super::*;
+
+    const SOURCE: &str = r#"
+#include "sgrmath::dispatch"
+
+@group(0) @binding(0) var<storage, read_write> target_buffer: array<u32>;
+
+@compute @workgroup_size(WORKGROUP_SIZE_X, WORKGROUP_SIZE_Y, WORKGROUP_SIZE_Z)
+fn main(
+    @builtin(global_invocation_id) global_id: vec3<u32>,
+    @builtin(num_workgroups) num_workgroups: vec3<u32>,
+) {
+    let size = vec3<u32>(arrayLength(&target_buffer) / 2u, 2u, 1u);
+    let index = dispatch_index(global_id, num_workgroups);
+    if (!dispatch_in_bounds(index, size)) {
+        return;
+    }
+
+    let position = dispatch_position(index, size);
+    target_buffer[index] = position.x + position.y;
+}
+"#;
+
+    #[test]
+    fn workgroup_size() {
+        let context = WgpuContext::new();
+        let shader = Shader::builder("dispatch", SOURCE)
+            .workgroup_size(8, 4, 2)
+            .build(&context)
+            .unwrap();
+
+        assert_eq!(shader.workgroup_size(), [8, 4, 2]);
+        assert_eq!(shader.workgroup_count(&context, 64), [1, 1, 1]);
+        assert_eq!(shader.workgroup_count(&context, (10, 10)), [2, 1, 1]);
+        assert_eq!(shader.workgroup_count(&context, 0), [0, 0, 0]);
+    }
+
+    #[test]
+    fn workgroup_count_folded() {
+        let context = WgpuContext::new();
+        let max = context.device.limits().max_compute_workgroups_per_dimension;
+        let shader = Shader::builder("dispatch", SOURCE)
+            .workgroup_size(1, 1, 1)
+            .build(&context)
+            .unwrap();
+
+        assert_eq!(shader.workgroup_count(&context, max as usize), [max, 1, 1]);
+        assert_eq!(shader.workgroup_count(&context, max as usize + 1), [max, 2, 1]);
+        assert_eq!(
+            shader.workgroup_count(&context, (max as usize, max as usize, 2)),
+            [max, max, 2]
+        );
+    }
+
+    #[test]
+    fn execute_folded() {
+        let context = WgpuContext::new();
+        let width = context.device.limits().max_compute_workgroups_per_dimension as usize + 10;
+        let shader = Shader::builder("dispatch", SOURCE)
+            .workgroup_size(1, 1, 1)
+            .build(&context)
+            .unwrap();
+        let target = StorageBuffer::<u32>::new(&context, 2 * width);
+
+        shader.execute_with_params(&context, (width, 2), &[&target]).unwrap();
+
+        assert_eq!(
+            ReadbackBuffer::<u32>::new(&context, 2 * width).read(&context, &target, 0, 2 * width),
+            (0..2 * width as u32).map(|i| i % width as u32 + i / width as u32).collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn execute_workgroups() {
+        let context = WgpuContext::new();
+        let shader = Shader::builder("dispatch", SOURCE)
+            .workgroup_size(64, 1, 1)
+            .build(&context)
+            .unwrap();
+        let target = StorageBuffer::<u32>::new(&context, 200);
+
+        shader.execute_with_params(&context, (100, 2), &[&target]).unwrap();
+
+        assert_eq!(
+            ReadbackBuffer::<u32>::new(&context, 200).read(&context, &target, 0, 200),
+            (0..200).map(|i| i % 100 + i / 100).collect::<Vec<_>>()
+        );
+    }
+}
+
+mod cache {
+    use super::*;
+
+    #[test]
+    fn reuses_pipelines() {
+        let context = WgpuContext::new();
+        let first = Shader::new(&context, "first", include_str!("plus_one.wgsl"));
+        let second = Shader::new(&context, "second", include_str!("plus_one.wgsl"));
+
+        assert_eq!(context.cache.len(), 1);
+        assert_eq!(first.pipeline, second.pipeline);
+        assert_eq!(first.bindings(), second.bindings());
+        assert_eq!(second.label(), "second");
+    }
+
+    #[test]
+    fn keyed_by_source_and_constants() {
+        let context = WgpuContext::new();
+        let source = r"
+            override offset: i32 = 0;
+
+            @group(0) @binding(0) var<storage, read_write> values: array<i32>;
+
+            @compute @workgroup_size(1)
+            fn main(@builtin(global_invocation_id) global_id: vec3<u32>) {
+                values[global_id.x] = offset;
+            }
+        ";
+
+        let default = Shader::new(&context, "default", source);
+        let first = Shader::builder("first", source).override_constant("offset", 1.0).build(&context).unwrap();
+        let second = Shader::builder("second", source).override_constant("offset", 1.0).build(&context).unwrap();
+        let other = Shader::new(&context, "other", include_str!("plus_one.wgsl"));
+
+        assert_eq!(context.cache.len(), 3);
+        assert_eq!(first.pipeline, second.pipeline);
+        assert_ne!(default.pipeline, first.pipeline);
+        assert_ne!(default.pipeline, other.pipeline);
+    }
+
+    #[test]
+    fn shared_by_clones() {
+        let context = WgpuContext::new();
+        let clone = context.clone();
+        let _shader = Shader::new(&context, "test", include_str!("plus_one.wgsl"));
+
+        assert_eq!(clone.cache.len(), 1);
+
+        clone.cache.clear();
+        assert!(context.cache.is_empty());
+    }
+
+    #[test]
+    fn compilation_errors_not_cached() {
+        let context = WgpuContext::new();
+
+        assert!(Shader::try_new(&context, "invalid", "fn main() {}").is_err());
+        assert!(context.cache.is_empty());
+    }
+}
+
+mod layout {
+    use super::*;
+
+    const SOURCE: &str = r"
+struct Dispatch {
+    offset: u32,
+    value: u32,
+}
+
+@group(0) @binding(0) var<storage, read_write> target_buffer: array<u32>;
+@group(1) @binding(0) var<storage, read> factor: u32;
+
+@compute @workgroup_size(1)
+fn main(@builtin(global_invocation_id) global_id: vec3<u32>) {
+    target_buffer[dispatch.offset + global_id.x] = dispatch.value * factor;
+}
+
+@compute @workgroup_size(1)
+fn clear(@builtin(global_invocation_id) global_id: vec3<u32>) {
+    target_buffer[global_id.x] = 0u;
+}
+";
+
+    fn shader(context: &WgpuContext, entry_point: &str) -> Shader {
+        Shader::builder("layout", SOURCE)
+            .entry_point(entry_point)
+            .push_constants("dispatch", "Dispatch")
+            .build(context)
+            .unwrap()
+    }
+
+    #[test]
+    fn entry_point() {
+        let context = WgpuContext::new();
+        let main = shader(&context, "main");
+        let clear = shader(&context, "clear");
+        let target = StorageBuffer::<u32>::init(&context, &[7; 4]);
+
+        assert!(main.bindings().iter().any(|binding| binding.group == 1));
+        assert_eq!(clear.bindings().len(), 1);
+        assert_eq!(clear.push_constant_size(), None);
+        assert_ne!(main.pipeline, clear.pipeline);
+
+        clear.execute_with_params(&context, 2, &[&target]).unwrap();
+
+        assert_eq!(ReadbackBuffer::<u32>::new(&context, 4).read(&context, &target, 0, 4), vec![0, 0, 7, 7]);
+    }
+
+    #[test]
+    fn missing_entry_point() {
+        let context = WgpuContext::new();
+
+        assert!(matches!(
+            Shader::builder("layout", SOURCE).entry_point("missing").build(&context),
+            Err(Error::ShaderCompilation { .. })
+        ));
+    }
+
+    #[test]
+    fn bind_groups() {
+        let context = WgpuContext::new();
+        let mut shader = shader(&context, "main");
+        let factor = StorageBuffer::<u32>::init(&context, &[3]);
+        let first = StorageBuffer::<u32>::new(&context, 2);
+        let second = StorageBuffer::<u32>::new(&context, 2);
+        let readback = ReadbackBuffer::<u32>::new(&context, 2);
+
+        assert!(!shader.is_bound());
+        shader.bind_group(&context, 1, &[&factor]).unwrap();
+        shader.set_push_constants(&[0_u32, 2]);
+        shader.execute_with_params(&context, 2, &[&first]).unwrap();
+        shader.execute_with_params(&context, 2, &[&second]).unwrap();
+
+        assert_eq!(readback.read(&context, &first, 0, 2), vec![6, 6]);
+        assert_eq!(readback.read(&context, &second, 0, 2), vec![6, 6]);
+
+        shader.bind(&context, &[&first]).unwrap();
+        assert!(shader.is_bound());
+        shader.unbind();
+        assert!(!shader.is_bound());
+    }
+
+    #[test]
+    fn invalid_group() {
+        let context = WgpuContext::new();
+        let mut shader = shader(&context, "main");
+        let factor = StorageBuffer::<u32>::init(&context, &[3]);
+
+        assert!(matches!(
+            shader.bind_group(&context, 2, &[&factor]),
+            Err(Error::InvalidGroup { group: 2, .. })
+        ));
+        assert!(matches!(
+            shader.bind_group(&context, 1, &[&factor, &factor]),
+            Err(Error::BindingCount { group: 1, expected: 1, actual: 2, .. })
+        ));
+    }
+
+    #[test]
+    fn push_constants() {
+        let context = WgpuContext::new();
+        let mut shader = shader(&context, "main");
+        let factor = StorageBuffer::<u32>::init(&context, &[1]);
+        let target = StorageBuffer::<u32>::new(&context, 6);
+
+        shader.bind(&context, &[&target]).unwrap();
+        shader.bind_group(&context, 1, &[&factor]).unwrap();
+        assert_eq!(shader.push_constant_size(), Some(8));
+
+        // Every dispatch keeps the value set when it was encoded
+        let commands = (0_u32..3)
+            .map(|i| {
+                shader.set_push_constants(&[2 * i, i + 1]);
+                shader.execute_async(&context, 2)
+            })
+            .collect::<Vec<_>>();
+        context.queue.submit(commands);
+
+        assert_eq!(
+            ReadbackBuffer::<u32>::new(&context, 6).read(&context, &target, 0, 6),
+            vec![1, 1, 2, 2, 3, 3]
+        );
+    }
+
+    #[test]
+    fn push_constants_declaration() {
+        let source = Shader::builder("layout", SOURCE)
+            .push_constants("dispatch", "Dispatch")
+            .compose()
+            .unwrap();
+
+        assert!(source.ends_with("@group(3) @binding(0) var<uniform> dispatch: Dispatch;\n"));
+    }
+
+    #[test]
+    #[should_panic(expected = "different size")]
+    fn push_constants_size() {
+        let context = WgpuContext::new();
+        let mut shader = shader(&context, "main");
+
+        shader.set_push_constants(&0_u32);
+    }
+}
 
-fn prepare() -> (WgpuContext, Shader, StorageBuffer, StorageBuffer, ReadbackBuffer) {
+fn prepare() -> (WgpuContext, Shader, StorageBuffer<i32>, StorageBuffer<i32>, ReadbackBuffer<i32>) {
     let context = WgpuContext::new();
-    let source = StorageBuffer::new::<i32, _>(&context, 10);
-    let target = StorageBuffer::new::<i32, _>(&context, 10);
-    let readback = ReadbackBuffer::new::<i32, _>(&context, 10);
+    let source = StorageBuffer::<i32>::new(&context, 10);
+    let target = StorageBuffer::<i32>::new(&context, 10);
+    let readback = ReadbackBuffer::<i32>::new(&context, 10);
 
     let shader = Shader::new(&context, "test", include_str!("plus_one.wgsl"));
     target.update_buffer_range(&context, &[10_i32; 10], 0);
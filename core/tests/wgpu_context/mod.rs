@@ -1,6 +1,70 @@
-use sgrmath_core::WgpuContext;
+use sgrmath_core::{Error, WgpuContext};
 
 #[test]
 fn new() {
     let _context = WgpuContext::new();
 }
+
+#[test]
+fn builder() {
+    let context = WgpuContext::builder()
+        .power_preference(wgpu::PowerPreference::LowPower)
+        .build()
+        .unwrap();
+
+    assert_eq!(context.adapter_info(), context.adapter.get_info());
+}
+
+#[test]
+fn builder_clamps_limits() {
+    let context = WgpuContext::builder()
+        .required_limits(wgpu::Limits {
+            max_buffer_size: u64::MAX,
+            max_storage_buffer_binding_size: u32::MAX,
+            min_storage_buffer_offset_alignment: 1,
+            ..wgpu::Limits::default()
+        })
+        .build()
+        .unwrap();
+    let supported = context.adapter.limits();
+
+    assert_eq!(context.limits().max_buffer_size, supported.max_buffer_size);
+    assert_eq!(context.limits().max_storage_buffer_binding_size, supported.max_storage_buffer_binding_size);
+    assert_eq!(context.limits().min_storage_buffer_offset_alignment, supported.min_storage_buffer_offset_alignment);
+}
+
+#[test]
+fn builder_unsupported_features() {
+    let result = WgpuContext::builder()
+        .required_features(wgpu::Features::all())
+        .build();
+
+    assert!(matches!(result, Err(Error::UnsupportedFeatures { .. })));
+}
+
+#[test]
+fn report() {
+    let context = WgpuContext::new();
+    let report = context.report();
+
+    assert_eq!(report.info.name, context.adapter_info().name);
+    assert_eq!(report.limits, context.limits());
+    assert!(report.to_string().contains(&report.info.name));
+}
+
+#[test]
+fn builder_pipeline_cache_dir() {
+    let directory = std::env::temp_dir().join(format!("sgrmath-pipeline-cache-{}", std::process::id()));
+    let context = WgpuContext::builder()
+        .pipeline_cache_dir(&directory)
+        .build()
+        .unwrap();
+    let supported = context.adapter.features().contains(wgpu::Features::PIPELINE_CACHE)
+        && wgpu::util::pipeline_cache_key(&context.adapter_info()).is_some();
+
+    assert_eq!(context.cache.is_persistent(), supported);
+    assert_eq!(context.device.features().contains(wgpu::Features::PIPELINE_CACHE), supported);
+    context.cache.save().unwrap();
+
+    let _ = std::fs::remove_dir_all(directory);
+}
@@ -1,3 +1,15 @@
+#[cfg(test)]
+mod tests;
 mod random;
+mod rank;
+mod roulette;
+mod scaling;
+mod stochastic_universal;
+mod tournament;
 
-pub use random::*;
\ No newline at end of file
+pub use random::*;
+pub use rank::*;
+pub use roulette::*;
+pub use scaling::Scaling;
+pub use stochastic_universal::*;
+pub use tournament::*;
\ No newline at end of file
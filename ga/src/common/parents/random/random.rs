@@ -1,4 +1,4 @@
-use rand::distr::{Distribution, Uniform};
+use rand::{distr::{Distribution, Uniform}, rngs::StdRng};
 use sgrmath_core::{Compiled, Iteration};
 use bytemuck::Pod;
 
@@ -12,6 +12,7 @@ where
     T: Pod
 {
     params: IterationParams<T>,
+    rng: StdRng,
 }
 
 impl Random {
@@ -34,23 +35,22 @@ where
     T: Pod
 {
     pub fn new(params: &IterationParams<T>) -> Self {
-        Self { params: params.clone() }
+        Self { params: params.clone(), rng: params.context.borrow_mut().stream_rng() }
     }
 
-    pub fn execute(&self, params: &IterationParams<T>) {
+    pub fn execute(&mut self, params: &IterationParams<T>) {
         let (wgpu, min, max, parents_count) = {
             let context = params.context.borrow();
 
-            (context.wgpu.clone(), 0u32, context.options.population_size as u32 - 1, context.options.parents_count)
+            (context.wgpu.clone(), 0u32, context.options.population_size as u32, context.options.parents_count)
         };
-        let mut context = params.context.borrow_mut();
         let data = params.data.borrow();
         
         let uniform = Uniform::new(min, max).unwrap();
         data.parents.update_buffer_range(
             &wgpu, 
             &uniform
-                .sample_iter(&mut context.rng)
+                .sample_iter(&mut self.rng)
                 .take(params.solutions_count * parents_count)
                 .collect::<Vec<u32>>(),
             params.solutions_offset * parents_count,
@@ -67,11 +67,13 @@ where
     }
 
     fn evaluate(&mut self) {
-        self.execute(&self.params);
+        let params = self.params.clone();
+        self.execute(&params);
     }
 
     fn evaluate_async(&mut self) -> Vec<wgpu::CommandBuffer> {
-        self.execute(&self.params);
+        let params = self.params.clone();
+        self.execute(&params);
         vec![]
     }
 
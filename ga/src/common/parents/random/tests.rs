@@ -15,6 +15,13 @@ fn parents() {
     }
 }
 
+#[test]
+fn last_individual() {
+    let picked = (0..10).flat_map(|_| execute(0, 100)).any(|parent| parent == 49);
+
+    assert!(picked, "The last individual is never picked");
+}
+
 #[test]
 fn offset() {
     let data = execute(20, 30);
@@ -36,7 +43,7 @@ fn execute(offset: usize, count: usize) -> Vec<u32> {
     let (wgpu, result_buffer) = { 
         let (context, data) = (params.context.borrow(), params.data.borrow());
         
-        data.parents.update_buffer_range::<u32>(
+        data.parents.update_buffer_range(
             &context.wgpu, 
             &vec![100_000; options.generation_size * options.parents_count], 
             0
@@ -47,7 +54,7 @@ fn execute(offset: usize, count: usize) -> Vec<u32> {
 
     RandomIteration::new(&params).execute(&params);
 
-    let reader = ReadbackBuffer::new::<f32, _>(&wgpu, (options.population_size, options.parents_count));
+    let reader = ReadbackBuffer::new(&wgpu, (options.population_size, options.parents_count));
     reader.read(&wgpu, &result_buffer, 0, options.population_size * options.parents_count)
 }
 
@@ -60,6 +67,7 @@ pub fn options() -> Options {
         vector_length: 10,
         min_value: -1.0,
         max_value: 1.0,
+        ..Options::default()
     }
 }
 
@@ -0,0 +1,5 @@
+#[cfg(test)]
+mod tests;
+mod rank;
+
+pub use rank::{Rank, RankIteration, Ranking};
@@ -0,0 +1,159 @@
+use rand::{distr::{weighted::WeightedIndex, Distribution}, rngs::StdRng};
+use sgrmath_core::{Compiled, Iteration};
+use bytemuck::Pod;
+
+use crate::IterationParams;
+
+/// How the rank of an individual, zero for the best one, turns into its selection weight.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Ranking {
+    /// Weights falling linearly from `pressure` for the best individual to `2 - pressure`
+    /// for the worst one, `pressure` is between one (uniform) and two
+    Linear {
+        /// The expected number of parents of the best individual per individual
+        pressure: f32,
+    },
+    /// Weights `base.powi(rank)`, `base` is between zero and one, smaller bases select harder
+    Exponential {
+        /// The ratio of the weights of two neighbouring ranks
+        base: f32,
+    },
+}
+
+/// Rank-based parent selection.
+///
+/// Individuals are sorted by their results, the optimization direction and the constraint
+/// handling of the options, and every parent is drawn with a probability given by the rank
+/// of the individual only. The magnitude and the sign of the results do not matter.
+#[derive(Clone)]
+pub struct Rank {
+    /// How ranks turn into selection weights
+    pub ranking: Ranking,
+}
+
+/// The iteration of the [`Rank`] parent selection.
+pub struct RankIteration<T>
+where
+    T: Pod
+{
+    params: IterationParams<T>,
+    rng: StdRng,
+    ranking: Ranking,
+}
+
+impl Ranking {
+    /// Returns the weight of a rank
+    ///
+    /// # Arguments
+    /// * `rank` - The rank, zero for the best individual
+    /// * `count` - The number of ranked individuals
+    pub fn weight(self, rank: usize, count: usize) -> f32 {
+        match self {
+            Self::Linear { pressure } if count > 1 => {
+                let position = (count - 1 - rank) as f32 / (count - 1) as f32;
+                2.0 - pressure + 2.0 * (pressure - 1.0) * position
+            }
+            Self::Linear { .. } => 1.0,
+            Self::Exponential { base } => base.powi(rank as i32),
+        }
+    }
+}
+
+impl Rank {
+    pub fn new(ranking: Ranking) -> Self {
+        Self { ranking }
+    }
+
+    /// Creates a linear rank selection
+    ///
+    /// # Panics
+    /// Panics if the pressure is not between one and two.
+    pub fn linear(pressure: f32) -> Self {
+        assert!((1.0..=2.0).contains(&pressure), "Linear rank pressure {pressure} is not between 1 and 2");
+        Self::new(Ranking::Linear { pressure })
+    }
+
+    /// Creates an exponential rank selection
+    ///
+    /// # Panics
+    /// Panics if the base is not between zero (exclusive) and one.
+    pub fn exponential(base: f32) -> Self {
+        assert!(base > 0.0 && base <= 1.0, "Exponential rank base {base} is not in (0, 1]");
+        Self::new(Ranking::Exponential { base })
+    }
+}
+
+impl<T> Compiled<IterationParams<T>, RankIteration<T>> for Rank
+where
+    T: Pod
+{
+    fn compile(&self, params: &IterationParams<T>) -> RankIteration<T> {
+        RankIteration::new(params, self.ranking)
+    }
+}
+
+impl<T> RankIteration<T>
+where
+    T: Pod
+{
+    pub fn new(params: &IterationParams<T>, ranking: Ranking) -> Self {
+        Self { params: params.clone(), rng: params.context.borrow_mut().stream_rng(), ranking }
+    }
+
+    pub fn execute(&mut self, params: &IterationParams<T>) {
+        let context = params.context.borrow();
+        let data = params.data.borrow();
+
+        let (direction, policy) = (&context.options.optimization_direction, &context.options.fitness_policy);
+        let constraints = context.options.constraint_handling();
+        let mut order = (0..data.individuals.len()).collect::<Vec<_>>();
+        order.sort_by(|&a, &b| {
+            let (a, b) = (&data.individuals[a], &data.individuals[b]);
+            constraints.total_cmp(direction, (a.result, a.violation), (b.result, b.violation), policy)
+        });
+
+        let mut weights = vec![0.0; order.len()];
+        for (rank, &index) in order.iter().enumerate() {
+            weights[index] = self.ranking.weight(rank, order.len());
+        }
+
+        let parents_count = context.options.parents_count;
+        let distribution = WeightedIndex::new(&weights).expect("Rank weights are finite and not all zero");
+        let parents = distribution
+            .sample_iter(&mut self.rng)
+            .take(params.solutions_count * parents_count)
+            .map(|index| index as u32)
+            .collect::<Vec<_>>();
+
+        data.parents.update_buffer_range(&context.wgpu, &parents, params.solutions_offset * parents_count);
+    }
+}
+
+impl<T> Iteration<IterationParams<T>> for RankIteration<T>
+where
+    T: Pod
+{
+    fn bind(&mut self, params: &IterationParams<T>) {
+        self.params = params.clone();
+    }
+
+    fn evaluate(&mut self) {
+        let params = self.params.clone();
+        self.execute(&params);
+    }
+
+    fn evaluate_async(&mut self) -> Vec<wgpu::CommandBuffer> {
+        let params = self.params.clone();
+        self.execute(&params);
+        vec![]
+    }
+
+    fn evaluate_with_params(&mut self, params: &IterationParams<T>) {
+        self.execute(params);
+    }
+
+    fn evaluate_with_params_async(&mut self, params: &IterationParams<T>) -> Vec<wgpu::CommandBuffer> {
+        self.execute(params);
+        vec![]
+    }
+}
@@ -0,0 +1,58 @@
+use sgrmath_core::OptimizationDirection;
+use crate::common::parents::{self, Rank};
+
+use super::{RankIteration, Ranking};
+
+const RESULTS: [f32; 5] = [ -2.0, 3.0, 1.0, -5.0, 0.0 ];
+
+#[test]
+fn weights() {
+    let linear = Ranking::Linear { pressure: 1.5 };
+    assert_eq!((0..5).map(|rank| linear.weight(rank, 5)).collect::<Vec<_>>(), vec![ 1.5, 1.25, 1.0, 0.75, 0.5 ]);
+    assert_eq!(linear.weight(0, 1), 1.0);
+
+    let exponential = Ranking::Exponential { base: 0.5 };
+    assert_eq!((0..4).map(|rank| exponential.weight(rank, 4)).collect::<Vec<_>>(), vec![ 1.0, 0.5, 0.25, 0.125 ]);
+}
+
+#[test]
+fn linear() {
+    for (direction, worst) in [ (OptimizationDirection::Minimize, 1), (OptimizationDirection::Maximize, 3) ] {
+        let parents = execute(Ranking::Linear { pressure: 2.0 }, direction, 0, 100);
+
+        assert!(parents.iter().all(|&parent| parent < 5));
+        assert!(parents.iter().all(|&parent| parent != worst), "The worst individual is never selected");
+    }
+}
+
+#[test]
+fn exponential() {
+    let parents = execute(Ranking::Exponential { base: 0.01 }, OptimizationDirection::Minimize, 0, 100);
+    let best = parents.iter().filter(|&&parent| parent == 3).count();
+
+    assert!(best > 150, "The best individual is selected {best} times of 200");
+}
+
+#[test]
+#[should_panic(expected = "not between 1 and 2")]
+fn linear_pressure() {
+    Rank::linear(2.5);
+}
+
+#[test]
+fn offset() {
+    let parents = execute(Ranking::Linear { pressure: 1.5 }, OptimizationDirection::Minimize, 20, 30);
+
+    for (i, &parent) in parents.iter().enumerate() {
+        match (40..100).contains(&i) {
+            true => assert!(parent < 5, "Value at index {} is not an individual", i),
+            false => assert_eq!(parent, 100_000, "Value at index {} is not 100_000 (initial value)", i),
+        }
+    }
+}
+
+fn execute(ranking: Ranking, direction: OptimizationDirection, offset: usize, count: usize) -> Vec<u32> {
+    parents::tests::execute(&RESULTS, direction, offset, count, |params| {
+        RankIteration::new(params, ranking).execute(params);
+    })
+}
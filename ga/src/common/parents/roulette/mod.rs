@@ -0,0 +1,5 @@
+#[cfg(test)]
+mod tests;
+mod roulette;
+
+pub use roulette::{Roulette, RouletteIteration};
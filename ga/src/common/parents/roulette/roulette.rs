@@ -0,0 +1,95 @@
+use rand::{distr::{weighted::WeightedIndex, Distribution}, rngs::StdRng};
+use sgrmath_core::{Compiled, Iteration};
+use bytemuck::Pod;
+
+use crate::{common::parents::Scaling, IterationParams};
+
+/// Roulette-wheel parent selection.
+///
+/// Every parent is drawn independently with a probability proportional to the weight
+/// its result gets from the [`Scaling`].
+#[derive(Clone)]
+pub struct Roulette {
+    /// How results are turned into selection weights
+    pub scaling: Scaling,
+}
+
+/// The iteration of the [`Roulette`] parent selection.
+pub struct RouletteIteration<T>
+where
+    T: Pod
+{
+    params: IterationParams<T>,
+    rng: StdRng,
+    scaling: Scaling,
+}
+
+impl Roulette {
+    pub fn new(scaling: Scaling) -> Self {
+        Self { scaling }
+    }
+}
+
+impl<T> Compiled<IterationParams<T>, RouletteIteration<T>> for Roulette
+where
+    T: Pod
+{
+    fn compile(&self, params: &IterationParams<T>) -> RouletteIteration<T> {
+        RouletteIteration::new(params, self.scaling)
+    }
+}
+
+impl<T> RouletteIteration<T>
+where
+    T: Pod
+{
+    pub fn new(params: &IterationParams<T>, scaling: Scaling) -> Self {
+        Self { params: params.clone(), rng: params.context.borrow_mut().stream_rng(), scaling }
+    }
+
+    pub fn execute(&mut self, params: &IterationParams<T>) {
+        let context = params.context.borrow();
+        let data = params.data.borrow();
+
+        let parents_count = context.options.parents_count;
+        let weights = self.scaling.weights(&data.individuals, &context.options);
+        let distribution = WeightedIndex::new(&weights).expect("Roulette weights are finite and not all zero");
+
+        let parents = distribution
+            .sample_iter(&mut self.rng)
+            .take(params.solutions_count * parents_count)
+            .map(|index| index as u32)
+            .collect::<Vec<_>>();
+
+        data.parents.update_buffer_range(&context.wgpu, &parents, params.solutions_offset * parents_count);
+    }
+}
+
+impl<T> Iteration<IterationParams<T>> for RouletteIteration<T>
+where
+    T: Pod
+{
+    fn bind(&mut self, params: &IterationParams<T>) {
+        self.params = params.clone();
+    }
+
+    fn evaluate(&mut self) {
+        let params = self.params.clone();
+        self.execute(&params);
+    }
+
+    fn evaluate_async(&mut self) -> Vec<wgpu::CommandBuffer> {
+        let params = self.params.clone();
+        self.execute(&params);
+        vec![]
+    }
+
+    fn evaluate_with_params(&mut self, params: &IterationParams<T>) {
+        self.execute(params);
+    }
+
+    fn evaluate_with_params_async(&mut self, params: &IterationParams<T>) -> Vec<wgpu::CommandBuffer> {
+        self.execute(params);
+        vec![]
+    }
+}
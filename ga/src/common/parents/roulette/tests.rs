@@ -0,0 +1,85 @@
+use sgrmath_core::{ConstraintHandling, FitnessPolicy, NanPolicy, OptimizationDirection};
+use crate::{common::parents::{self, tests::{individuals, options}, Scaling}, Options};
+
+use super::RouletteIteration;
+
+const RESULTS: [f32; 5] = [ -2.0, 3.0, 1.0, -5.0, 0.0 ];
+
+#[test]
+fn windowing() {
+    let options = options(OptimizationDirection::Maximize, 5);
+    assert_eq!(Scaling::Windowing.weights(&individuals(&RESULTS), &options), vec![ 3.0, 8.0, 6.0, 0.0, 5.0 ]);
+
+    let options = self::options(OptimizationDirection::Minimize, 5);
+    assert_eq!(Scaling::Windowing.weights(&individuals(&RESULTS), &options), vec![ 5.0, 0.0, 2.0, 8.0, 3.0 ]);
+}
+
+#[test]
+fn sigma() {
+    let options = options(OptimizationDirection::Maximize, 5);
+    let weights = Scaling::Sigma(1.0).weights(&individuals(&[ 1.0, 3.0, 5.0, 7.0 ]), &options);
+
+    let deviation = 5.0_f32.sqrt();
+    for (weight, expected) in weights.into_iter().zip([ 0.0, deviation - 1.0, 1.0 + deviation, 3.0 + deviation ]) {
+        assert!((weight - expected).abs() < 1e-5, "{weight} != {expected}");
+    }
+}
+
+#[test]
+fn scaling_edge_cases() {
+    let options = options(OptimizationDirection::Minimize, 5);
+    assert_eq!(Scaling::Windowing.weights(&individuals(&[ 2.0, 2.0 ]), &options), vec![ 1.0, 1.0 ]);
+    assert_eq!(Scaling::Windowing.weights(&individuals(&[ 2.0, f32::NAN, 1.0 ]), &options), vec![ 0.0, 0.0, 1.0 ]);
+
+    let options = Options { constraints: Some(ConstraintHandling::Feasibility), ..options };
+    let mut population = individuals(&[ 3.0, 0.0, 2.0, 1.0 ]);
+    population[1].violation = 1.0;
+    assert_eq!(Scaling::Windowing.weights(&population, &options), vec![ 0.0, 0.0, 1.0, 2.0 ]);
+}
+
+#[test]
+fn fallback_to_accepted() {
+    let options = Options {
+        fitness_policy: FitnessPolicy { nan: NanPolicy::Error, ..FitnessPolicy::default() },
+        ..options(OptimizationDirection::Minimize, 3)
+    };
+    assert_eq!(Scaling::Windowing.weights(&individuals(&[ 2.0, f32::NAN, 2.0 ]), &options), vec![ 1.0, 0.0, 1.0 ]);
+}
+
+#[test]
+fn overflowing_weights() {
+    let options = options(OptimizationDirection::Maximize, 3);
+    let weights = Scaling::Windowing.weights(&individuals(&[ f32::MAX, -f32::MAX, f32::MAX ]), &options);
+
+    assert_eq!(weights, vec![ 1.0, 0.0, 1.0 ]);
+    assert!(Scaling::Sigma(1.0).weights(&individuals(&[ f32::MAX, -f32::MAX, 0.0 ]), &options).iter().sum::<f32>().is_finite());
+}
+
+#[test]
+fn parents() {
+    for direction in [ OptimizationDirection::Minimize, OptimizationDirection::Maximize ] {
+        let never = if direction.is_minimize() { 1 } else { 3 };
+        let parents = execute(direction, 0, 100);
+
+        assert!(parents.iter().all(|&parent| parent < 5));
+        assert!(parents.iter().all(|&parent| parent != never), "The worst individual is never selected");
+    }
+}
+
+#[test]
+fn offset() {
+    let parents = execute(OptimizationDirection::Minimize, 20, 30);
+
+    for (i, &parent) in parents.iter().enumerate() {
+        match (40..100).contains(&i) {
+            true => assert!(parent < 5, "Value at index {} is not an individual", i),
+            false => assert_eq!(parent, 100_000, "Value at index {} is not 100_000 (initial value)", i),
+        }
+    }
+}
+
+fn execute(direction: OptimizationDirection, offset: usize, count: usize) -> Vec<u32> {
+    parents::tests::execute(&RESULTS, direction, offset, count, |params| {
+        RouletteIteration::new(params, Scaling::Windowing).execute(params);
+    })
+}
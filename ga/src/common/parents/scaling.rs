@@ -0,0 +1,90 @@
+use sgrmath_core::ConstraintHandling;
+
+use crate::{Individual, Options};
+
+/// How results are turned into non-negative selection weights.
+///
+/// The weights grow with the quality of an individual in both optimization directions, so
+/// negative and minimized results can be used for fitness-proportionate selection. NaN and
+/// infinite results, and infeasible individuals under [`ConstraintHandling::Feasibility`]
+/// when feasible ones exist, get a weight of zero. Penalties are applied to the results first.
+/// If no individual has a positive weight, the individuals whose results the fitness policy
+/// accepts get a weight of one, skipping infeasible ones when feasible ones exist, or every
+/// individual if there are none. The weights are scaled down if their sum overflows `f32`.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum Scaling {
+    /// Subtracts the worst result, so the worst individual is never selected
+    #[default]
+    Windowing,
+    /// Subtracts the mean result minus `c` standard deviations, individuals below get zero
+    Sigma(f32),
+}
+
+impl Scaling {
+    /// Computes the selection weights of the individuals
+    ///
+    /// # Arguments
+    /// * `individuals` - The individuals of the population
+    /// * `options` - The options with the optimization direction, the fitness policy and the constraint handling
+    ///
+    /// # Returns
+    /// The weight of each individual, in the order of `individuals`
+    pub fn weights(self, individuals: &[Individual], options: &Options) -> Vec<f32> {
+        let (direction, constraints) = (&options.optimization_direction, options.constraint_handling());
+        let any_feasible = individuals.iter().any(|individual| ConstraintHandling::is_feasible(individual.violation));
+
+        let excluded = |individual: &Individual| {
+            constraints == ConstraintHandling::Feasibility
+                && any_feasible
+                && !ConstraintHandling::is_feasible(individual.violation)
+        };
+
+        // Computed in f64, so the differences and squares of large results do not overflow
+        let quality = individuals
+            .iter()
+            .map(|individual| {
+                let value = constraints.penalized(direction, individual.result, individual.violation);
+                match value.is_finite() && !excluded(individual) {
+                    true if direction.is_minimize() => Some(-f64::from(value)),
+                    true => Some(f64::from(value)),
+                    false => None,
+                }
+            })
+            .collect::<Vec<_>>();
+
+        let values = quality.iter().flatten().copied().collect::<Vec<_>>();
+        let offset = match self {
+            Self::Windowing => values.iter().copied().reduce(f64::min).unwrap_or_default(),
+            Self::Sigma(c) => {
+                let count = values.len().max(1) as f64;
+                let mean = values.iter().sum::<f64>() / count;
+                let deviation = (values.iter().map(|value| (value - mean).powi(2)).sum::<f64>() / count).sqrt();
+                mean - f64::from(c) * deviation
+            }
+        };
+
+        let weights = quality
+            .into_iter()
+            .map(|value| value.map_or(0.0, |value| (value - offset).max(0.0)))
+            .collect::<Vec<_>>();
+
+        if weights.iter().any(|&weight| weight > 0.0) {
+            // Selections sum the weights in f32, which must not overflow
+            let sum = weights.iter().sum::<f64>();
+            let scale = match (sum as f32).is_finite() {
+                true => 1.0,
+                false => weights.iter().copied().fold(0.0, f64::max),
+            };
+            return weights.into_iter().map(|weight| (weight / scale) as f32).collect();
+        }
+
+        let accepted = individuals
+            .iter()
+            .map(|individual| !excluded(individual) && options.fitness_policy.check(individual.result).is_ok())
+            .collect::<Vec<_>>();
+        match accepted.contains(&true) {
+            true => accepted.into_iter().map(|accepted| if accepted { 1.0 } else { 0.0 }).collect(),
+            false => vec![1.0; individuals.len()],
+        }
+    }
+}
@@ -0,0 +1,5 @@
+#[cfg(test)]
+mod tests;
+mod stochastic_universal;
+
+pub use stochastic_universal::{StochasticUniversal, StochasticUniversalIteration};
@@ -0,0 +1,110 @@
+use rand::{rngs::StdRng, seq::SliceRandom, Rng};
+use sgrmath_core::{Compiled, Iteration};
+use bytemuck::Pod;
+
+use crate::{common::parents::Scaling, IterationParams};
+
+/// Stochastic universal sampling parent selection.
+///
+/// All parents of a call are drawn with a single spin of a wheel with equally spaced
+/// pointers, so every individual gets a number of parents within one of its expected
+/// share. The parents are shuffled before they are paired.
+#[derive(Clone)]
+pub struct StochasticUniversal {
+    /// How results are turned into selection weights
+    pub scaling: Scaling,
+}
+
+/// The iteration of the [`StochasticUniversal`] parent selection.
+pub struct StochasticUniversalIteration<T>
+where
+    T: Pod
+{
+    params: IterationParams<T>,
+    rng: StdRng,
+    scaling: Scaling,
+}
+
+impl StochasticUniversal {
+    pub fn new(scaling: Scaling) -> Self {
+        Self { scaling }
+    }
+}
+
+impl<T> Compiled<IterationParams<T>, StochasticUniversalIteration<T>> for StochasticUniversal
+where
+    T: Pod
+{
+    fn compile(&self, params: &IterationParams<T>) -> StochasticUniversalIteration<T> {
+        StochasticUniversalIteration::new(params, self.scaling)
+    }
+}
+
+impl<T> StochasticUniversalIteration<T>
+where
+    T: Pod
+{
+    pub fn new(params: &IterationParams<T>, scaling: Scaling) -> Self {
+        Self { params: params.clone(), rng: params.context.borrow_mut().stream_rng(), scaling }
+    }
+
+    pub fn execute(&mut self, params: &IterationParams<T>) {
+        let context = params.context.borrow();
+        let data = params.data.borrow();
+
+        let parents_count = context.options.parents_count;
+        let weights = self.scaling.weights(&data.individuals, &context.options);
+        let count = params.solutions_count * parents_count;
+        if count == 0 {
+            return;
+        }
+        let step = weights.iter().sum::<f32>() / count as f32;
+
+        let mut pointer = self.rng.random_range(0.0..step);
+        let mut parents = Vec::with_capacity(count);
+        let mut cumulative = 0.0;
+        for (index, weight) in weights.iter().enumerate() {
+            cumulative += weight;
+            while pointer < cumulative && parents.len() < count {
+                parents.push(index as u32);
+                pointer += step;
+            }
+        }
+
+        // Rounding can leave the last pointers past the accumulated weight
+        let last = weights.iter().rposition(|&weight| weight > 0.0).unwrap_or_default() as u32;
+        parents.resize(count, last);
+        parents.shuffle(&mut self.rng);
+
+        data.parents.update_buffer_range(&context.wgpu, &parents, params.solutions_offset * parents_count);
+    }
+}
+
+impl<T> Iteration<IterationParams<T>> for StochasticUniversalIteration<T>
+where
+    T: Pod
+{
+    fn bind(&mut self, params: &IterationParams<T>) {
+        self.params = params.clone();
+    }
+
+    fn evaluate(&mut self) {
+        let params = self.params.clone();
+        self.execute(&params);
+    }
+
+    fn evaluate_async(&mut self) -> Vec<wgpu::CommandBuffer> {
+        let params = self.params.clone();
+        self.execute(&params);
+        vec![]
+    }
+
+    fn evaluate_with_params(&mut self, params: &IterationParams<T>) {
+        self.execute(params);
+    }
+
+    fn evaluate_with_params_async(&mut self, params: &IterationParams<T>) -> Vec<wgpu::CommandBuffer> {
+        self.execute(params);
+        vec![]
+    }
+}
@@ -0,0 +1,40 @@
+use sgrmath_core::OptimizationDirection;
+use crate::common::parents::{self, Scaling};
+
+use super::StochasticUniversalIteration;
+
+const RESULTS: [f32; 5] = [ -2.0, 3.0, 1.0, -5.0, 0.0 ];
+
+#[test]
+fn parents() {
+    for (direction, weights) in [
+        (OptimizationDirection::Maximize, [ 3.0, 8.0, 6.0, 0.0, 5.0 ]),
+        (OptimizationDirection::Minimize, [ 5.0, 0.0, 2.0, 8.0, 3.0 ]),
+    ] {
+        let parents = execute(direction, 0, 100);
+
+        for (index, weight) in weights.into_iter().enumerate() {
+            let expected = 200.0 * weight / weights.iter().sum::<f32>();
+            let count = parents.iter().filter(|&&parent| parent == index as u32).count() as f32;
+            assert!(count >= expected.floor() && count <= expected.ceil(), "{count} parents of {index}, expected {expected}");
+        }
+    }
+}
+
+#[test]
+fn offset() {
+    let parents = execute(OptimizationDirection::Minimize, 20, 30);
+
+    for (i, &parent) in parents.iter().enumerate() {
+        match (40..100).contains(&i) {
+            true => assert!(parent < 5, "Value at index {} is not an individual", i),
+            false => assert_eq!(parent, 100_000, "Value at index {} is not 100_000 (initial value)", i),
+        }
+    }
+}
+
+fn execute(direction: OptimizationDirection, offset: usize, count: usize) -> Vec<u32> {
+    parents::tests::execute(&RESULTS, direction, offset, count, |params| {
+        StochasticUniversalIteration::new(params, Scaling::Windowing).execute(params);
+    })
+}
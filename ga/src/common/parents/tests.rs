@@ -0,0 +1,67 @@
+use std::{cell::RefCell, rc::Rc};
+
+use sgrmath_core::{OptimizationDirection, ReadbackBuffer, WgpuContext};
+use crate::{Context, Data, Individual, IterationParams, Options};
+
+/// Fills the parents buffer with 100_000, selects the parents and reads them back
+///
+/// The population holds one individual per result, the generation 100 children of 2 parents,
+/// and the selection runs on `count` children from `offset`.
+pub fn execute<F>(results: &[f32], direction: OptimizationDirection, offset: usize, count: usize, select: F) -> Vec<u32>
+where
+    F: FnOnce(&IterationParams<f32>),
+{
+    let options = options(direction, results.len());
+    let params = params(&options, results, offset, count);
+
+    let (wgpu, result_buffer) = {
+        let (context, data) = (params.context.borrow(), params.data.borrow());
+
+        data.parents.update_buffer_range(
+            &context.wgpu,
+            &vec![100_000; options.generation_size * options.parents_count],
+            0
+        );
+
+        (context.wgpu.clone(), data.parents.clone())
+    };
+
+    select(&params);
+
+    let reader = ReadbackBuffer::new(&wgpu, (options.generation_size, options.parents_count));
+    reader.read(&wgpu, &result_buffer, 0, options.generation_size * options.parents_count)
+}
+
+/// Returns one single-objective individual per result
+pub fn individuals(results: &[f32]) -> Vec<Individual> {
+    results
+        .iter()
+        .enumerate()
+        .map(|(index, &result)| Individual::new(index, 0, vec![], vec![ result ]))
+        .collect()
+}
+
+/// Returns the options of a generation of 100 children of 2 parents
+pub fn options(optimization_direction: OptimizationDirection, population_size: usize) -> Options {
+    Options {
+        optimization_direction,
+        population_size,
+        generation_size: 100,
+        parents_count: 2,
+        vector_length: 10,
+        ..Options::default()
+    }
+}
+
+fn params(options: &Options, results: &[f32], offset: usize, count: usize) -> IterationParams<f32> {
+    let wgpu = WgpuContext::new();
+    let mut data = Data::new(&wgpu, options);
+    data.individuals = individuals(results);
+
+    IterationParams {
+        context: Rc::new(RefCell::new(Context::new(&wgpu, options))),
+        data: Rc::new(RefCell::new(data)),
+        solutions_count: count,
+        solutions_offset: offset,
+    }
+}
@@ -0,0 +1,5 @@
+#[cfg(test)]
+mod tests;
+mod tournament;
+
+pub use tournament::{Tournament, TournamentIteration};
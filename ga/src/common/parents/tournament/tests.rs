@@ -0,0 +1,66 @@
+use std::collections::HashSet;
+
+use sgrmath_core::OptimizationDirection;
+use crate::common::parents;
+
+use super::TournamentIteration;
+
+const RESULTS: [f32; 10] = [ 5.0, 3.0, 9.0, 4.0, 6.0, 2.0, 8.0, 1.0, 7.0, 3.5 ];
+
+#[test]
+fn whole_population() {
+    let parents = execute(OptimizationDirection::Minimize, 10, false, 0, 100);
+    assert!(parents.iter().all(|&parent| parent == 7), "The best individual wins every tournament");
+
+    let parents = execute(OptimizationDirection::Maximize, 10, false, 0, 100);
+    assert!(parents.iter().all(|&parent| parent == 2), "The best individual wins every tournament");
+}
+
+#[test]
+fn with_replacement() {
+    let parents = execute(OptimizationDirection::Minimize, 1, true, 0, 100);
+
+    assert!(parents.iter().all(|&parent| parent < 10));
+    assert_eq!(parents.iter().copied().collect::<HashSet<_>>().len(), 10, "Every individual can win");
+}
+
+#[test]
+fn pressure() {
+    let parents = execute(OptimizationDirection::Minimize, 9, false, 0, 100);
+
+    // Without replacement the worst individual never wins a tournament of nine
+    assert!(parents.iter().all(|&parent| parent != 2));
+    assert!(parents.iter().all(|&parent| parent < 10));
+}
+
+#[test]
+fn offset() {
+    let parents = execute(OptimizationDirection::Minimize, 10, false, 20, 30);
+
+    for (i, &parent) in parents.iter().enumerate() {
+        match (40..100).contains(&i) {
+            true => assert_eq!(parent, 7, "Value at index {} is not the best individual", i),
+            false => assert_eq!(parent, 100_000, "Value at index {} is not 100_000 (initial value)", i),
+        }
+    }
+}
+
+#[test]
+#[should_panic(expected = "larger than the population")]
+fn too_large() {
+    parents::tests::execute(&RESULTS, OptimizationDirection::Minimize, 0, 100, |params| {
+        TournamentIteration::new(params, 11, false);
+    });
+}
+
+fn execute(
+    direction: OptimizationDirection,
+    size: usize,
+    with_replacement: bool,
+    offset: usize,
+    count: usize
+) -> Vec<u32> {
+    parents::tests::execute(&RESULTS, direction, offset, count, |params| {
+        TournamentIteration::new(params, size, with_replacement).execute(params);
+    })
+}
@@ -0,0 +1,127 @@
+use std::cmp::Ordering;
+
+use rand::{distr::{Distribution, Uniform}, rngs::StdRng, seq::index::sample};
+use sgrmath_core::{Compiled, Iteration};
+use bytemuck::Pod;
+
+use crate::IterationParams;
+
+/// Tournament parent selection.
+///
+/// Every parent is the best of `size` individuals drawn uniformly from the population,
+/// ranked by their results, the optimization direction and the constraint handling of the
+/// options. Larger tournaments put a higher selection pressure on the population.
+#[derive(Clone)]
+pub struct Tournament {
+    /// The number of individuals competing for each parent
+    pub size: usize,
+    /// Whether an individual can be drawn more than once into the same tournament
+    pub with_replacement: bool,
+}
+
+/// The iteration of the [`Tournament`] parent selection.
+pub struct TournamentIteration<T>
+where
+    T: Pod
+{
+    params: IterationParams<T>,
+    rng: StdRng,
+    size: usize,
+    with_replacement: bool,
+}
+
+impl Tournament {
+    pub fn new(size: usize, with_replacement: bool) -> Self {
+        Self { size, with_replacement }
+    }
+}
+
+impl<T> Compiled<IterationParams<T>, TournamentIteration<T>> for Tournament
+where
+    T: Pod
+{
+    fn compile(&self, params: &IterationParams<T>) -> TournamentIteration<T> {
+        TournamentIteration::new(params, self.size, self.with_replacement)
+    }
+}
+
+impl<T> TournamentIteration<T>
+where
+    T: Pod
+{
+    /// Creates the iteration
+    ///
+    /// # Panics
+    /// Panics if the size is zero, or larger than the population without replacement.
+    pub fn new(params: &IterationParams<T>, size: usize, with_replacement: bool) -> Self {
+        let population_size = params.context.borrow().options.population_size;
+        assert!(size > 0, "Tournament size must be positive");
+        assert!(
+            with_replacement || size <= population_size,
+            "Tournament size {size} is larger than the population {population_size}"
+        );
+
+        Self { params: params.clone(), rng: params.context.borrow_mut().stream_rng(), size, with_replacement }
+    }
+
+    pub fn execute(&mut self, params: &IterationParams<T>) {
+        let context = params.context.borrow();
+        let data = params.data.borrow();
+
+        let (direction, policy) = (context.options.optimization_direction.clone(), context.options.fitness_policy);
+        let constraints = context.options.constraint_handling();
+        let (population_size, parents_count) = (data.individuals.len(), context.options.parents_count);
+        let uniform = Uniform::new(0, population_size).expect("Tournament needs a non-empty population");
+
+        let mut parents = Vec::with_capacity(params.solutions_count * parents_count);
+        for _ in 0..(params.solutions_count * parents_count) {
+            let competitors = match self.with_replacement {
+                true => uniform.sample_iter(&mut self.rng).take(self.size).collect::<Vec<_>>(),
+                false => sample(&mut self.rng, population_size, self.size).into_vec(),
+            };
+            let winner = competitors
+                .into_iter()
+                .reduce(|best, index| {
+                    let (a, b) = (&data.individuals[index], &data.individuals[best]);
+                    match constraints.total_cmp(&direction, (a.result, a.violation), (b.result, b.violation), &policy) {
+                        Ordering::Less => index,
+                        _ => best,
+                    }
+                })
+                .expect("Tournament size is positive");
+
+            parents.push(winner as u32);
+        }
+
+        data.parents.update_buffer_range(&context.wgpu, &parents, params.solutions_offset * parents_count);
+    }
+}
+
+impl<T> Iteration<IterationParams<T>> for TournamentIteration<T>
+where
+    T: Pod
+{
+    fn bind(&mut self, params: &IterationParams<T>) {
+        self.params = params.clone();
+    }
+
+    fn evaluate(&mut self) {
+        let params = self.params.clone();
+        self.execute(&params);
+    }
+
+    fn evaluate_async(&mut self) -> Vec<wgpu::CommandBuffer> {
+        let params = self.params.clone();
+        self.execute(&params);
+        vec![]
+    }
+
+    fn evaluate_with_params(&mut self, params: &IterationParams<T>) {
+        self.execute(params);
+    }
+
+    fn evaluate_with_params_async(&mut self, params: &IterationParams<T>) -> Vec<wgpu::CommandBuffer> {
+        self.execute(params);
+        vec![]
+    }
+}
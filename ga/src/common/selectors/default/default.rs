@@ -1,4 +1,4 @@
-use std::collections::HashSet;
+use std::collections::BTreeSet;
 
 use sgrmath_core::{Compiled, Iteration};
 use bytemuck::Pod;
@@ -42,26 +42,29 @@ where
         let mut context = params.context.borrow_mut();
         let mut data = params.data.borrow_mut();
 
-        let next = data.read_generation(&mut context);
+        let next = std::mem::take(&mut data.generation);
+        let (direction, policy) = (&context.options.optimization_direction, &context.options.fitness_policy);
+        let constraints = context.options.constraint_handling();
+
         let (population_size, generation_size) = (context.options.population_size as usize, context.options.generation_size as usize);
 
         let mut order = (0..(population_size + generation_size)).collect::<Vec<_>>();
         order.sort_by(|&a, &b| {
-            let a_value = if a < population_size { 
-                data.individuals[a].result
+            let a = if a < population_size { 
+                &data.individuals[a]
             } else { 
-                next[a - population_size].result
+                &next[a - population_size]
             };
-            let b_value = if b < population_size { 
-                data.individuals[b].result
+            let b = if b < population_size { 
+                &data.individuals[b]
             } else { 
-                next[b - context.options.population_size].result
+                &next[b - population_size]
             };
 
-            context.options.optimization_direction.compare(&a_value, &b_value)
+            constraints.total_cmp(direction, (a.result, a.violation), (b.result, b.violation), policy)
         });
 
-        let mut deleted = (0..context.options.population_size).collect::<HashSet<_>>();
+        let mut deleted = (0..context.options.population_size).collect::<BTreeSet<_>>();
         let mut new = vec![];
         for index in order.iter().take(context.options.population_size) {
             match index < &population_size {
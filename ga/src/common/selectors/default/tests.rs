@@ -1,6 +1,6 @@
 use std::{cell::RefCell, collections::HashSet, rc::Rc};
 
-use sgrmath_core::{OptimizationDirection, WgpuContext};
+use sgrmath_core::{ConstraintHandling, OptimizationDirection, WgpuContext};
 use crate::{Context, Data, Individual, IterationParams, Options};
 
 use super::DefaultIteration;
@@ -29,6 +29,72 @@ fn select() {
     );
 }
 
+#[test]
+fn select_nan() {
+    assert_eq!(
+        execute(
+            OptimizationDirection::Minimize,
+            0, 
+            5, 
+            vec![ 0.0, 1.0, f32::NAN, 3.0, 4.0 ],
+            vec![ f32::NAN, 1.5, f32::NAN, 3.5, 4.5, 5.5 ]
+        ),
+        HashSet::from_iter(vec![ 0, 1, 3, 6, 8 ].into_iter())
+    );
+}
+
+#[test]
+fn select_constrained() {
+    let population = vec![ (0.0, 1.0), (1.0, 0.0), (2.0, 0.0), (3.0, 0.5), (4.0, 0.0) ];
+    let next = vec![ (0.5, 0.0), (1.5, 2.0), (2.5, 0.0), (3.5, 0.0), (4.5, 0.0), (5.5, 0.0) ];
+
+    assert_eq!(
+        execute_constrained(ConstraintHandling::Feasibility, population.clone(), next.clone()),
+        HashSet::from_iter(vec![ 1, 2, 5, 7, 8 ].into_iter())
+    );
+    assert_eq!(
+        execute_constrained(ConstraintHandling::Penalty(2.0), population, next),
+        HashSet::from_iter(vec![ 0, 1, 2, 5, 7 ].into_iter())
+    );
+}
+
+fn execute_constrained(
+    constraints: ConstraintHandling,
+    population: Vec<(f32, f32)>,
+    next: Vec<(f32, f32)>
+) -> HashSet<usize> {
+    let options = Options { constraints: Some(constraints), ..options(OptimizationDirection::Minimize) };
+    let params = params(&options, 0, 5);
+
+    {
+        let mut context = params.context.borrow_mut();
+        let mut data = params.data.borrow_mut();
+
+        data.results.update_buffer_range(&context.wgpu, &next.iter().map(|x| x.0).collect::<Vec<_>>(), 0);
+        data.violations
+            .as_ref()
+            .expect("Constrained data has a violations buffer")
+            .update_buffer_range(&context.wgpu, &next.iter().map(|x| x.1).collect::<Vec<_>>(), 0);
+        data.individuals = population
+            .into_iter()
+            .enumerate()
+            .map(|(index, (result, violation))| Individual::new(index, 0, vec![], vec![ result ]).with_violation(violation))
+            .collect();
+        data.generation = data.read_generation(&mut context).unwrap();
+    }
+
+    DefaultIteration::new(&params).execute(&params);
+
+    let result = params.data
+        .borrow()
+        .individuals
+        .iter()
+        .map(|individual| individual.id)
+        .collect();
+
+    result
+}
+
 fn execute(
     direction: OptimizationDirection,
     offset: usize, 
@@ -40,10 +106,10 @@ fn execute(
     let params = params(&options, offset, count);
 
     {
-        let context = params.context.borrow();
+        let mut context = params.context.borrow_mut();
         let mut data = params.data.borrow_mut();
         
-        data.results.update_buffer_range::<f32>(&context.wgpu, &next_results, 0);
+        data.results.update_buffer_range(&context.wgpu, &next_results, 0);
         data.individuals = population_results
             .into_iter()
             .enumerate()
@@ -52,8 +118,11 @@ fn execute(
                 generation: 0,
                 parents: vec![],
                 result: value,
+                objectives: vec![ value ],
+                violation: 0.0,
             })
             .collect();
+        data.generation = data.read_generation(&mut context).unwrap();
 
         (context.wgpu.clone(), data.population.clone())
     };
@@ -79,6 +148,7 @@ pub fn options(optimization_direction: OptimizationDirection) -> Options {
         vector_length: 5,
         min_value: -1.0,
         max_value: 1.0,
+        ..Options::default()
     }
 }
 
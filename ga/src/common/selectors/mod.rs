@@ -1,3 +1,5 @@
 mod default;
+mod nsga2;
 
-pub use default::{Default, DefaultIteration};
\ No newline at end of file
+pub use default::{Default, DefaultIteration};
+pub use nsga2::{Nsga2, Nsga2Iteration};
\ No newline at end of file
@@ -0,0 +1,5 @@
+#[cfg(test)]
+mod tests;
+mod nsga2;
+
+pub use nsga2::{Nsga2, Nsga2Iteration};
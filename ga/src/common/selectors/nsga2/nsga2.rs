@@ -0,0 +1,132 @@
+use std::collections::BTreeSet;
+
+use sgrmath_core::{Compiled, Iteration};
+use bytemuck::Pod;
+
+use crate::{constrained_sort, crowding_distance, IterationParams};
+
+/// NSGA-II survival selection for multi-objective problems.
+///
+/// Population and offspring are sorted into fronts of non-dominated individuals, the
+/// fronts survive in order and the last front that does not fit is cut by crowding
+/// distance, preferring individuals in sparse regions. Constraint violations are ranked
+/// as by [`constrained_sort`](crate::constrained_sort). With a single objective it keeps
+/// the same individuals as [`Default`](super::Default), up to ties. The offspring are taken
+/// from [`Data::generation`](crate::Data::generation).
+#[derive(Clone)]
+pub struct Nsga2;
+
+/// The iteration of the [`Nsga2`] selector.
+pub struct Nsga2Iteration<T>
+where
+    T: Pod
+{
+    params: IterationParams<T>,
+}
+
+impl Nsga2 {
+    pub fn new() -> Self {
+        Self { }
+    }
+}
+
+impl<T> Compiled<IterationParams<T>, Nsga2Iteration<T>> for Nsga2
+where
+    T: Pod
+{
+    fn compile(&self, params: &IterationParams<T>) -> Nsga2Iteration<T> {
+        Nsga2Iteration::new(params)
+    }
+}
+
+impl<T> Nsga2Iteration<T>
+where
+    T: Pod
+{
+    pub fn new(params: &IterationParams<T>) -> Self {
+        Self { params: params.clone() }
+    }
+
+    pub fn execute(&self, params: &IterationParams<T>) {
+        let mut context = params.context.borrow_mut();
+        let mut data = params.data.borrow_mut();
+
+        let next = std::mem::take(&mut data.generation);
+        let policy = context.options.fitness_policy;
+
+        let population_size = context.options.population_size;
+        let directions = context.options.directions().cloned().collect::<Vec<_>>();
+        let objectives = data.individuals
+            .iter()
+            .chain(next.iter())
+            .map(|individual| individual.objectives.clone())
+            .collect::<Vec<_>>();
+        let violations = data.individuals
+            .iter()
+            .chain(next.iter())
+            .map(|individual| individual.violation)
+            .collect::<Vec<_>>();
+        let fronts = constrained_sort(&objectives, &violations, &directions, &policy, context.options.constraint_handling());
+
+        let mut survivors = Vec::with_capacity(population_size);
+        for front in fronts {
+            let free = population_size - survivors.len();
+            if front.len() <= free {
+                survivors.extend(front);
+                continue;
+            }
+
+            // Population individuals come first in the front, so they win ties and are not copied
+            let distances = crowding_distance(&front, &objectives, &directions, &policy);
+            let mut order = (0..front.len()).collect::<Vec<_>>();
+            order.sort_by(|&a, &b| distances[b].total_cmp(&distances[a]));
+            survivors.extend(order.into_iter().take(free).map(|index| front[index]));
+            break;
+        }
+
+        let mut deleted = (0..population_size).collect::<BTreeSet<_>>();
+        let mut new = vec![];
+        for index in survivors {
+            match index < population_size {
+                true => { deleted.remove(&index); },
+                false => { new.push(index - population_size); }
+            }
+        }
+
+        data.update_population(
+            &mut context,
+            deleted
+                .into_iter()
+                .zip(new.into_iter())
+                .map(|(index, new_index)| (index, next[new_index].clone()))
+                .collect::<Vec<_>>()
+        );
+    }
+}
+
+impl<T> Iteration<IterationParams<T>> for Nsga2Iteration<T>
+where
+    T: Pod
+{
+    fn bind(&mut self, params: &IterationParams<T>) {
+        self.params = params.clone();
+    }
+
+    fn evaluate(&mut self) {
+        self.execute(&self.params);
+    }
+
+    fn evaluate_async(&mut self) -> Vec<wgpu::CommandBuffer> {
+        self.execute(&self.params);
+        vec![]
+    }
+
+    fn evaluate_with_params(&mut self, params: &IterationParams<T>) {
+        self.execute(params);
+    }
+
+    fn evaluate_with_params_async(&mut self, params: &IterationParams<T>) -> Vec<wgpu::CommandBuffer> {
+        self.execute(params);
+        vec![]
+    }
+}
@@ -0,0 +1,110 @@
+use std::{cell::RefCell, collections::HashSet, rc::Rc};
+
+use sgrmath_core::{OptimizationDirection, WgpuContext};
+use crate::{Context, Data, Individual, IterationParams, Options};
+
+use super::Nsga2Iteration;
+
+#[test]
+fn select() {
+    assert_eq!(
+        execute(
+            options(vec![ OptimizationDirection::Minimize ], 4, 4),
+            vec![ vec![ 5.0, 5.0 ], vec![ 1.0, 4.0 ], vec![ 6.0, 6.0 ], vec![ 4.0, 1.0 ] ],
+            vec![ vec![ 2.0, 2.0 ], vec![ 7.0, 7.0 ], vec![ 0.0, 5.0 ], vec![ 5.0, 0.0 ] ]
+        ),
+        HashSet::from_iter(vec![ 1, 4, 6, 7 ].into_iter())
+    );
+}
+
+#[test]
+fn select_directions() {
+    assert_eq!(
+        execute(
+            options(vec![ OptimizationDirection::Maximize ], 2, 2),
+            vec![ vec![ 1.0, 1.0 ], vec![ 1.0, 5.0 ] ],
+            vec![ vec![ 0.0, 6.0 ], vec![ 3.0, 0.0 ] ]
+        ),
+        HashSet::from_iter(vec![ 1, 2 ].into_iter())
+    );
+}
+
+#[test]
+fn select_single_objective() {
+    assert_eq!(
+        execute(
+            options(vec![], 5, 6),
+            vec![ vec![ 0.0 ], vec![ 1.0 ], vec![ 2.0 ], vec![ 3.0 ], vec![ 4.0 ] ],
+            vec![ vec![ 0.5 ], vec![ 1.5 ], vec![ 2.5 ], vec![ 3.5 ], vec![ 4.5 ], vec![ 5.5 ] ]
+        ),
+        HashSet::from_iter(vec![ 0, 1, 2, 5, 6 ].into_iter())
+    );
+}
+
+#[test]
+fn pareto_front() {
+    let options = options(vec![ OptimizationDirection::Minimize ], 4, 4);
+    let params = params(&options, &[ vec![ 5.0, 5.0 ], vec![ 1.0, 4.0 ], vec![ 6.0, 6.0 ], vec![ 4.0, 1.0 ] ]);
+
+    assert_eq!(params.data.borrow().pareto_front(&options), vec![ 1, 3 ]);
+}
+
+fn execute(options: Options, population: Vec<Vec<f32>>, next: Vec<Vec<f32>>) -> HashSet<usize> {
+    let params = params(&options, &population);
+
+    {
+        let mut context = params.context.borrow_mut();
+        let mut data = params.data.borrow_mut();
+
+        data.results.update_buffer_range(&context.wgpu, &next.concat(), 0);
+        data.generation = data.read_generation(&mut context).unwrap();
+    }
+
+    Nsga2Iteration::new(&params).execute(&params);
+
+    let result = params.data
+        .borrow()
+        .individuals
+        .iter()
+        .map(|individual| individual.id)
+        .collect();
+
+    result
+}
+
+fn options(extra_objectives: Vec<OptimizationDirection>, population_size: usize, generation_size: usize) -> Options {
+    Options {
+        optimization_direction: OptimizationDirection::Minimize,
+        extra_objectives,
+        population_size,
+        generation_size,
+        parents_count: 2,
+        vector_length: 3,
+        min_value: -1.0,
+        max_value: 1.0,
+        ..Options::default()
+    }
+}
+
+fn params(options: &Options, population: &[Vec<f32>]) -> IterationParams<f32> {
+    let wgpu = WgpuContext::new();
+    let mut data = Data::new(&wgpu, &options);
+    data.individuals = population
+        .iter()
+        .enumerate()
+        .map(|(index, objectives)| Individual::new(index, 0, vec![], objectives.clone()))
+        .collect();
+
+    IterationParams {
+        context: Rc::new(RefCell::new({
+            let mut context = Context::new(&wgpu, &options);
+            context.generation_index = 1;
+            context.next_id = options.population_size;
+
+            context
+        })),
+        data: Rc::new(RefCell::new(data)),
+        solutions_count: options.generation_size,
+        solutions_offset: 0,
+    }
+}
@@ -0,0 +1,71 @@
+use rand::{rngs::StdRng, SeedableRng};
+use sgrmath_core::{Random, RandomStreams, WgpuContext};
+
+use crate::Options;
+
+/// Context for genetic algorithm operations.
+///
+/// This struct holds the state and resources needed for genetic algorithm operations,
+/// including GPU context, random number generator, and various counters.
+#[derive(Debug)]
+pub struct Context {
+    /// The WGPU context used for GPU operations
+    pub wgpu: WgpuContext,
+    /// Configuration options for the genetic algorithm
+    pub options: Options,
+    /// Random number generator for genetic operations, seeded by [`Options::seed`]
+    ///
+    /// The operators of this crate draw from their own streams, see [`Context::stream_rng`].
+    pub rng: StdRng,
+    /// The streams of the random numbers drawn on the GPU, one per operator
+    pub streams: RandomStreams,
+    /// Next available ID for new individuals
+    pub next_id: usize,
+    /// Current generation index
+    pub generation_index: usize,
+    /// Whether the first generation has been initialized
+    pub is_initialized: bool,
+}
+
+impl Context {
+    /// Creates a new context instance.
+    ///
+    /// # Arguments
+    /// * `wgpu` - The WGPU context used for GPU operations
+    /// * `options` - Configuration options for the genetic algorithm
+    ///
+    /// # Returns
+    /// A new `Context` instance
+    pub fn new(wgpu: &WgpuContext, options: &Options) -> Self {
+        let seed = options.seed.unwrap_or_else(rand::random);
+
+        Self {
+            wgpu: wgpu.clone(),
+            options: options.clone(),
+            rng: StdRng::seed_from_u64(seed),
+            streams: RandomStreams::new(seed),
+            next_id: 0,
+            generation_index: 0,
+            is_initialized: false,
+        }
+    }
+
+    /// Returns the seed of the random numbers
+    pub fn seed(&self) -> u64 {
+        self.streams.seed()
+    }
+
+    /// Returns a generator for an operator drawing on the CPU, seeded by the next unused stream
+    ///
+    /// Operators take their generator once, when they are created, so the numbers of an
+    /// operator do not depend on how many numbers the others draw.
+    pub fn stream_rng(&mut self) -> StdRng {
+        let mut random = Random::new(self.streams.stream().key(), 0);
+        let mut seed = [0; 32];
+        for word in seed.chunks_mut(4) {
+            word.copy_from_slice(&random.next_u32().to_le_bytes());
+        }
+
+        StdRng::from_seed(seed)
+    }
+}
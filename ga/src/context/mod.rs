@@ -0,0 +1,5 @@
+#[cfg(test)]
+mod tests;
+mod context;
+
+pub use context::Context;
\ No newline at end of file
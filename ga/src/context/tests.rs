@@ -0,0 +1,57 @@
+use rand::Rng;
+use sgrmath_core::{OptimizationDirection, WgpuContext};
+
+use crate::{Context, Options};
+
+#[test]
+fn seeded() {
+    let wgpu = WgpuContext::new();
+    let (mut first, mut second) = (Context::new(&wgpu, &options(Some(7))), Context::new(&wgpu, &options(Some(7))));
+
+    assert_eq!(first.seed(), 7);
+    assert_eq!(first.rng.random::<u64>(), second.rng.random::<u64>());
+    assert_eq!(first.streams.stream(), second.streams.stream());
+    assert_eq!(first.stream_rng().random::<u64>(), second.stream_rng().random::<u64>());
+}
+
+#[test]
+fn other_seed() {
+    let wgpu = WgpuContext::new();
+    let (mut first, mut second) = (Context::new(&wgpu, &options(Some(7))), Context::new(&wgpu, &options(Some(8))));
+
+    assert_ne!(first.rng.random::<u64>(), second.rng.random::<u64>());
+    assert_ne!(first.stream_rng().random::<u64>(), second.stream_rng().random::<u64>());
+}
+
+#[test]
+fn independent_streams() {
+    let mut context = Context::new(&WgpuContext::new(), &options(Some(7)));
+
+    let first = context.stream_rng().random::<u64>();
+    let second = context.stream_rng().random::<u64>();
+    context.rng.random::<u64>();
+
+    assert_ne!(first, second);
+    assert_eq!(context.streams.stream().key().stream, 2);
+}
+
+#[test]
+fn entropy() {
+    let wgpu = WgpuContext::new();
+
+    assert_ne!(Context::new(&wgpu, &options(None)).seed(), Context::new(&wgpu, &options(None)).seed());
+}
+
+fn options(seed: Option<u64>) -> Options {
+    Options {
+        optimization_direction: OptimizationDirection::Minimize,
+        population_size: 4,
+        generation_size: 2,
+        parents_count: 2,
+        vector_length: 3,
+        min_value: -1.0,
+        max_value: 1.0,
+        seed,
+        ..Options::default()
+    }
+}
@@ -1,31 +1,34 @@
-use std::ops::DerefMut;
+use sgrmath_core::{Compiled, CompiledIteration, Iteration, RandomStream, Schedule, Shader, Size, ValueBuffer, WgpuContext};
 
-use rand_distr::{Distribution, Uniform};
-use sgrmath_core::{Compiled, CompiledIteration, Iteration, Shader, Size, StorageBuffer, ValueBuffer};
+use crate::{continuous::crossovers::blx_alpha::{DispatchOptions, ShaderOptions}, Context, IterationParams};
 
-use crate::{continuous::crossovers::blx_alpha::ShaderOptions, Context, IterationParams};
+const WORKGROUP_SIZE: u32 = 64;
 
 pub struct BLXAlpha {
-    pub k: f32
+    /// The width of the blending interval relative to the parents' distance,
+    /// scheduled by the generation index
+    pub k: Schedule,
 }
 
 pub struct BLXAlphaIteration {
-    k: f32,
+    k: Schedule,
     shader: Shader,
     bind: Option<IterationParams<f32>>,
-    buffer_options: ValueBuffer,
-    buffer_random: StorageBuffer,
+    stream: RandomStream,
 }
 
 impl BLXAlpha {
-    pub fn new(k: f32) -> CompiledIteration<Self, BLXAlphaIteration, IterationParams<f32>> {
-        CompiledIteration::new(Self { k })
+    pub fn new<K>(k: K) -> CompiledIteration<Self, BLXAlphaIteration, IterationParams<f32>>
+    where
+        K: Into<Schedule>,
+    {
+        CompiledIteration::new(Self { k: k.into() })
     }
 }
 
 impl Compiled<IterationParams<f32>, BLXAlphaIteration> for BLXAlpha {
     fn compile(&self, params: &IterationParams<f32>) -> BLXAlphaIteration {
-        BLXAlphaIteration::new(self.k, params)
+        BLXAlphaIteration::new(self.k.clone(), params)
     }
 }
 
@@ -38,105 +41,119 @@ impl Iteration<IterationParams<f32>> for BLXAlphaIteration {
         self.shader.bind(
             &context.wgpu, 
             &[
-                &self.buffer_options,
                 &data.population,
                 &data.parents,
-                &self.buffer_random,
                 &data.next,
             ]
-        );
+        ).expect("BLXAlpha buffers do not match the shader bindings");
     }
 
     fn evaluate(&mut self) {
-        let params = self.bind.as_ref().expect("evaluate called without bind");
-        let size = self.size(params);
-        let mut context = params.context.borrow_mut();
-        self.fill_random(&size, context.deref_mut());
+        let params = self.bind.clone().expect("evaluate called without bind");
+        let size = self.size(&params);
+        let context = params.context.borrow();
+        let options = self.dispatch_options(&context, &params);
+        self.shader.set_push_constants(&options);
 
         self.shader.execute(&context.wgpu, size);
     }
 
     fn evaluate_async(&mut self) -> Vec<wgpu::CommandBuffer> {
-        let params = self.bind.as_ref().expect("evaluate called without bind");
-        let mut context = params.context.borrow_mut();
-        let size = self.size(params);
-        self.fill_random(&size, context.deref_mut());
+        let params = self.bind.clone().expect("evaluate called without bind");
+        let size = self.size(&params);
+        let context = params.context.borrow();
+        let options = self.dispatch_options(&context, &params);
+        self.shader.set_push_constants(&options);
 
         vec![ self.shader.execute_async(&context.wgpu, size) ]
     }
 
     fn evaluate_with_params(&mut self, params: &IterationParams<f32>) {
         let size = self.size(params);
-        let mut context = params.context.borrow_mut();
+        let context = params.context.borrow();
         let data = params.data.borrow();
-        self.fill_random(&size, context.deref_mut());
+        let options = self.dispatch_options(&context, params);
+        self.shader.set_push_constants(&options);
 
         self.shader.execute_with_params(
             &context.wgpu, 
             size,
             &[
-                &self.buffer_options,
                 &data.population,
                 &data.parents,
-                &self.buffer_random,
                 &data.next,
             ]
-        );
+        ).expect("BLXAlpha buffers do not match the shader bindings");
     }
 
     fn evaluate_with_params_async(&mut self, params: &IterationParams<f32>) -> Vec<wgpu::CommandBuffer> {
         let size = self.size(params);
-        let mut context = params.context.borrow_mut();
+        let context = params.context.borrow();
         let data = params.data.borrow();
-        self.fill_random(&size, context.deref_mut());
+        let options = self.dispatch_options(&context, params);
+        self.shader.set_push_constants(&options);
 
         vec![ 
             self.shader.execute_with_params_async(
                 &context.wgpu, 
                 size,
                 &[
-                    &self.buffer_options,
                     &data.population,
                     &data.parents,
-                    &self.buffer_random,
                     &data.next,
                 ]
-            )
+            ).expect("BLXAlpha buffers do not match the shader bindings")
         ]
     }
 }
 
 impl BLXAlphaIteration {
-    pub fn new(k: f32, params: &IterationParams<f32>) -> Self {
-        let context = params.context.borrow();
+    pub fn new<K>(k: K, params: &IterationParams<f32>) -> Self
+    where
+        K: Into<Schedule>,
+    {
+        let mut context = params.context.borrow_mut();
+        let buffer_options = ValueBuffer::init(
+            &context.wgpu, 
+            &ShaderOptions {
+                vector_length: context.options.vector_length as u32,
+                parents_count: context.options.parents_count as u32,
+                min: context.options.min_value,
+                max: context.options.max_value,
+            }
+        );
+
+        let mut shader = Self::shader(&context.wgpu);
+        shader
+            .bind_group(&context.wgpu, 1, &[ &buffer_options ])
+            .expect("BLXAlpha options do not match the shader bindings");
+
         Self { 
-            k, 
-            shader: Shader::new(&context.wgpu, "blx_alpha", include_str!("blx_alpha.wgsl")),
+            k: k.into(), 
+            shader,
             bind: None,
-            buffer_options: ValueBuffer::init(
-                &context.wgpu, 
-                &ShaderOptions {
-                    generation_offset: (params.solutions_offset * context.options.vector_length) as u32,
-                    vector_length: context.options.vector_length as u32,
-                    parents_count: context.options.parents_count as u32,
-                    min: context.options.min_value,
-                    max: context.options.max_value,
-                }
-            ),
-            buffer_random: StorageBuffer::new::<f32, _>(&context.wgpu, (params.solutions_count, context.options.vector_length)),
+            stream: context.streams.stream(),
         }
     }
 
-    fn fill_random(&self, size: &Size, context: &mut Context) {
-        self.buffer_random.update_buffer_range::<f32>(
-            &context.wgpu, 
-            &Uniform::new(-self.k / 2.0, self.k / 2.0)
-                .unwrap()
-                .sample_iter(&mut context.rng)
-                .take(size.len())
-                .collect::<Vec<_>>(),
-            0
-        );
+    /// Compiles the BLX-α shader
+    pub(crate) fn shader(wgpu: &WgpuContext) -> Shader {
+        Shader::builder("blx_alpha", include_str!("blx_alpha.wgsl"))
+            .workgroup_size(WORKGROUP_SIZE, 1, 1)
+            .push_constants("dispatch", "BlxAlphaDispatch")
+            .build(wgpu)
+            .expect("BLXAlpha shader must compile")
+    }
+
+    /// Returns the push constants of the next dispatch and advances the random stream
+    fn dispatch_options(&mut self, context: &Context, params: &IterationParams<f32>) -> DispatchOptions {
+        DispatchOptions {
+            random: self.stream.next_key(),
+            generation_offset: (params.solutions_offset * context.options.vector_length) as u32,
+            solutions_count: params.solutions_count as u32,
+            k: self.k.value(context.generation_index),
+            _padding: 0,
+        }
     }
 
     fn size(&self, params: &IterationParams<f32>) -> Size {
@@ -1,20 +1,39 @@
+#include "sgrmath::dispatch"
+#include "sgrmath::random"
+
 struct BlxAlphaOptions {
-    generation_offset: u32,
     vector_length: u32,
     parents_count: u32,
     min: f32,
     max: f32,
 }
 
-@group(0) @binding(0) var<storage, read> options: BlxAlphaOptions;
-@group(0) @binding(1) var<storage, read> population: array<f32>;
-@group(0) @binding(2) var<storage, read> parents: array<u32>;
-@group(0) @binding(3) var<storage, read> random: array<f32>;
-@group(0) @binding(4) var<storage, read_write> generation: array<f32>;
+struct BlxAlphaDispatch {
+    random: RandomKey,
+    generation_offset: u32,
+    solutions_count: u32,
+    k: f32,
+    _padding: u32,
+}
+
+@group(0) @binding(0) var<storage, read> population: array<f32>;
+@group(0) @binding(1) var<storage, read> parents: array<u32>;
+@group(0) @binding(2) var<storage, read_write> generation: array<f32>;
+
+@group(1) @binding(0) var<storage, read> options: BlxAlphaOptions;
+
+@compute @workgroup_size(WORKGROUP_SIZE_X, WORKGROUP_SIZE_Y, WORKGROUP_SIZE_Z)
+fn main(
+    @builtin(global_invocation_id) global_invocation_id: vec3<u32>,
+    @builtin(num_workgroups) num_workgroups: vec3<u32>,
+) {
+    let size = vec3<u32>(options.vector_length, dispatch.solutions_count, 1u);
+    let global_index = dispatch_index(global_invocation_id, num_workgroups);
+    if (!dispatch_in_bounds(global_index, size)) {
+        return;
+    }
+    let global_id = dispatch_position(global_index, size);
 
-@compute @workgroup_size(1, 1)
-fn main(@builtin(global_invocation_id) global_id: vec3<u32>) {
-    let global_index = global_id.y * options.vector_length + global_id.x;
     let parents_start = global_id.y * options.parents_count;
     let parents_end = parents_start + options.parents_count;
 
@@ -32,9 +51,10 @@ fn main(@builtin(global_invocation_id) global_id: vec3<u32>) {
     let center = sum / f32(options.parents_count);
     let delta = max - min;
     
-    var value = center + delta * random[global_index];
+    var rng = random_init(dispatch.random, global_index);
+    var value = center + delta * random_range(&rng, -dispatch.k / 2.0, dispatch.k / 2.0);
     if (value > options.max) { value = options.max; }
     if (value < options.min) { value = options.min; }
     
-    generation[options.generation_offset + global_index] = value;
+    generation[dispatch.generation_offset + global_index] = value;
 }
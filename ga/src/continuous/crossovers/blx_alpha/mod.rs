@@ -3,5 +3,5 @@ mod tests;
 mod options;
 mod blx_alpha;
 
-pub(crate) use options::ShaderOptions;
+pub(crate) use options::{DispatchOptions, ShaderOptions};
 pub use blx_alpha::{BLXAlpha, BLXAlphaIteration};
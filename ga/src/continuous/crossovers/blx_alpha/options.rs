@@ -1,9 +1,23 @@
+use sgrmath_core::RandomKey;
+
 #[repr(C)]
 #[derive(Copy, Clone, Debug, bytemuck::Pod, bytemuck::Zeroable, PartialEq)]
 pub struct ShaderOptions {
-    pub generation_offset: u32,
     pub vector_length: u32,
     pub parents_count: u32,
     pub min: f32,
     pub max: f32
-}
\ No newline at end of file
+}
+
+/// Options of the BLX-α shader changing every generation
+#[repr(C)]
+#[derive(Copy, Clone, Debug, bytemuck::Pod, bytemuck::Zeroable, PartialEq)]
+pub struct DispatchOptions {
+    /// The key of the random numbers, first as the shader struct requires it
+    pub random: RandomKey,
+    pub generation_offset: u32,
+    pub solutions_count: u32,
+    /// The width of the blending interval of the generation
+    pub k: f32,
+    pub _padding: u32,
+}
@@ -1,10 +1,10 @@
 use core::f32;
 use std::{cell::RefCell, rc::Rc};
 
-use sgrmath_core::{Iteration, OptimizationDirection, ReadbackBuffer, WgpuContext};
+use sgrmath_core::{Executor, Iteration, OptimizationDirection, ReadbackBuffer, WgpuContext};
 
 use crate::{Context, Data, IterationParams, Options};
-use super::super::BLXAlphaIteration;
+use super::super::{BLXAlpha, BLXAlphaIteration};
 
 #[test]
 fn evaluate_with_params() {
@@ -15,6 +15,44 @@ fn evaluate_with_params() {
         |iteration, params| iteration.evaluate_with_params(&params)
     );
 
+    assert_generated(&result);
+}
+
+#[test]
+fn evaluate_async() {
+    let result = execute_iteration(
+        0.5,
+        1,
+        3,
+        |iteration, params| {
+            let wgpu = params.context.borrow().wgpu.clone();
+            iteration.bind(params);
+            Executor::new(&wgpu).evaluate(iteration).submit();
+        }
+    );
+
+    assert_generated(&result);
+}
+
+#[test]
+fn compiled_once_for_any_count() {
+    let result = execute_iteration(
+        0.5,
+        1,
+        3,
+        |_, params| {
+            let mut iteration = BLXAlpha::new(0.5);
+            iteration.bind(&IterationParams { solutions_count: 1, solutions_offset: 0, ..params.clone() });
+            iteration.evaluate_with_params(params);
+
+            assert_eq!(iteration.compiled_count(), 1);
+        }
+    );
+
+    assert_generated(&result);
+}
+
+fn assert_generated(result: &[f32]) {
     for i in 0..result.len() {
         if i < 5 || i >= 20 {
             assert!(result[i] == 42.5, "invalid value at index {} ({})", i, result[i]);
@@ -41,7 +79,7 @@ where
         let context = params.context.borrow();
         let data = params.data.borrow();
 
-        data.population.update_buffer_range::<f32>(
+        data.population.update_buffer_range(
             &context.wgpu, 
             &vec![
                 0.0, 0.0, 0.0, 0.0, 0.0,
@@ -50,7 +88,7 @@ where
             ],
             0
         );
-        data.parents.update_buffer_range::<u32>(
+        data.parents.update_buffer_range(
             &context.wgpu, 
             &vec![
                 0, 1,
@@ -59,7 +97,7 @@ where
             ],
             0
         );
-        data.next.update_buffer_range::<f32>(
+        data.next.update_buffer_range(
             &context.wgpu, 
             &vec![
                 42.5, 42.5, 42.5, 42.5, 42.5,
@@ -77,7 +115,7 @@ where
     let context = params.context.borrow();
     let data = params.data.borrow();
     
-    return ReadbackBuffer::new::<f32, _>(
+    return ReadbackBuffer::<f32>::new(
         &context.wgpu, 
         options.generation_size * options.vector_length
     ).read(
@@ -97,6 +135,7 @@ fn options() -> Options {
         vector_length: 5,
         min_value: -100.0,
         max_value: 100.0,
+        ..Options::default()
     }
 }
 
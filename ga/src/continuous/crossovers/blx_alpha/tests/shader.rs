@@ -1,52 +1,98 @@
 use core::f32;
 use std::{cell::RefCell, rc::Rc};
 
-use sgrmath_core::{OptimizationDirection, ReadbackBuffer, Shader, StorageBuffer, ValueBuffer, WgpuContext};
+use sgrmath_core::{
+    OptimizationDirection, Random, RandomKey, RandomStream, ReadbackBuffer, StorageBuffer, ValueBuffer,
+    WgpuContext
+};
 
 use crate::{Context, Data, IterationParams, Options};
-use super::super::ShaderOptions;
+use super::super::{BLXAlphaIteration, DispatchOptions, ShaderOptions};
 
 #[test]
 fn execute() {
-    assert_eq!(
-        execute_shader(
-            1,
-            3,
-            // population
-            vec![
-                0.0, 0.0, 0.0, 0.0, 0.0,
-                1.0, 2.0, 3.0, 4.0, 5.0,
-                2.0, 4.0, 8.0, 16.0, 32.0,
-            ],
-            // parents
-            vec![
-                0, 1,
-                0, 2,
-                1, 2,
-            ],
-            // random
-            vec![
-                0.5, 1.0, -0.75, 0.25, 1.0,
-                0.0, 0.25, -0.25, 100.0, -100.0,
-                0.0, 0.0, 0.0, 0.0, 0.0,
-            ],
-        ),
-        vec![
-            42.2, 42.2, 42.2, 42.2, 42.2,
-            1.0, 3.0, -0.75, 3.0, 7.5,
-            1.0, 3.0, 2.0, 100.0, -100.0,
-            1.5, 3.0, 5.5, 10.0, 18.5,
-            42.2, 42.2, 42.2, 42.2, 42.2,
-        ]
-    )
+    let key = RandomStream::new(3, 1).next_key();
+    let population = vec![
+        0.0, 0.0, 0.0, 0.0, 0.0,
+        1.0, 2.0, 3.0, 4.0, 5.0,
+        2.0, 4.0, 8.0, 16.0, 32.0,
+    ];
+    let parents = vec![
+        0, 1,
+        0, 2,
+        1, 2,
+    ];
+
+    let result = execute_shader(1, 3, 0.5, key, population.clone(), parents.clone());
+    let expected = expected(1, 3, 0.5, key, &population, &parents);
+
+    assert_approx(&result, &expected);
+    assert!(result[5..20].iter().all(|value| *value != 42.2));
+}
+
+#[test]
+fn bounded() {
+    let key = RandomStream::new(3, 2).next_key();
+    let population = vec![
+        -90.0, -90.0, -90.0, -90.0, -90.0,
+        90.0, 90.0, 90.0, 90.0, 90.0,
+    ];
+    let parents = vec![0, 1, 1, 0];
+
+    let result = execute_shader(0, 2, 100.0, key, population.clone(), parents.clone());
+
+    assert_approx(&result, &expected(0, 2, 100.0, key, &population, &parents));
+    assert!(result[0..10].iter().all(|value| value.abs() == 100.0));
+}
+
+#[test]
+fn dispatch_key() {
+    let population = vec![0.0, 0.0, 0.0, 0.0, 0.0, 1.0, 1.0, 1.0, 1.0, 1.0];
+    let parents = vec![0, 1];
+    let mut stream = RandomStream::new(3, 1);
+
+    let first = execute_shader(0, 1, 1.0, stream.next_key(), population.clone(), parents.clone());
+    let second = execute_shader(0, 1, 1.0, stream.next_key(), population, parents);
+
+    assert_ne!(first[0..5], second[0..5]);
+}
+
+/// Computes the generation of the shader on the CPU
+fn expected(offset: usize, count: usize, k: f32, key: RandomKey, population: &[f32], parents: &[u32]) -> Vec<f32> {
+    let options = options();
+    let mut generation = vec![42.2; options.generation_size * options.vector_length];
+
+    for solution in 0..count {
+        for gene in 0..options.vector_length {
+            let values = parents[solution * 2..solution * 2 + 2]
+                .iter()
+                .map(|&parent| population[parent as usize * options.vector_length + gene])
+                .collect::<Vec<_>>();
+            let (min, max) = (values[0].min(values[1]), values[0].max(values[1]));
+            let mut random = Random::new(key, (solution * options.vector_length + gene) as u32);
+            let value = (min + max) / 2.0 + (max - min) * random.range(-k / 2.0, k / 2.0);
+
+            generation[(offset + solution) * options.vector_length + gene] =
+                value.clamp(options.min_value, options.max_value);
+        }
+    }
+
+    generation
+}
+
+fn assert_approx(result: &[f32], expected: &[f32]) {
+    for (i, (actual, expected)) in result.iter().zip(expected).enumerate() {
+        assert!((actual - expected).abs() < 1e-4, "invalid value at index {} ({} != {})", i, actual, expected);
+    }
 }
 
 fn execute_shader(
     offset: usize,
     count: usize,
+    k: f32,
+    key: RandomKey,
     population: Vec<f32>,
     parents: Vec<u32>,
-    random: Vec<f32>
 ) -> Vec<f32> {
     let options = options();
     let params = params(&options, offset, count);
@@ -54,30 +100,36 @@ fn execute_shader(
     let wgpu = context.wgpu.clone();
     let generation_size = options.generation_size * options.vector_length;
 
-    let shader = Shader::new(&wgpu, "blx_alpha", include_str!("../blx_alpha.wgsl"));
+    let mut shader = BLXAlphaIteration::shader(&wgpu);
 
     let buffer_options = ValueBuffer::init(
         &wgpu, 
         &ShaderOptions { 
-            generation_offset: (offset * options.vector_length) as u32,
             vector_length: options.vector_length as u32,
             parents_count: options.parents_count as u32,
             min: options.min_value,
             max: options.max_value,
         }
     );
-    let buffer_population = StorageBuffer::init::<f32>(&wgpu, &population);
-    let buffer_parents = StorageBuffer::init::<u32>(&wgpu, &parents);
-    let buffer_random = StorageBuffer::init::<f32>(&wgpu, &random);
-    let buffer_generation = StorageBuffer::init::<f32>(&wgpu, &vec![42.2; generation_size]);
+    let buffer_population = StorageBuffer::<f32>::init(&wgpu, &population);
+    let buffer_parents = StorageBuffer::<u32>::init(&wgpu, &parents);
+    let buffer_generation = StorageBuffer::<f32>::init(&wgpu, &vec![42.2; generation_size]);
 
+    shader.bind_group(&wgpu, 1, &[ &buffer_options ]).unwrap();
+    shader.set_push_constants(&DispatchOptions {
+        random: key,
+        generation_offset: (offset * options.vector_length) as u32,
+        solutions_count: count as u32,
+        k,
+        _padding: 0,
+    });
     shader.execute_with_params(
         &wgpu, 
         (options.vector_length, count),
-        &[ &buffer_options, &buffer_population, &buffer_parents, &buffer_random, &buffer_generation ]
-    );
+        &[ &buffer_population, &buffer_parents, &buffer_generation ]
+    ).unwrap();
 
-    return ReadbackBuffer::new::<f32, _>(&wgpu, generation_size).read(&wgpu, &buffer_generation, 0, generation_size);
+    return ReadbackBuffer::<f32>::new(&wgpu, generation_size).read(&wgpu, &buffer_generation, 0, generation_size);
 }
 
 fn options() -> Options {
@@ -89,6 +141,7 @@ fn options() -> Options {
         vector_length: 5,
         min_value: -100.0,
         max_value: 100.0,
+        ..Options::default()
     }
 }
 
@@ -1,13 +1,28 @@
-use rand::distr::{Distribution, Uniform};
-use sgrmath_core::{Compiled, CompiledIteration, Iteration};
+use sgrmath_core::{Compiled, CompiledIteration, Iteration, RandomKey, RandomStream, Shader, WgpuContext};
 
 use crate::IterationParams;
 
+const WORKGROUP_SIZE: u32 = 64;
+
+/// Initializes genes with uniformly distributed values in `[min_value, max_value)`,
+/// drawn on the GPU.
 #[derive(Clone, Debug)]
 pub struct Random;
 
 pub struct RandomIteration {
     params: IterationParams<f32>,
+    shader: Shader,
+    stream: RandomStream,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug, bytemuck::Pod, bytemuck::Zeroable)]
+struct DispatchOptions {
+    random: RandomKey,
+    population_offset: u32,
+    values_count: u32,
+    min: f32,
+    max: f32,
 }
 
 impl Random {
@@ -24,27 +39,47 @@ impl Compiled<IterationParams<f32>, RandomIteration> for Random {
 
 impl RandomIteration {
     pub fn new(params: &IterationParams<f32>) -> Self {
-        Self { params: params.clone() }
+        let mut context = params.context.borrow_mut();
+
+        Self {
+            params: params.clone(),
+            shader: Self::shader(&context.wgpu),
+            stream: context.streams.stream(),
+        }
     }
 
-    pub fn execute(&self, params: &IterationParams<f32>) {
-        let (wgpu, min, max, vector_length) = {
-            let context = params.context.borrow();
+    pub fn execute(&mut self, params: &IterationParams<f32>) {
+        let command = self.execute_async(params);
+        let wgpu = params.context.borrow().wgpu.clone();
+        wgpu.queue.submit([command]);
+        wgpu.device.poll(wgpu::PollType::Wait).unwrap();
+    }
 
-            (context.wgpu.clone(), context.options.min_value, context.options.max_value, context.options.vector_length)
-        };
-        let mut context = params.context.borrow_mut();
+    pub fn execute_async(&mut self, params: &IterationParams<f32>) -> wgpu::CommandBuffer {
+        let context = params.context.borrow();
         let data = params.data.borrow();
-        
-        let uniform = Uniform::new(min, max).unwrap();
-        data.population.update_buffer_range(
-            &wgpu, 
-            &uniform
-                .sample_iter(&mut context.rng)
-                .take(params.solutions_count * vector_length)
-                .collect::<Vec<f32>>(),
-            params.solutions_offset * vector_length,
-        );
+        let vector_length = context.options.vector_length;
+        let values_count = params.solutions_count * vector_length;
+
+        self.shader.set_push_constants(&DispatchOptions {
+            random: self.stream.next_key(),
+            population_offset: (params.solutions_offset * vector_length) as u32,
+            values_count: values_count as u32,
+            min: context.options.min_value,
+            max: context.options.max_value,
+        });
+        self.shader
+            .execute_with_params_async(&context.wgpu, values_count, &[&data.population])
+            .expect("Random initializer buffers do not match the shader bindings")
+    }
+
+    /// Compiles the random initializer shader
+    fn shader(wgpu: &WgpuContext) -> Shader {
+        Shader::builder("random_initializer", include_str!("random.wgsl"))
+            .workgroup_size(WORKGROUP_SIZE, 1, 1)
+            .push_constants("dispatch", "RandomInitializerDispatch")
+            .build(wgpu)
+            .expect("Random initializer shader must compile")
     }
 }
 
@@ -54,12 +89,13 @@ impl Iteration<IterationParams<f32>> for RandomIteration {
     }
 
     fn evaluate(&mut self) {
-        self.execute(&self.params);
+        let params = self.params.clone();
+        self.execute(&params);
     }
 
     fn evaluate_async(&mut self) -> Vec<wgpu::CommandBuffer> {
-        self.execute(&self.params);
-        vec![]
+        let params = self.params.clone();
+        vec![ self.execute_async(&params) ]
     }
 
     fn evaluate_with_params(&mut self, params: &IterationParams<f32>) {
@@ -67,7 +103,6 @@ impl Iteration<IterationParams<f32>> for RandomIteration {
     }
 
     fn evaluate_with_params_async(&mut self, params: &IterationParams<f32>) -> Vec<wgpu::CommandBuffer> {
-        self.execute(params);
-        vec![]
+        vec![ self.execute_async(params) ]
     }
 }
@@ -0,0 +1,26 @@
+#include "sgrmath::dispatch"
+#include "sgrmath::random"
+
+struct RandomInitializerDispatch {
+    random: RandomKey,
+    population_offset: u32,
+    values_count: u32,
+    min: f32,
+    max: f32,
+}
+
+@group(0) @binding(0) var<storage, read_write> population: array<f32>;
+
+@compute @workgroup_size(WORKGROUP_SIZE_X, WORKGROUP_SIZE_Y, WORKGROUP_SIZE_Z)
+fn main(
+    @builtin(global_invocation_id) global_invocation_id: vec3<u32>,
+    @builtin(num_workgroups) num_workgroups: vec3<u32>,
+) {
+    let global_index = dispatch_index(global_invocation_id, num_workgroups);
+    if (!dispatch_in_bounds(global_index, vec3<u32>(dispatch.values_count, 1u, 1u))) {
+        return;
+    }
+
+    var rng = random_init(dispatch.random, global_index);
+    population[dispatch.population_offset + global_index] = random_range(&rng, dispatch.min, dispatch.max);
+}
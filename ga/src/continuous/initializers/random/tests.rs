@@ -33,7 +33,7 @@ fn execute(offset: usize, count: usize) -> Vec<f32> {
 
     let (wgpu, result_buffer) = { 
         let (context, data) = (params.context.borrow(), params.data.borrow());
-        data.population.update_buffer_range::<f32>(
+        data.population.update_buffer_range(
             &context.wgpu, 
             &vec![0.0; options.population_size * options.vector_length], 
             0
@@ -44,7 +44,7 @@ fn execute(offset: usize, count: usize) -> Vec<f32> {
 
     RandomIteration::new(&params).execute(&params);
 
-    let reader = ReadbackBuffer::new::<f32, _>(&wgpu, (options.population_size, options.vector_length));
+    let reader = ReadbackBuffer::<f32>::new(&wgpu, (options.population_size, options.vector_length));
     reader.read(&wgpu, &result_buffer, 0, options.population_size * options.vector_length)
 }
 
@@ -57,6 +57,7 @@ pub fn options() -> Options {
         vector_length: 10,
         min_value: -1.0,
         max_value: 1.0,
+        ..Options::default()
     }
 }
 
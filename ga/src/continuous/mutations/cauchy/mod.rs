@@ -0,0 +1,5 @@
+#[cfg(test)]
+mod tests;
+mod cauchy;
+
+pub use cauchy::{Cauchy, CauchyIteration};
\ No newline at end of file
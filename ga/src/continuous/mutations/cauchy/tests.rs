@@ -0,0 +1,57 @@
+use sgrmath_core::Iteration;
+use crate::continuous::mutations;
+
+use super::{Cauchy, CauchyIteration};
+
+#[test]
+fn mutate() {
+    let data = execute(0.0, 0.01, 1.0, 0, 50);
+
+    let mut steps = data.iter().map(|value| value.abs()).collect::<Vec<_>>();
+    steps.sort_by(f32::total_cmp);
+    let median = steps[steps.len() / 2];
+
+    assert!((median - 0.01).abs() < 0.001, "Median step is {}", median);
+    assert!(steps.iter().any(|step| *step > 0.3), "No long jumps");
+}
+
+#[test]
+fn bounded() {
+    let data = execute(0.45, 1.0, 1.0, 0, 50);
+
+    assert!(data.iter().all(|value| (-0.5..=0.5).contains(value)), "Values out of bounds");
+    assert!(data.contains(&0.5) && data.contains(&-0.5));
+}
+
+#[test]
+fn offset() {
+    let data = execute(0.25, 0.1, 1.0, 20, 30);
+
+    for (i, value) in data.iter().enumerate() {
+        match (2000..5000).contains(&i) {
+            true => assert!(*value != 0.25, "Value at index {} is not mutated", i),
+            false => assert_eq!(*value, 0.25, "Value at index {} is mutated", i),
+        }
+    }
+}
+
+#[test]
+fn unlikely() {
+    let data = execute(0.25, 0.1, 0.0, 0, 50);
+
+    assert!(data.iter().all(|value| *value == 0.25), "Mutations with a zero probability");
+}
+
+#[test]
+#[should_panic(expected = "Cauchy scale")]
+fn negative_scale() {
+    mutations::tests::execute(&[0.0; 10], 0, 10, |params| {
+        Cauchy::new(-0.1, 0.1).bind(params);
+    });
+}
+
+fn execute(value: f32, scale: f32, probability: f32, offset: usize, count: usize) -> Vec<f32> {
+    mutations::tests::execute(&[value; 5000], offset, count, |params| {
+        CauchyIteration::new(scale, probability, params).execute(params);
+    })
+}
@@ -0,0 +1,142 @@
+use sgrmath_core::{Compiled, CompiledIteration, Iteration, RandomKey, RandomStream, Schedule, Shader, WgpuContext};
+
+use crate::IterationParams;
+
+const WORKGROUP_SIZE: u32 = 64;
+
+/// Adds normally distributed noise to every gene with the given probability, drawn on the GPU.
+///
+/// Small steps around the current value suit fine-tuning late in a run. The mutated genes
+/// are clamped to `[min_value, max_value]`.
+#[derive(Clone, Debug)]
+pub struct Gaussian {
+    /// The standard deviation of the noise, scheduled by the generation index
+    pub sigma: Schedule,
+    /// The probability of a gene to mutate, scheduled by the generation index
+    pub probability: Schedule,
+}
+
+pub struct GaussianIteration {
+    sigma: Schedule,
+    probability: Schedule,
+    params: IterationParams<f32>,
+    shader: Shader,
+    stream: RandomStream,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug, bytemuck::Pod, bytemuck::Zeroable)]
+struct DispatchOptions {
+    random: RandomKey,
+    generation_offset: u32,
+    values_count: u32,
+    probability: f32,
+    sigma: f32,
+    min: f32,
+    max: f32,
+}
+
+impl Gaussian {
+    /// Creates a Gaussian mutation
+    ///
+    /// The standard deviation is checked when compiled, see [`GaussianIteration::new`].
+    pub fn new<S, P>(sigma: S, probability: P) -> CompiledIteration<Self, GaussianIteration, IterationParams<f32>>
+    where
+        S: Into<Schedule>,
+        P: Into<Schedule>,
+    {
+        CompiledIteration::new(Self { sigma: sigma.into(), probability: probability.into() })
+    }
+}
+
+impl Compiled<IterationParams<f32>, GaussianIteration> for Gaussian {
+    fn compile(&self, params: &IterationParams<f32>) -> GaussianIteration {
+        GaussianIteration::new(self.sigma.clone(), self.probability.clone(), params)
+    }
+}
+
+impl GaussianIteration {
+    /// Creates a Gaussian mutation of the generation buffer, compiling its shader
+    ///
+    /// # Panics
+    /// Panics if the standard deviation can be negative.
+    pub fn new<S, P>(sigma: S, probability: P, params: &IterationParams<f32>) -> Self
+    where
+        S: Into<Schedule>,
+        P: Into<Schedule>,
+    {
+        let sigma = sigma.into();
+        assert!(sigma.min().map_or(true, |min| min >= 0.0), "Gaussian sigma {sigma:?} can be negative");
+
+        let mut context = params.context.borrow_mut();
+
+        Self {
+            sigma,
+            probability: probability.into(),
+            params: params.clone(),
+            shader: Self::shader(&context.wgpu),
+            stream: context.streams.stream(),
+        }
+    }
+
+    pub fn execute(&mut self, params: &IterationParams<f32>) {
+        let command = self.execute_async(params);
+        let wgpu = params.context.borrow().wgpu.clone();
+        wgpu.queue.submit([command]);
+        wgpu.device.poll(wgpu::PollType::Wait).unwrap();
+    }
+
+    /// Encodes the mutation of the generation buffer
+    pub fn execute_async(&mut self, params: &IterationParams<f32>) -> wgpu::CommandBuffer {
+        let context = params.context.borrow();
+        let data = params.data.borrow();
+        let vector_length = context.options.vector_length;
+        let values_count = params.solutions_count * vector_length;
+
+        self.shader.set_push_constants(&DispatchOptions {
+            random: self.stream.next_key(),
+            generation_offset: (params.solutions_offset * vector_length) as u32,
+            values_count: values_count as u32,
+            probability: self.probability.value(context.generation_index),
+            sigma: self.sigma.value(context.generation_index),
+            min: context.options.min_value,
+            max: context.options.max_value,
+        });
+        self.shader
+            .execute_with_params_async(&context.wgpu, values_count, &[&data.next])
+            .expect("Gaussian mutation buffers do not match the shader bindings")
+    }
+
+    /// Compiles the Gaussian mutation shader
+    fn shader(wgpu: &WgpuContext) -> Shader {
+        Shader::builder("gaussian_mutation", include_str!("gaussian.wgsl"))
+            .workgroup_size(WORKGROUP_SIZE, 1, 1)
+            .push_constants("dispatch", "GaussianMutationDispatch")
+            .build(wgpu)
+            .expect("Gaussian mutation shader must compile")
+    }
+}
+
+impl Iteration<IterationParams<f32>> for GaussianIteration {
+    fn bind(&mut self, params: &IterationParams<f32>) {
+        self.params = params.clone();
+    }
+
+    fn evaluate(&mut self) {
+        let params = self.params.clone();
+        self.execute(&params);
+    }
+
+    fn evaluate_async(&mut self) -> Vec<wgpu::CommandBuffer> {
+        let params = self.params.clone();
+        vec![ self.execute_async(&params) ]
+    }
+
+    fn evaluate_with_params(&mut self, params: &IterationParams<f32>) {
+        self.execute(params);
+    }
+
+    fn evaluate_with_params_async(&mut self, params: &IterationParams<f32>) -> Vec<wgpu::CommandBuffer> {
+        vec![ self.execute_async(params) ]
+    }
+}
@@ -0,0 +1,5 @@
+#[cfg(test)]
+mod tests;
+mod gaussian;
+
+pub use gaussian::{Gaussian, GaussianIteration};
\ No newline at end of file
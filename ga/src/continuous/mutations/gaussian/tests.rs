@@ -0,0 +1,76 @@
+use sgrmath_core::{Iteration, Schedule};
+use crate::continuous::mutations;
+
+use super::{Gaussian, GaussianIteration};
+
+#[test]
+fn mutate() {
+    let data = execute(0.0, 0.1, 1.0, 0, 50);
+
+    let mean = data.iter().sum::<f32>() / data.len() as f32;
+    let deviation = (data.iter().map(|value| (value - mean).powi(2)).sum::<f32>() / data.len() as f32).sqrt();
+
+    assert!(mean.abs() < 0.01, "Mean of the noise is {}", mean);
+    assert!((deviation - 0.1).abs() < 0.01, "Standard deviation of the noise is {}", deviation);
+}
+
+#[test]
+fn bounded() {
+    let data = execute(0.45, 10.0, 1.0, 0, 50);
+
+    assert!(data.iter().all(|value| (-0.5..=0.5).contains(value)), "Values out of bounds");
+    assert!(data.contains(&0.5) && data.contains(&-0.5));
+}
+
+#[test]
+fn probability() {
+    let data = execute(0.25, 0.1, 0.05, 0, 50);
+
+    let count = data.iter().filter(|value| **value != 0.25).count();
+    assert!((150..350).contains(&count), "Mutations count is {}", count);
+}
+
+#[test]
+fn offset() {
+    let data = execute(0.25, 0.1, 1.0, 20, 30);
+
+    for (i, value) in data.iter().enumerate() {
+        match (2000..5000).contains(&i) {
+            true => assert!(*value != 0.25, "Value at index {} is not mutated", i),
+            false => assert_eq!(*value, 0.25, "Value at index {} is mutated", i),
+        }
+    }
+}
+
+#[test]
+fn scheduled() {
+    let data = execute_scheduled(0.25, 0.1, Schedule::Piecewise(vec![ (0, 1.0), (3, 0.0) ]), 3, 0, 50);
+
+    assert!(data.iter().all(|value| *value == 0.25), "Mutations with a zero probability");
+}
+
+#[test]
+#[should_panic(expected = "Gaussian sigma")]
+fn negative_sigma() {
+    mutations::tests::execute(&[0.0; 10], 0, 10, |params| {
+        Gaussian::new(Schedule::Linear { start: 0.1, end: -0.1, steps: 10 }, 0.1).bind(params);
+    });
+}
+
+fn execute(value: f32, sigma: f32, probability: f32, offset: usize, count: usize) -> Vec<f32> {
+    execute_scheduled(value, sigma, Schedule::Constant(probability), 0, offset, count)
+}
+
+fn execute_scheduled(
+    value: f32,
+    sigma: f32,
+    probability: Schedule,
+    generation_index: usize,
+    offset: usize,
+    count: usize,
+) -> Vec<f32> {
+    mutations::tests::execute(&[value; 5000], offset, count, |params| {
+        params.context.borrow_mut().generation_index = generation_index;
+        GaussianIteration::new(sigma, probability, params).execute(params);
+    })
+}
@@ -1,3 +1,11 @@
+#[cfg(test)]
+mod tests;
+mod cauchy;
+mod gaussian;
+mod polynomial;
 mod random;
 
+pub use cauchy::{Cauchy, CauchyIteration};
+pub use gaussian::{Gaussian, GaussianIteration};
+pub use polynomial::{Polynomial, PolynomialIteration};
 pub use random::{Random, RandomIteration};
\ No newline at end of file
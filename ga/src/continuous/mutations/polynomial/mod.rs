@@ -0,0 +1,5 @@
+#[cfg(test)]
+mod tests;
+mod polynomial;
+
+pub use polynomial::{Polynomial, PolynomialIteration};
\ No newline at end of file
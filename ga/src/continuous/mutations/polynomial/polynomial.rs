@@ -0,0 +1,143 @@
+use sgrmath_core::{Compiled, CompiledIteration, Iteration, RandomKey, RandomStream, Schedule, Shader, WgpuContext};
+
+use crate::IterationParams;
+
+const WORKGROUP_SIZE: u32 = 64;
+
+/// The bounded polynomial mutation of NSGA-II (Deb and Goyal), drawn on the GPU.
+///
+/// Every gene mutates with the given probability by a step from a polynomial distribution
+/// that is scaled to the distance to the bounds, so the mutated genes always lie in
+/// `[min_value, max_value]`. Larger distribution indexes give smaller steps.
+#[derive(Clone, Debug)]
+pub struct Polynomial {
+    /// The distribution index, usually between 5 and 100, scheduled by the generation index
+    pub eta: Schedule,
+    /// The probability of a gene to mutate, scheduled by the generation index
+    pub probability: Schedule,
+}
+
+pub struct PolynomialIteration {
+    eta: Schedule,
+    probability: Schedule,
+    params: IterationParams<f32>,
+    shader: Shader,
+    stream: RandomStream,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug, bytemuck::Pod, bytemuck::Zeroable)]
+struct DispatchOptions {
+    random: RandomKey,
+    generation_offset: u32,
+    values_count: u32,
+    probability: f32,
+    eta: f32,
+    min: f32,
+    max: f32,
+}
+
+impl Polynomial {
+    /// Creates a polynomial mutation
+    ///
+    /// The distribution index is checked when compiled, see [`PolynomialIteration::new`].
+    pub fn new<S, P>(eta: S, probability: P) -> CompiledIteration<Self, PolynomialIteration, IterationParams<f32>>
+    where
+        S: Into<Schedule>,
+        P: Into<Schedule>,
+    {
+        CompiledIteration::new(Self { eta: eta.into(), probability: probability.into() })
+    }
+}
+
+impl Compiled<IterationParams<f32>, PolynomialIteration> for Polynomial {
+    fn compile(&self, params: &IterationParams<f32>) -> PolynomialIteration {
+        PolynomialIteration::new(self.eta.clone(), self.probability.clone(), params)
+    }
+}
+
+impl PolynomialIteration {
+    /// Creates a polynomial mutation of the generation buffer, compiling its shader
+    ///
+    /// # Panics
+    /// Panics if the distribution index can be negative.
+    pub fn new<S, P>(eta: S, probability: P, params: &IterationParams<f32>) -> Self
+    where
+        S: Into<Schedule>,
+        P: Into<Schedule>,
+    {
+        let eta = eta.into();
+        assert!(eta.min().map_or(true, |min| min >= 0.0), "Polynomial eta {eta:?} can be negative");
+
+        let mut context = params.context.borrow_mut();
+
+        Self {
+            eta,
+            probability: probability.into(),
+            params: params.clone(),
+            shader: Self::shader(&context.wgpu),
+            stream: context.streams.stream(),
+        }
+    }
+
+    pub fn execute(&mut self, params: &IterationParams<f32>) {
+        let command = self.execute_async(params);
+        let wgpu = params.context.borrow().wgpu.clone();
+        wgpu.queue.submit([command]);
+        wgpu.device.poll(wgpu::PollType::Wait).unwrap();
+    }
+
+    /// Encodes the mutation of the generation buffer
+    pub fn execute_async(&mut self, params: &IterationParams<f32>) -> wgpu::CommandBuffer {
+        let context = params.context.borrow();
+        let data = params.data.borrow();
+        let vector_length = context.options.vector_length;
+        let values_count = params.solutions_count * vector_length;
+
+        self.shader.set_push_constants(&DispatchOptions {
+            random: self.stream.next_key(),
+            generation_offset: (params.solutions_offset * vector_length) as u32,
+            values_count: values_count as u32,
+            probability: self.probability.value(context.generation_index),
+            eta: self.eta.value(context.generation_index),
+            min: context.options.min_value,
+            max: context.options.max_value,
+        });
+        self.shader
+            .execute_with_params_async(&context.wgpu, values_count, &[&data.next])
+            .expect("Polynomial mutation buffers do not match the shader bindings")
+    }
+
+    /// Compiles the polynomial mutation shader
+    fn shader(wgpu: &WgpuContext) -> Shader {
+        Shader::builder("polynomial_mutation", include_str!("polynomial.wgsl"))
+            .workgroup_size(WORKGROUP_SIZE, 1, 1)
+            .push_constants("dispatch", "PolynomialMutationDispatch")
+            .build(wgpu)
+            .expect("Polynomial mutation shader must compile")
+    }
+}
+
+impl Iteration<IterationParams<f32>> for PolynomialIteration {
+    fn bind(&mut self, params: &IterationParams<f32>) {
+        self.params = params.clone();
+    }
+
+    fn evaluate(&mut self) {
+        let params = self.params.clone();
+        self.execute(&params);
+    }
+
+    fn evaluate_async(&mut self) -> Vec<wgpu::CommandBuffer> {
+        let params = self.params.clone();
+        vec![ self.execute_async(&params) ]
+    }
+
+    fn evaluate_with_params(&mut self, params: &IterationParams<f32>) {
+        self.execute(params);
+    }
+
+    fn evaluate_with_params_async(&mut self, params: &IterationParams<f32>) -> Vec<wgpu::CommandBuffer> {
+        vec![ self.execute_async(params) ]
+    }
+}
@@ -0,0 +1,49 @@
+#include "sgrmath::dispatch"
+#include "sgrmath::random"
+
+struct PolynomialMutationDispatch {
+    random: RandomKey,
+    generation_offset: u32,
+    values_count: u32,
+    probability: f32,
+    eta: f32,
+    min: f32,
+    max: f32,
+}
+
+@group(0) @binding(0) var<storage, read_write> generation: array<f32>;
+
+@compute @workgroup_size(WORKGROUP_SIZE_X, WORKGROUP_SIZE_Y, WORKGROUP_SIZE_Z)
+fn main(
+    @builtin(global_invocation_id) global_invocation_id: vec3<u32>,
+    @builtin(num_workgroups) num_workgroups: vec3<u32>,
+) {
+    let global_index = dispatch_index(global_invocation_id, num_workgroups);
+    if (!dispatch_in_bounds(global_index, vec3<u32>(dispatch.values_count, 1u, 1u))) {
+        return;
+    }
+
+    var rng = random_init(dispatch.random, global_index);
+    if (random_uniform(&rng) >= dispatch.probability) {
+        return;
+    }
+
+    let index = dispatch.generation_offset + global_index;
+    let range = dispatch.max - dispatch.min;
+    if (range <= 0.0) {
+        return;
+    }
+
+    let value = clamp(generation[index], dispatch.min, dispatch.max);
+    let u = random_uniform(&rng);
+    let power = 1.0 / (dispatch.eta + 1.0);
+    var delta: f32;
+    if (u < 0.5) {
+        let base = 1.0 - (value - dispatch.min) / range;
+        delta = pow(2.0 * u + (1.0 - 2.0 * u) * pow(base, dispatch.eta + 1.0), power) - 1.0;
+    } else {
+        let base = 1.0 - (dispatch.max - value) / range;
+        delta = 1.0 - pow(2.0 * (1.0 - u) + 2.0 * (u - 0.5) * pow(base, dispatch.eta + 1.0), power);
+    }
+    generation[index] = clamp(value + delta * range, dispatch.min, dispatch.max);
+}
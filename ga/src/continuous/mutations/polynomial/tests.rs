@@ -0,0 +1,88 @@
+use sgrmath_core::{Iteration, Random, RandomStreams};
+use crate::continuous::mutations;
+
+use super::{Polynomial, PolynomialIteration};
+
+#[test]
+fn mutate() {
+    let values = (0..5000).map(|i| i as f32 / 5000.0 - 0.5).collect::<Vec<_>>();
+    let data = execute(&values, 20.0, 1.0, 0, 50);
+
+    for (i, (actual, expected)) in data.iter().zip(expected(&values, 20.0, 1.0)).enumerate() {
+        assert!((actual - expected).abs() < 1e-4, "Value at index {} is {} ({} expected)", i, actual, expected);
+    }
+}
+
+#[test]
+fn bounded() {
+    let values = [-0.5, -0.49, 0.0, 0.49, 0.5].repeat(1000);
+    let data = execute(&values, 0.5, 1.0, 0, 50);
+
+    assert!(data.iter().all(|value| (-0.5..=0.5).contains(value)), "Values out of bounds");
+}
+
+#[test]
+fn distribution_index() {
+    let values = vec![0.0; 5000];
+    let mean_step = |eta| execute(&values, eta, 1.0, 0, 50).iter().map(|value| value.abs()).sum::<f32>() / 5000.0;
+
+    let (wide, narrow) = (mean_step(2.0), mean_step(100.0));
+    assert!(narrow < wide / 10.0, "Mean steps are {} and {}", wide, narrow);
+}
+
+#[test]
+fn offset() {
+    let values = vec![0.25; 5000];
+    let data = execute(&values, 20.0, 1.0, 20, 30);
+
+    for (i, value) in data.iter().enumerate() {
+        match (2000..5000).contains(&i) {
+            true => assert!(*value != 0.25, "Value at index {} is not mutated", i),
+            false => assert_eq!(*value, 0.25, "Value at index {} is mutated", i),
+        }
+    }
+}
+
+#[test]
+#[should_panic(expected = "Polynomial eta")]
+fn negative_eta() {
+    mutations::tests::execute(&[0.0; 10], 0, 10, |params| {
+        Polynomial::new(-0.5, 0.1).bind(params);
+    });
+}
+
+/// Computes the mutation on the CPU, from the random numbers of the first dispatch
+fn expected(values: &[f32], eta: f32, probability: f32) -> Vec<f32> {
+    let key = RandomStreams::new(1).stream().key();
+    let (min, max) = (-0.5_f32, 0.5_f32);
+    let range = max - min;
+
+    values
+        .iter()
+        .enumerate()
+        .map(|(i, &value)| {
+            let mut random = Random::new(key, i as u32);
+            if random.uniform() >= probability {
+                return value;
+            }
+
+            let u = random.uniform();
+            let power = 1.0 / (eta + 1.0);
+            let delta = if u < 0.5 {
+                let base = 1.0 - (value - min) / range;
+                (2.0 * u + (1.0 - 2.0 * u) * base.powf(eta + 1.0)).powf(power) - 1.0
+            } else {
+                let base = 1.0 - (max - value) / range;
+                1.0 - (2.0 * (1.0 - u) + 2.0 * (u - 0.5) * base.powf(eta + 1.0)).powf(power)
+            };
+
+            (value + delta * range).clamp(min, max)
+        })
+        .collect()
+}
+
+fn execute(values: &[f32], eta: f32, probability: f32, offset: usize, count: usize) -> Vec<f32> {
+    mutations::tests::execute(values, offset, count, |params| {
+        PolynomialIteration::new(eta, probability, params).execute(params);
+    })
+}
@@ -1,67 +1,104 @@
-use rand::distr::{Distribution, Uniform};
-use rand_distr::Binomial;
-use sgrmath_core::{Compiled, CompiledIteration, Iteration, WgpuContext};
+use sgrmath_core::{Compiled, CompiledIteration, Iteration, RandomKey, RandomStream, Schedule, Shader, WgpuContext};
 
 use crate::IterationParams;
 
+const WORKGROUP_SIZE: u32 = 64;
+
+/// Replaces every gene with the given probability by a uniformly distributed value
+/// in `[min_value, max_value)`, drawn on the GPU.
 #[derive(Clone, Debug)]
 pub struct Random {
-    pub probability: f32,
+    /// The probability of a gene to mutate, scheduled by the generation index
+    pub probability: Schedule,
 }
 
 pub struct RandomIteration {
-    probability: f32,
+    probability: Schedule,
     params: IterationParams<f32>,
+    shader: Shader,
+    stream: RandomStream,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug, bytemuck::Pod, bytemuck::Zeroable)]
+struct DispatchOptions {
+    random: RandomKey,
+    generation_offset: u32,
+    values_count: u32,
+    probability: f32,
+    min: f32,
+    max: f32,
+    _padding: u32,
 }
 
 impl Random {
-    pub fn new(probability: f32) -> CompiledIteration<Self, RandomIteration, IterationParams<f32>> {
-        CompiledIteration::new(Self { probability })
+    pub fn new<P>(probability: P) -> CompiledIteration<Self, RandomIteration, IterationParams<f32>>
+    where
+        P: Into<Schedule>,
+    {
+        CompiledIteration::new(Self { probability: probability.into() })
     }
 }
 
 impl Compiled<IterationParams<f32>, RandomIteration> for Random {
     fn compile(&self, params: &IterationParams<f32>) -> RandomIteration {
-        RandomIteration::new(self.probability, params)
+        RandomIteration::new(self.probability.clone(), params)
     }
 }
 
 impl RandomIteration {
-    pub fn new(probability: f32, params: &IterationParams<f32>) -> Self {
-        Self { probability, params: params.clone() }
+    pub fn new<P>(probability: P, params: &IterationParams<f32>) -> Self
+    where
+        P: Into<Schedule>,
+    {
+        let mut context = params.context.borrow_mut();
+
+        Self {
+            probability: probability.into(),
+            params: params.clone(),
+            shader: Self::shader(&context.wgpu),
+            stream: context.streams.stream(),
+        }
     }
 
-    pub fn execute(&self, params: &IterationParams<f32>) {
-        let context = self.execute_async(params);
-        context.device.poll(wgpu::MaintainBase::Wait).unwrap();
+    pub fn execute(&mut self, params: &IterationParams<f32>) {
+        let command = self.execute_async(params);
+        let wgpu = params.context.borrow().wgpu.clone();
+        wgpu.queue.submit([command]);
+        wgpu.device.poll(wgpu::PollType::Wait).unwrap();
     }
 
-    pub fn execute_async(&self, params: &IterationParams<f32>) -> WgpuContext {
-        let mut context = params.context.borrow_mut();
-        let wgpu = context.wgpu.clone();
+    /// Encodes the mutation of the generation buffer
+    ///
+    /// The mutation is part of the returned command buffer rather than queue writes,
+    /// so it follows the crossover when both are submitted together.
+    pub fn execute_async(&mut self, params: &IterationParams<f32>) -> wgpu::CommandBuffer {
+        let context = params.context.borrow();
         let data = params.data.borrow();
-        let binomial = Binomial::new(
-            (context.options.vector_length * context.options.generation_size) as u64, 
-            self.probability as f64
-        ).unwrap();
-        
-        let max_index = params.solutions_count * context.options.vector_length;
-        let mutations_count = binomial.sample(&mut context.rng) as usize;
-        let uniform = Uniform::new(context.options.min_value, context.options.max_value).unwrap();
-        let indexes_offset = params.solutions_offset * context.options.vector_length;
-
-        let indexes = rand::seq::index::sample(&mut context.rng, max_index, mutations_count)
-            .into_iter()
-            .map(|i| i + indexes_offset);
-        let values = uniform
-            .sample_iter(&mut context.rng)
-            .take(mutations_count);
-
-        for (i, v) in indexes.zip(values) {
-            data.next.update_buffer_range_async(&wgpu, &[v], i);
-        }
+        let vector_length = context.options.vector_length;
+        let values_count = params.solutions_count * vector_length;
+
+        self.shader.set_push_constants(&DispatchOptions {
+            random: self.stream.next_key(),
+            generation_offset: (params.solutions_offset * vector_length) as u32,
+            values_count: values_count as u32,
+            probability: self.probability.value(context.generation_index),
+            min: context.options.min_value,
+            max: context.options.max_value,
+            _padding: 0,
+        });
+        self.shader
+            .execute_with_params_async(&context.wgpu, values_count, &[&data.next])
+            .expect("Random mutation buffers do not match the shader bindings")
+    }
 
-        wgpu
+    /// Compiles the random mutation shader
+    fn shader(wgpu: &WgpuContext) -> Shader {
+        Shader::builder("random_mutation", include_str!("random.wgsl"))
+            .workgroup_size(WORKGROUP_SIZE, 1, 1)
+            .push_constants("dispatch", "RandomMutationDispatch")
+            .build(wgpu)
+            .expect("Random mutation shader must compile")
     }
 }
 
@@ -71,12 +108,13 @@ impl Iteration<IterationParams<f32>> for RandomIteration {
     }
 
     fn evaluate(&mut self) {
-        self.execute(&self.params);
+        let params = self.params.clone();
+        self.execute(&params);
     }
 
     fn evaluate_async(&mut self) -> Vec<wgpu::CommandBuffer> {
-        self.execute_async(&self.params);
-        vec![]
+        let params = self.params.clone();
+        vec![ self.execute_async(&params) ]
     }
 
     fn evaluate_with_params(&mut self, params: &IterationParams<f32>) {
@@ -84,7 +122,6 @@ impl Iteration<IterationParams<f32>> for RandomIteration {
     }
 
     fn evaluate_with_params_async(&mut self, params: &IterationParams<f32>) -> Vec<wgpu::CommandBuffer> {
-        self.execute_async(params);
-        vec![]
+        vec![ self.execute_async(params) ]
     }
 }
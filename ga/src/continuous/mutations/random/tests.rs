@@ -1,6 +1,6 @@
 use std::{cell::RefCell, rc::Rc};
 
-use sgrmath_core::{OptimizationDirection, ReadbackBuffer, WgpuContext};
+use sgrmath_core::{OptimizationDirection, ReadbackBuffer, Schedule, WgpuContext};
 use crate::{Context, Data, IterationParams, Options};
 
 use super::RandomIteration;
@@ -29,13 +29,33 @@ fn offset() {
     }
 }
 
+#[test]
+fn certain() {
+    let data = execute_scheduled(0, 50, Schedule::Constant(1.0), 0);
+
+    for (i, value) in data.iter().enumerate() {
+        assert!((-0.5..0.5).contains(value), "Value at index {} is out of range ({})", i, value);
+    }
+}
+
+#[test]
+fn scheduled() {
+    let data = execute_scheduled(0, 50, Schedule::Piecewise(vec![ (0, 0.05), (3, 0.0) ]), 3);
+
+    assert!(data.iter().all(|value| *value == 42.5), "Mutations with a zero probability");
+}
+
 fn execute(offset: usize, count: usize) -> Vec<f32> {
+    execute_scheduled(offset, count, Schedule::Constant(0.05), 0)
+}
+
+fn execute_scheduled(offset: usize, count: usize, probability: Schedule, generation_index: usize) -> Vec<f32> {
     let options = options();
     let params = params(&options, offset, count);
 
     let (wgpu, next_buffer) = { 
         let (context, data) = (params.context.borrow(), params.data.borrow());
-        data.next.update_buffer_range::<f32>(
+        data.next.update_buffer_range(
             &context.wgpu, 
             &vec![42.5; options.population_size * options.vector_length], 
             0
@@ -44,9 +64,10 @@ fn execute(offset: usize, count: usize) -> Vec<f32> {
         (context.wgpu.clone(), data.next.clone())
     };
 
-    RandomIteration::new(0.05, &params).execute(&params);
+    params.context.borrow_mut().generation_index = generation_index;
+    RandomIteration::new(probability, &params).execute(&params);
 
-    let reader = ReadbackBuffer::new::<f32, _>(&wgpu, (options.population_size, options.vector_length));
+    let reader = ReadbackBuffer::<f32>::new(&wgpu, (options.population_size, options.vector_length));
     reader.read(&wgpu, &next_buffer, 0, options.population_size * options.vector_length)
 }
 
@@ -59,6 +80,7 @@ pub fn options() -> Options {
         vector_length: 100,
         min_value: -0.5,
         max_value: 0.5,
+        ..Options::default()
     }
 }
 
@@ -0,0 +1,53 @@
+use std::{cell::RefCell, rc::Rc};
+
+use sgrmath_core::{OptimizationDirection, ReadbackBuffer, WgpuContext};
+use crate::{Context, Data, IterationParams, Options};
+
+/// Fills the generation buffer with the values, mutates it and reads it back
+///
+/// The values are 50 vectors of length 100 in `[-0.5, 0.5]`, the mutation runs on `count`
+/// vectors from `offset` with the random numbers of seed 1.
+pub fn execute<F>(values: &[f32], offset: usize, count: usize, mutate: F) -> Vec<f32>
+where
+    F: FnOnce(&IterationParams<f32>),
+{
+    let options = options();
+    let params = params(&options, offset, count);
+
+    let (wgpu, next_buffer) = {
+        let (context, data) = (params.context.borrow(), params.data.borrow());
+        data.next.update_buffer_range(&context.wgpu, values, 0);
+
+        (context.wgpu.clone(), data.next.clone())
+    };
+
+    mutate(&params);
+
+    let reader = ReadbackBuffer::<f32>::new(&wgpu, (options.population_size, options.vector_length));
+    reader.read(&wgpu, &next_buffer, 0, options.population_size * options.vector_length)
+}
+
+fn options() -> Options {
+    Options {
+        optimization_direction: OptimizationDirection::Minimize,
+        population_size: 50,
+        generation_size: 100,
+        parents_count: 2,
+        vector_length: 100,
+        min_value: -0.5,
+        max_value: 0.5,
+        seed: Some(1),
+        ..Options::default()
+    }
+}
+
+fn params(options: &Options, offset: usize, count: usize) -> IterationParams<f32> {
+    let wgpu = WgpuContext::new();
+
+    IterationParams {
+        context: Rc::new(RefCell::new(Context::new(&wgpu, options))),
+        data: Rc::new(RefCell::new(Data::new(&wgpu, options))),
+        solutions_count: count,
+        solutions_offset: offset,
+    }
+}
@@ -1,9 +1,9 @@
 use std::marker::PhantomData;
 
 use bytemuck::Pod;
-use sgrmath_core::{OptimizationDirection, ReadbackBuffer, StorageBuffer, WgpuContext};
+use sgrmath_core::{ConstraintHandling, Error, FitnessPolicy, OptimizationDirection, ReadbackBatch, ReadbackBuffer, StorageBuffer, WgpuContext};
 
-use crate::{Context, Individual, Options};
+use crate::{constrained_sort, Context, Individual, Options};
 
 /// Data structure for genetic algorithm.
 ///
@@ -20,17 +20,24 @@ where
     /// Phantom data to hold the type parameter
     _t: PhantomData<T>,
     /// Buffer containing the population data
-    pub population: StorageBuffer,
+    pub population: StorageBuffer<T>,
     /// Buffer for storing intermediate results
-    pub next: StorageBuffer,
+    pub next: StorageBuffer<T>,
     /// Buffer for storing parents
-    pub parents: StorageBuffer,
+    pub parents: StorageBuffer<u32>,
     /// Buffer for storing results
-    pub results: StorageBuffer,
-    /// Readable buffer for reading data
-    pub reader: ReadbackBuffer,
+    pub results: StorageBuffer<f32>,
+    /// Buffer for storing constraint violations, `None` for unconstrained problems
+    pub violations: Option<StorageBuffer<f32>>,
+    /// Batch reading parents and results of a generation with one mapping
+    pub reader: ReadbackBatch,
+    /// Readable buffer for reading a single individual
+    pub individual_reader: ReadbackBuffer<T>,
     /// Vector of individuals in the population
     pub individuals: Vec<Individual>,
+    /// The individuals of the next generation, read by [`GA::generation`](crate::GA::generation)
+    /// before the selector takes them
+    pub generation: Vec<Individual>,
 }
 
 impl<T> Data<T> 
@@ -48,12 +55,15 @@ where
     pub fn new(wgpu: &WgpuContext, options: &Options) -> Self {
         Self { 
             _t: PhantomData,
-            population: StorageBuffer::new::<T, _>(wgpu, (options.population_size, options.vector_length)),
-            next: StorageBuffer::new::<T, _>(wgpu, (options.generation_size, options.vector_length)),
-            parents: StorageBuffer::new::<T, _>(wgpu, (options.generation_size, options.parents_count)),
-            results: StorageBuffer::new::<T, _>(wgpu, options.generation_size),
-            reader: ReadbackBuffer::new::<T, _>(wgpu, (options.generation_size, options.parents_count)),
+            population: StorageBuffer::new(wgpu, (options.population_size, options.vector_length)),
+            next: StorageBuffer::new(wgpu, (options.generation_size, options.vector_length)),
+            parents: StorageBuffer::new(wgpu, (options.generation_size, options.parents_count)),
+            results: StorageBuffer::new(wgpu, (options.generation_size, options.objectives_count())),
+            violations: options.constraints.map(|_| StorageBuffer::new(wgpu, options.generation_size)),
+            reader: ReadbackBatch::new(),
+            individual_reader: ReadbackBuffer::new(wgpu, options.vector_length),
             individuals: Vec::with_capacity(options.population_size),
+            generation: Vec::with_capacity(options.generation_size),
         }
     }
 
@@ -111,29 +121,44 @@ where
     ///
     /// # Returns
     /// A vector of individuals
-    pub fn read_generation(&mut self, context: &mut Context) -> Vec<Individual> {
+    ///
+    /// # Errors
+    /// Returns [`Error::InvalidFitness`] if an objective is rejected by the fitness policy,
+    /// and [`Error::Poll`] or [`Error::BufferMap`] if the readback fails.
+    pub fn read_generation(&mut self, context: &mut Context) -> Result<Vec<Individual>, Error> {
         let parents_size = context.options.generation_size * context.options.parents_count;
-        self.reader.scale::<u32, _>(&context.wgpu, parents_size);
-        
-        let parents = self.reader.read::<u32>(&context.wgpu, &self.parents, 0, parents_size);
-        let results = self.reader.read::<f32>(&context.wgpu, &self.results, 0, context.options.generation_size);
+        let parents_slot = self.reader.push(&self.parents, 0, parents_size);
+        let objectives_count = context.options.objectives_count();
+        let results_slot = self.reader.push(&self.results, 0, context.options.generation_size * objectives_count);
+        let violations_slot = self.violations
+            .as_ref()
+            .map(|violations| self.reader.push(violations, 0, context.options.generation_size));
+
+        let data = self.reader.try_read(&context.wgpu)?;
+        let parents = data.get(parents_slot);
+        let violations = violations_slot.map(|slot| data.get(slot));
+
+        let results = data.get(results_slot);
+        for value in results {
+            context.options.fitness_policy.check(*value)?;
+        }
 
         let mut individuals = Vec::with_capacity(context.options.generation_size);
-        for (index, result) in results.into_iter().enumerate() {
-            individuals.push(Individual { 
-                id: context.next_id + index, 
-                generation: context.generation_index, 
-                parents: parents
+        for (index, objectives) in results.chunks(objectives_count).enumerate() {
+            individuals.push(Individual::new(
+                context.next_id + index, 
+                context.generation_index, 
+                parents
                     .iter()
                     .skip(index * context.options.parents_count)
                     .take(context.options.parents_count)
                     .map(|x| *x as usize)
                     .collect(), 
-                result
-            });
+                objectives.to_vec()
+            ).with_violation(violations.map_or(0.0, |violations| violations[index])));
         }
 
-        individuals
+        Ok(individuals)
     }
 
     /// Reads an individual from the population buffer.
@@ -143,26 +168,54 @@ where
     /// * `options` - The options of the genetic algorithm
     /// * `index` - The index of the individual to read
     pub fn read_individual(&self, context: &Context, index: usize) -> Vec<T> {
-        ReadbackBuffer::new::<T, _>(&context.wgpu, context.options.vector_length)
-            .read::<T>(
-                &context.wgpu, 
-                &self.population, index * context.options.vector_length, 
-                context.options.vector_length
-            )
+        self.individual_reader.read(
+            &context.wgpu, 
+            &self.population, index * context.options.vector_length, 
+            context.options.vector_length
+        )
     }
 
     /// Finds the best individual in the population.
     ///
     /// # Arguments
     /// * `direction` - The direction of the optimization
+    /// * `policy` - How NaN and infinite results are ordered
+    /// * `constraints` - How constraint violations rank the individuals
+    ///
+    /// # Returns
+    /// The index and result of the first best individual
+    pub fn best(
+        &self,
+        direction: &OptimizationDirection,
+        policy: &FitnessPolicy,
+        constraints: ConstraintHandling
+    ) -> Option<(usize, f32)> {
+        constraints.best_of(
+            direction,
+            self.individuals.iter().map(|individual| (individual.result, individual.violation)),
+            policy
+        )
+    }
+
+    /// Finds the individuals of the Pareto front of the population.
+    ///
+    /// # Arguments
+    /// * `options` - The options of the genetic algorithm, with the directions of the objectives
     ///
     /// # Returns
-    /// The index and result of the best individual
-    pub fn best(&self, direction: &OptimizationDirection) -> Option<(usize, f32)> {
-        self.individuals
+    /// The indices of the individuals no other individual dominates, in ascending order.
+    /// Constraint violations are ranked as by [`constrained_sort`].
+    pub fn pareto_front(&self, options: &Options) -> Vec<usize> {
+        let directions = options.directions().cloned().collect::<Vec<_>>();
+        let objectives = self.individuals
             .iter()
-            .enumerate()
-            .min_by(|(_, a), (_, b)| direction.compare(&a.result, &b.result))
-            .map(|(index, individual)| (index, individual.result))
+            .map(|individual| individual.objectives.clone())
+            .collect::<Vec<_>>();
+        let violations = self.individuals.iter().map(|individual| individual.violation).collect::<Vec<_>>();
+
+        constrained_sort(&objectives, &violations, &directions, &options.fitness_policy, options.constraint_handling())
+            .into_iter()
+            .next()
+            .unwrap_or_default()
     }
 }
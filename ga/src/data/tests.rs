@@ -1,4 +1,4 @@
-use sgrmath_core::{OptimizationDirection, WgpuContext};
+use sgrmath_core::{ConstraintHandling, Error, FitnessPolicy, NanPolicy, OptimizationDirection, ReadbackBuffer, WgpuContext};
 use crate::{Context, Data, Individual, Options};
 
 #[test]
@@ -8,16 +8,14 @@ fn update_population() {
     data.update_population(
         &mut context, 
         vec![
-            (0, Individual { id: 51, generation: 0, parents: vec![], result: 0.0 }),
-            (5, Individual { id: 52, generation: 0, parents: vec![], result: 0.0 }),
-            (12, Individual { id: 149, generation: 0, parents: vec![], result: 0.0 })
+            (0, Individual { id: 51, generation: 0, parents: vec![], result: 0.0, objectives: vec![ 0.0 ], violation: 0.0 }),
+            (5, Individual { id: 52, generation: 0, parents: vec![], result: 0.0, objectives: vec![ 0.0 ], violation: 0.0 }),
+            (12, Individual { id: 149, generation: 0, parents: vec![], result: 0.0, objectives: vec![ 0.0 ], violation: 0.0 })
         ]
     );
 
-    data.reader.scale::<i32, _>(&context.wgpu, 500);
-
     assert_eq!(
-        data.reader.read::<i32>(&context.wgpu, &data.population, 0, 500),
+        ReadbackBuffer::new(&context.wgpu, 500).read(&context.wgpu, &data.population, 0, 500),
         (0..500)
             .map(|index| {
                 let (mut row, mut k, column) = (index / 10, 100, index % 10);
@@ -40,18 +38,116 @@ fn read_generation() {
     let (mut data, mut context) = prepare();
 
     assert_eq!(
-        data.read_generation(&mut context),
+        data.read_generation(&mut context).unwrap(),
         (0..100)
             .map(|index| Individual { 
                 id: 50 + index, 
                 generation: 0, 
                 parents: vec![ 0, index ], 
-                result: 3.0 * index as f32 + 2.5 
+                result: 3.0 * index as f32 + 2.5,
+                objectives: vec![ 3.0 * index as f32 + 2.5 ],
+                violation: 0.0
             })
             .collect::<Vec<_>>()
     );
 }
 
+#[test]
+fn read_generation_multi_objective() {
+    let options = Options {
+        optimization_direction: OptimizationDirection::Minimize,
+        extra_objectives: vec![ OptimizationDirection::Maximize ],
+        fitness_policy: FitnessPolicy::default(),
+        constraints: None,
+        population_size: 2,
+        generation_size: 3,
+        parents_count: 1,
+        vector_length: 1,
+        min_value: 0.0,
+        max_value: 1.0,
+        seed: None,
+    };
+    let wgpu = WgpuContext::new();
+    let mut context = Context::new(&wgpu, &options);
+    context.next_id = 2;
+
+    let mut data = Data::<f32>::new(&wgpu, &options);
+    data.parents.update_buffer_range(&wgpu, &[ 0, 1, 0 ], 0);
+    data.results.update_buffer_range(&wgpu, &[ 1.0, 10.0, 2.0, 20.0, 3.0, 30.0 ], 0);
+
+    assert_eq!(
+        data.read_generation(&mut context).unwrap(),
+        vec![
+            Individual::new(2, 0, vec![ 0 ], vec![ 1.0, 10.0 ]),
+            Individual::new(3, 0, vec![ 1 ], vec![ 2.0, 20.0 ]),
+            Individual::new(4, 0, vec![ 0 ], vec![ 3.0, 30.0 ]),
+        ]
+    );
+}
+
+#[test]
+fn read_generation_invalid_fitness() {
+    let options = Options {
+        optimization_direction: OptimizationDirection::Minimize,
+        extra_objectives: vec![ OptimizationDirection::Minimize ],
+        fitness_policy: FitnessPolicy { nan: NanPolicy::Error, ..FitnessPolicy::default() },
+        population_size: 2,
+        generation_size: 2,
+        parents_count: 1,
+        vector_length: 1,
+        min_value: 0.0,
+        max_value: 1.0,
+        ..Options::default()
+    };
+    let wgpu = WgpuContext::new();
+    let mut context = Context::new(&wgpu, &options);
+
+    let mut data = Data::<f32>::new(&wgpu, &options);
+    data.results.update_buffer_range(&wgpu, &[ 1.0, 10.0, 2.0, f32::NAN ], 0);
+
+    assert!(matches!(data.read_generation(&mut context), Err(Error::InvalidFitness(value)) if value.is_nan()));
+}
+
+#[test]
+fn read_generation_constrained() {
+    let options = Options {
+        optimization_direction: OptimizationDirection::Minimize,
+        extra_objectives: vec![],
+        fitness_policy: FitnessPolicy::default(),
+        constraints: Some(ConstraintHandling::Feasibility),
+        population_size: 2,
+        generation_size: 2,
+        parents_count: 1,
+        vector_length: 1,
+        min_value: 0.0,
+        max_value: 1.0,
+        seed: None,
+    };
+    let wgpu = WgpuContext::new();
+    let mut context = Context::new(&wgpu, &options);
+    context.next_id = 2;
+
+    let mut data = Data::<f32>::new(&wgpu, &options);
+    data.parents.update_buffer_range(&wgpu, &[ 0, 1 ], 0);
+    data.results.update_buffer_range(&wgpu, &[ 1.0, 2.0 ], 0);
+    data.violations.as_ref().expect("Constrained data has a violations buffer").update_buffer_range(&wgpu, &[ 0.5, 0.0 ], 0);
+
+    let individuals = data.read_generation(&mut context).unwrap();
+    assert_eq!(
+        individuals,
+        vec![
+            Individual::new(2, 0, vec![ 0 ], vec![ 1.0 ]).with_violation(0.5),
+            Individual::new(3, 0, vec![ 1 ], vec![ 2.0 ]),
+        ]
+    );
+
+    data.individuals = individuals;
+    assert_eq!(
+        data.best(&options.optimization_direction, &options.fitness_policy, options.constraint_handling()),
+        Some((1, 2.0))
+    );
+}
+
 #[test]
 fn read_individual() {
     let (data, mut context) = prepare();
@@ -65,12 +161,16 @@ fn read_individual() {
 fn prepare() -> (Data<i32>, Context) {
     let options = Options {
         optimization_direction: OptimizationDirection::Minimize,
+        extra_objectives: vec![],
+        fitness_policy: FitnessPolicy::default(),
+        constraints: None,
         population_size: 50,
         generation_size: 100,
         parents_count: 2,
         vector_length: 10,
         min_value: 0.0,
         max_value: 1.0,
+        seed: None,
     };
     let wgpu = WgpuContext::new();
     let mut context = Context::new(&wgpu, &options);
@@ -82,9 +182,11 @@ fn prepare() -> (Data<i32>, Context) {
         id: index, 
         generation: 0, 
         parents: vec![], 
-        result: 0.0 
+        result: 0.0,
+        objectives: vec![ 0.0 ],
+        violation: 0.0
     }).collect();
-    data.population.update_buffer_range::<i32>(
+    data.population.update_buffer_range(
         &wgpu,  
         (0..500)
             .map(|index| {
@@ -97,7 +199,7 @@ fn prepare() -> (Data<i32>, Context) {
             .as_slice(),
         0
     );
-    data.next.update_buffer_range::<i32>(
+    data.next.update_buffer_range(
         &wgpu, 
         (0..1000)
             .map(|index| {
@@ -110,7 +212,7 @@ fn prepare() -> (Data<i32>, Context) {
             .as_slice(),
         0
     );
-    data.parents.update_buffer_range::<u32>(
+    data.parents.update_buffer_range(
         &wgpu,
         (0..200)
             .map(|index| {
@@ -123,7 +225,7 @@ fn prepare() -> (Data<i32>, Context) {
             .as_slice(),
         0
     );
-    data.results.update_buffer_range::<f32>(
+    data.results.update_buffer_range(
         &wgpu,
         (0..100)
             .map(|index| 3.0 * index as f32 + 2.5)
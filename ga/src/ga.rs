@@ -1,15 +1,22 @@
 use std::{cell::RefCell, rc::Rc};
 
 use bytemuck::Pod;
-use sgrmath_core::{CompiledIteration, Iteration, NotImplementedIteration, ProblemParams, ReadbackBuffer, StorageBuffer, WgpuContext};
+use sgrmath_core::{
+    CompiledIteration, Error, Executor, Fallible, Iteration, NotImplementedIteration, ProblemParams, ProfileReport, Profiled,
+    ReadbackBuffer, StorageBuffer, TryIteration, WgpuContext
+};
 
-use crate::{Context, Data, Individual, IterationParams, Options};
+use crate::{Context, Data, Individual, IterationParams, Options, ParetoSolution};
 use crate::common;
 
 /// Genetic Algorithm implementation with GPU acceleration.
 ///
 /// This struct represents a genetic algorithm that can be used to solve optimization problems.
 /// It uses GPU acceleration through WGPU for improved performance.
+///
+/// The operators are driven as [`TryIteration`]s, so a failing operator ends the generation
+/// with an error. Operators implementing only [`Iteration`] are adapted by [`Fallible`],
+/// labelled by their role.
 pub struct GA<T> 
 where
     T: Pod
@@ -19,19 +26,21 @@ where
     /// The GA data
     pub data: Rc<RefCell<Data<T>>>,
     /// The problem to be solved
-    pub problem: Box<dyn Iteration<ProblemParams>>,
+    pub problem: Box<dyn TryIteration<ProblemParams<T>>>,
     /// The GA options
     pub options: Options,
     /// The initializer for the first generation
-    pub initializer: Box<dyn Iteration<IterationParams<T>>>,
+    pub initializer: Box<dyn TryIteration<IterationParams<T>>>,
     /// Parents selection strategy
-    pub parents: Box<dyn Iteration<IterationParams<T>>>,
+    pub parents: Box<dyn TryIteration<IterationParams<T>>>,
     /// Crossover operation
-    pub crossover: Box<dyn Iteration<IterationParams<T>>>,
+    pub crossover: Box<dyn TryIteration<IterationParams<T>>>,
     /// Mutation operation
-    pub mutation: Box<dyn Iteration<IterationParams<T>>>,
+    pub mutation: Box<dyn TryIteration<IterationParams<T>>>,
     /// Selection strategy
-    pub selector: Box<dyn Iteration<IterationParams<T>>>,
+    pub selector: Box<dyn TryIteration<IterationParams<T>>>,
+    /// The time spent in each operator during the last generation, see [`GA::profiled`]
+    pub profile: Option<ProfileReport>,
 }
 
 impl<T> GA<T> 
@@ -52,11 +61,12 @@ where
             data: Rc::new(RefCell::new(Data::new(context, options))),
             problem: Box::new(NotImplementedIteration::new("problem")),
             initializer: Box::new(NotImplementedIteration::new("initializer")),
-            parents: Box::new(CompiledIteration::new(common::parents::Random::new())),
+            parents: Box::new(Fallible::new("parents", CompiledIteration::new(common::parents::Random::new()))),
             crossover: Box::new(NotImplementedIteration::new("crossover")),
             mutation: Box::new(NotImplementedIteration::new("mutation")),
-            selector: Box::new(CompiledIteration::new(common::selectors::Default::new())),
+            selector: Box::new(Fallible::new("selector", CompiledIteration::new(common::selectors::Default::new()))),
             options: options.clone(),
+            profile: None,
         }
     }
 
@@ -64,23 +74,28 @@ where
     ///
     /// # Arguments
     /// * `f` - A function that takes a reference to the GA and an index, and returns a boolean indicating whether to continue running
-    pub fn run<F>(&mut self, f: F)
+    ///
+    /// # Errors
+    /// Stops at the first generation that fails and returns its error, see [`GA::generation`].
+    pub fn run<F>(&mut self, f: F) -> Result<(), Error>
     where
         F: Fn(&mut Self, usize) -> bool
     {
         let mut index = 0;
         loop {
-            self.generation();
+            self.generation()?;
             index += 1;
 
             if !f(self, index) {
-                break;
+                return Ok(());
             }
         }
     }
 
     /// Sets the problem to be solved.
     ///
+    /// Panics of the problem are reported as errors labelled "problem", see [`Fallible`].
+    ///
     /// # Arguments
     /// * `problem` - The problem options
     ///
@@ -88,7 +103,22 @@ where
     /// `&mut Self` for method chaining
     pub fn problem<P>(mut self, problem: P) -> Self
     where
-        P: Iteration<ProblemParams> + 'static,
+        P: Iteration<ProblemParams<T>> + 'static,
+    {
+        self.problem = Box::new(Fallible::new("problem", problem));
+        self
+    }
+
+    /// Sets the problem to be solved, reporting its own errors.
+    ///
+    /// # Arguments
+    /// * `problem` - The problem options
+    ///
+    /// # Returns
+    /// `&mut Self` for method chaining
+    pub fn try_problem<P>(mut self, problem: P) -> Self
+    where
+        P: TryIteration<ProblemParams<T>> + 'static,
     {
         self.problem = Box::new(problem);
         self
@@ -96,6 +126,8 @@ where
 
     /// Sets the initializer for the first generation.
     ///
+    /// Panics of the initializer are reported as errors labelled "initializer", see [`Fallible`].
+    ///
     /// # Arguments
     /// * `initializer` - The initializer options
     ///
@@ -104,6 +136,21 @@ where
     pub fn initializer<I>(mut self, initializer: I) -> Self
     where
         I: Iteration<IterationParams<T>> + 'static,
+    {
+        self.initializer = Box::new(Fallible::new("initializer", initializer));
+        self
+    }
+
+    /// Sets the initializer for the first generation, reporting its own errors.
+    ///
+    /// # Arguments
+    /// * `initializer` - The initializer options
+    ///
+    /// # Returns
+    /// `&mut Self` for method chaining
+    pub fn try_initializer<I>(mut self, initializer: I) -> Self
+    where
+        I: TryIteration<IterationParams<T>> + 'static,
     {
         self.initializer = Box::new(initializer);
         self
@@ -111,6 +158,8 @@ where
 
     /// Sets the parents selection strategy.
     ///
+    /// Panics of the parents are reported as errors labelled "parents", see [`Fallible`].
+    ///
     /// # Arguments
     /// * `parents` - The parents selection options
     ///
@@ -119,6 +168,21 @@ where
     pub fn parents<P>(mut self, parents: P) -> Self
     where
         P: Iteration<IterationParams<T>> + 'static,
+    {
+        self.parents = Box::new(Fallible::new("parents", parents));
+        self
+    }
+
+    /// Sets the parents selection strategy, reporting its own errors.
+    ///
+    /// # Arguments
+    /// * `parents` - The parents selection options
+    ///
+    /// # Returns
+    /// `&mut Self` for method chaining
+    pub fn try_parents<P>(mut self, parents: P) -> Self
+    where
+        P: TryIteration<IterationParams<T>> + 'static,
     {
         self.parents = Box::new(parents);
         self
@@ -126,6 +190,8 @@ where
 
     /// Sets the crossover operation.
     ///
+    /// Panics of the crossover are reported as errors labelled "crossover", see [`Fallible`].
+    ///
     /// # Arguments
     /// * `crossover` - The crossover options
     ///
@@ -134,6 +200,21 @@ where
     pub fn crossover<C>(mut self, crossover: C) -> Self
     where
         C: Iteration<IterationParams<T>> + 'static,
+    {
+        self.crossover = Box::new(Fallible::new("crossover", crossover));
+        self
+    }
+
+    /// Sets the crossover operation, reporting its own errors.
+    ///
+    /// # Arguments
+    /// * `crossover` - The crossover options
+    ///
+    /// # Returns
+    /// `&mut Self` for method chaining
+    pub fn try_crossover<C>(mut self, crossover: C) -> Self
+    where
+        C: TryIteration<IterationParams<T>> + 'static,
     {
         self.crossover = Box::new(crossover);
         self
@@ -141,6 +222,8 @@ where
 
     /// Sets the mutation operation.
     ///
+    /// Panics of the mutation are reported as errors labelled "mutation", see [`Fallible`].
+    ///
     /// # Arguments
     /// * `mutation` - The mutation options
     ///
@@ -149,6 +232,21 @@ where
     pub fn mutation<M>(mut self, mutation: M) -> Self
     where
         M: Iteration<IterationParams<T>> + 'static,
+    {
+        self.mutation = Box::new(Fallible::new("mutation", mutation));
+        self
+    }
+
+    /// Sets the mutation operation, reporting its own errors.
+    ///
+    /// # Arguments
+    /// * `mutation` - The mutation options
+    ///
+    /// # Returns
+    /// `&mut Self` for method chaining
+    pub fn try_mutation<M>(mut self, mutation: M) -> Self
+    where
+        M: TryIteration<IterationParams<T>> + 'static,
     {
         self.mutation = Box::new(mutation);
         self
@@ -156,6 +254,8 @@ where
 
     /// Sets the selection strategy.
     ///
+    /// Panics of the selector are reported as errors labelled "selector", see [`Fallible`].
+    ///
     /// # Arguments
     /// * `selector` - The selection options
     ///
@@ -164,11 +264,83 @@ where
     pub fn selector<S>(mut self, selector: S) -> Self
     where
         S: Iteration<IterationParams<T>> + 'static,
+    {
+        self.selector = Box::new(Fallible::new("selector", selector));
+        self
+    }
+
+    /// Sets the selection strategy, reporting its own errors.
+    ///
+    /// # Arguments
+    /// * `selector` - The selection options
+    ///
+    /// # Returns
+    /// `&mut Self` for method chaining
+    pub fn try_selector<S>(mut self, selector: S) -> Self
+    where
+        S: TryIteration<IterationParams<T>> + 'static,
     {
         self.selector = Box::new(selector);
         self
     }
 
+    /// Profiles the operators, so every generation stores its timings in [`GA::profile`].
+    ///
+    /// Wraps the problem, initializer, parents, crossover, mutation and selector set so far
+    /// in [`Profiled`], labelled by their role, and enables the profiler of the WGPU context.
+    /// Call it after the operators are set.
+    ///
+    /// # Returns
+    /// `Self` for method chaining
+    ///
+    /// # Examples
+    /// ```no_run
+    /// use sgrmath_ga::GA;
+    ///
+    /// fn example(ga: GA<f32>) -> Result<(), sgrmath_core::Error> {
+    ///     let mut ga = ga.profiled().compile();
+    ///     ga.generation()?;
+    ///     ga.generation()?;
+    ///
+    ///     if let Some(profile) = &ga.profile {
+    ///         println!("{profile}");
+    ///     }
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn profiled(mut self) -> Self
+    where
+        T: 'static,
+    {
+        let wgpu = self.context.borrow().wgpu.clone();
+        wgpu.profiler.set_enabled(true);
+
+        self.problem = Box::new(Profiled::new(&wgpu, "problem", self.problem));
+        self.initializer = Box::new(Profiled::new(&wgpu, "initializer", self.initializer));
+        self.parents = Box::new(Profiled::new(&wgpu, "parents", self.parents));
+        self.crossover = Box::new(Profiled::new(&wgpu, "crossover", self.crossover));
+        self.mutation = Box::new(Profiled::new(&wgpu, "mutation", self.mutation));
+        self.selector = Box::new(Profiled::new(&wgpu, "selector", self.selector));
+
+        self
+    }
+
+    /// Returns the seed of the random numbers of the run.
+    ///
+    /// Set [`Options::seed`] to it to reproduce a run seeded from entropy.
+    ///
+    /// # Examples
+    /// ```
+    /// use sgrmath_ga::GA;
+    ///
+    /// fn example(ga: &GA<f32>) {
+    ///     println!("Seed: {}", ga.seed());
+    /// }
+    /// ```
+    pub fn seed(&self) -> u64 {
+        self.context.borrow().seed()
+    }
+
     /// Returns the best individual.
     /// 
     /// # Panics
@@ -207,7 +379,7 @@ where
         let data = self.data.borrow();
         let context = self.context.borrow();
         
-        match data.best(&context.options.optimization_direction) {
+        match data.best(&context.options.optimization_direction, &context.options.fitness_policy, context.options.constraint_handling()) {
             Some((index, _)) => Some(data.individuals[index].clone()),
             None => None
         }
@@ -251,67 +423,131 @@ where
         let data = self.data.borrow();
         let context = self.context.borrow();
         
-        match data.best(&context.options.optimization_direction) {
+        match data.best(&context.options.optimization_direction, &context.options.fitness_policy, context.options.constraint_handling()) {
             Some((index, _)) => Some(data.read_individual(&context, index)),
             None => None
         }
     }
 
+    /// Returns the Pareto front of the population.
+    ///
+    /// The front holds the individuals no other individual dominates on all objectives,
+    /// see [`Options::extra_objectives`]. For a single objective these are the best individuals.
+    /// The front is empty before the first generation.
+    ///
+    /// # Examples
+    /// ```
+    /// use sgrmath_ga::GA;
+    ///
+    /// fn example(ga: &GA<f32>) {
+    ///     for solution in ga.pareto_front() {
+    ///         println!("{}: {:?} -> {:?}", solution.index, solution.solution, solution.objectives);
+    ///     }
+    /// }
+    /// ```
+    pub fn pareto_front(&self) -> Vec<ParetoSolution<T>> {
+        let data = self.data.borrow();
+        let context = self.context.borrow();
+
+        data.pareto_front(&context.options)
+            .into_iter()
+            .map(|index| ParetoSolution {
+                index,
+                objectives: data.individuals[index].objectives.clone(),
+                solution: data.read_individual(&context, index),
+            })
+            .collect()
+    }
+
     /// Compiles the genetic algorithm by binding all components to their parameters.
     ///
     /// This method should be called after setting up all components (initializer, parents, crossover, etc.).
     ///
     /// # Returns
     /// `&mut Self` for method chaining
-    pub fn compile(mut self) -> Self {
-        let (wgpu, options, next, results) = {
-            let context = self.context.borrow();
-            let data = self.data.borrow();
-
-            (context.wgpu.clone(), context.options.clone(), data.next.clone(), data.results.clone())
-        };
+    ///
+    /// # Panics
+    /// Panics if a component cannot be bound, see [`GA::try_compile`].
+    pub fn compile(self) -> Self {
+        self.try_compile().expect("Failed to compile the genetic algorithm")
+    }
 
-        let params = IterationParams::new(self.context.clone(), self.data.clone(), options.generation_size);
-        let problem_params = ProblemParams { 
-            context: wgpu.clone(), 
-            solutions: next, 
-            results: results, 
-            solutions_offset: 0,
-            solutions_count: options.generation_size, 
-            vector_length: options.vector_length 
-        };
+    /// Compiles the genetic algorithm by binding all components to their parameters.
+    ///
+    /// # Returns
+    /// `Self` for method chaining
+    ///
+    /// # Errors
+    /// Returns the error of the first component that cannot be bound.
+    pub fn try_compile(mut self) -> Result<Self, Error> {
+        let generation_size = self.context.borrow().options.generation_size;
+        let params = IterationParams::new(self.context.clone(), self.data.clone(), generation_size);
+        let problem_params = params.problem_params();
         
-        self.parents.bind(&params);
-        self.crossover.bind(&params);
-        self.mutation.bind(&params);
-        self.problem.bind(&problem_params);
-        self.selector.bind(&params);
+        self.parents.try_bind(&params)?;
+        self.crossover.try_bind(&params)?;
+        self.mutation.try_bind(&params)?;
+        self.problem.try_bind(&problem_params)?;
+        self.selector.try_bind(&params)?;
 
-        self
+        Ok(self)
     }
 
     /// Runs a single generation of the genetic algorithm.
-    pub fn generation(&mut self) {
+    ///
+    /// If the profiler of the WGPU context is enabled, the timings of the generation
+    /// are stored in [`GA::profile`].
+    ///
+    /// # Errors
+    /// A failing operator returns its error, panics of operators adapted by [`Fallible`] are
+    /// reported as [`Error::IterationPanicked`] labelled by their role (problem, initializer,
+    /// parents, crossover, mutation or selector). Failed readbacks return their buffer errors
+    /// and results rejected by the fitness policy [`Error::InvalidFitness`].
+    /// The generation is not counted then, and the population may hold a partially produced
+    /// generation.
+    pub fn generation(&mut self) -> Result<(), Error> {
         match self.is_initialized() {
-            true => self.generation_next(),
-            false => self.generation_init()
+            true => self.generation_next()?,
+            false => self.generation_init()?
+        }
+
+        let wgpu = self.context.borrow().wgpu.clone();
+        if wgpu.profiler.is_enabled() {
+            self.profile = wgpu.profiler.report(&wgpu).ok();
         }
+
+        Ok(())
     }
 
-    fn generation_next(&mut self) {
-        self.parents.evaluate();
-        self.crossover.evaluate();
-        self.mutation.evaluate();
-        self.problem.evaluate();
-        self.selector.evaluate();
+    fn generation_next(&mut self) -> Result<(), Error> {
+        let wgpu = self.context.borrow().wgpu.clone();
+        let mut executor = Executor::new(&wgpu);
+
+        // CPU problems read the generation back, so the operators producing it are submitted first
+        executor
+            .try_evaluate(&mut self.parents)?
+            .try_evaluate(&mut self.crossover)?
+            .try_evaluate(&mut self.mutation)?
+            .submit();
+        executor.try_evaluate(&mut self.problem)?.submit();
+
+        // Reads the results back, after the submissions above
+        {
+            let mut context = self.context.borrow_mut();
+            let mut data = self.data.borrow_mut();
+            data.generation = data.read_generation(&mut context)?;
+        }
+        self.selector.try_evaluate()?;
 
         let mut context = self.context.borrow_mut();
 
         context.generation_index += 1;
         context.next_id += context.options.generation_size;
+
+        Ok(())
     }
 
-    fn generation_init(&mut self) {
+    fn generation_init(&mut self) -> Result<(), Error> {
         let (wgpu, population, options) = {
             let context = self.context.borrow();
             let data = self.data.borrow();
@@ -320,8 +556,11 @@ where
         };
 
         // Create result and readback buffer (population size can be more than generation size)
-        let results_buffer = StorageBuffer::new::<T, _>(&wgpu, options.population_size);
-        let readback_buffer = ReadbackBuffer::new::<f32, _>(&wgpu, options.population_size);
+        let objectives_count = options.objectives_count();
+        let results_len = options.population_size * objectives_count;
+        let results_buffer = StorageBuffer::<f32>::new(&wgpu, results_len);
+        let readback_buffer = ReadbackBuffer::<f32>::new(&wgpu, results_len);
+        let violations_buffer = options.constraints.map(|_| StorageBuffer::<f32>::new(&wgpu, options.population_size));
 
         let problem_params = ProblemParams { 
             context: wgpu.clone(), 
@@ -329,34 +568,43 @@ where
             results: results_buffer.clone(), 
             solutions_offset: 0,
             solutions_count: options.generation_size, 
-            vector_length: options.vector_length 
+            vector_length: options.vector_length,
+            objectives_count,
+            violations: violations_buffer.clone()
         };
 
-        self.initializer.evaluate_with_params(&IterationParams::new(
-            self.context.clone(), 
-            self.data.clone(), 
-            options.generation_size
-        ));
-        self.problem.evaluate_with_params(&problem_params);
+        let mut executor = Executor::new(&wgpu);
+        let params = IterationParams::new(self.context.clone(), self.data.clone(), options.generation_size);
+        // CPU problems read the population back, so the initializer is submitted first
+        executor.try_evaluate_with_params(&mut self.initializer, &params)?.submit();
+        executor.try_evaluate_with_params(&mut self.problem, &problem_params)?.submit();
 
+        let results = readback_buffer.try_read(&wgpu, &results_buffer, 0, results_len)?;
+        for value in &results {
+            options.fitness_policy.check(*value)?;
+        }
+        let violations = match &violations_buffer {
+            Some(buffer) => ReadbackBuffer::<f32>::new(&wgpu, options.population_size)
+                .try_read(&wgpu, buffer, 0, options.population_size)?,
+            None => vec![0.0; options.population_size],
+        };
         let mut context = self.context.borrow_mut();
         let mut data = self.data.borrow_mut();
 
-        data.individuals = readback_buffer
-            .read::<f32>(&wgpu, &results_buffer, 0, options.population_size)
-            .into_iter()
+        data.individuals = results
+            .chunks(objectives_count)
             .enumerate()
-            .map(|(i, result)| Individual { 
-                id: context.next_id + i, 
-                generation: 0, 
-                parents: vec![], 
-                result 
+            .zip(violations)
+            .map(|((i, objectives), violation)| {
+                Individual::new(context.next_id + i, 0, vec![], objectives.to_vec()).with_violation(violation)
             })
             .collect();
 
         context.generation_index += 1;
         context.next_id += context.options.population_size;
         context.is_initialized = true;
+
+        Ok(())
     }
 
     fn is_initialized(&self) -> bool {
@@ -1,7 +1,8 @@
 /// Represents an individual in the genetic algorithm population.
 ///
 /// Each individual has a unique ID, belongs to a specific generation,
-/// has a list of parent IDs, and a fitness result.
+/// has a list of parent IDs, and a fitness result. Multi-objective problems
+/// evaluate an individual on several objectives, the first one is also its `result`.
 #[derive(Debug, Clone, PartialEq)]
 pub struct Individual {
     /// Unique identifier for the individual
@@ -10,6 +11,36 @@ pub struct Individual {
     pub generation: usize,
     /// List of parent IDs that created this individual
     pub parents: Vec<usize>,
-    /// Fitness result of this individual
+    /// Fitness result of this individual, the value of the first objective
     pub result: f32,
+    /// Values of all objectives, starting from the first one
+    pub objectives: Vec<f32>,
+    /// Constraint violation of this individual, zero if it is feasible or the problem is unconstrained
+    pub violation: f32,
+}
+
+impl Individual {
+    /// Creates an individual from the values of its objectives
+    ///
+    /// # Arguments
+    /// * `id` - Unique identifier for the individual
+    /// * `generation` - Generation number this individual belongs to
+    /// * `parents` - List of parent IDs that created this individual
+    /// * `objectives` - Values of all objectives, the first one becomes the `result`
+    ///
+    /// # Panics
+    /// Panics if there are no objectives.
+    pub fn new(id: usize, generation: usize, parents: Vec<usize>, objectives: Vec<f32>) -> Self {
+        let result = *objectives.first().expect("An individual has at least one objective");
+        Self { id, generation, parents, result, objectives, violation: 0.0 }
+    }
+
+    /// Sets the constraint violation of the individual
+    ///
+    /// # Arguments
+    /// * `violation` - The constraint violation, zero for feasible individuals
+    pub fn with_violation(mut self, violation: f32) -> Self {
+        self.violation = violation;
+        self
+    }
 }
@@ -1,7 +1,7 @@
 use std::{cell::RefCell, ops::Range, rc::Rc};
 
 use bytemuck::Pod;
-use sgrmath_core::Sliced;
+use sgrmath_core::{ProblemParams, Sliced};
 
 use crate::{Context, Data};
 
@@ -43,6 +43,44 @@ where
     pub fn new(context: Rc<RefCell<Context>>, data: Rc<RefCell<Data<T>>>, solutions_count: usize) -> Self {
         Self { context, data, solutions_offset: 0, solutions_count }
     }
+
+    /// Returns the parameters evaluating the same solutions of the generation with a problem.
+    ///
+    /// The problem reads the solutions from the `next` buffer and writes their results to
+    /// the `results` buffer of the GA data. Combined with [`MapParams`](sgrmath_core::MapParams)
+    /// a problem becomes an iteration over `IterationParams`.
+    ///
+    /// # Examples
+    /// ```
+    /// use sgrmath_core::{Iteration, MapParams, ProblemParams, Sequence};
+    /// use sgrmath_ga::IterationParams;
+    ///
+    /// fn generation(
+    ///     crossover: Box<dyn Iteration<IterationParams<f32>>>,
+    ///     problem: Box<dyn Iteration<ProblemParams<f32>>>,
+    ///     selector: Box<dyn Iteration<IterationParams<f32>>>,
+    /// ) -> Sequence<IterationParams<f32>> {
+    ///     Sequence::new()
+    ///         .add(crossover)
+    ///         .add(Box::new(MapParams::new(problem, IterationParams::problem_params)))
+    ///         .add(selector)
+    /// }
+    /// ```
+    pub fn problem_params(&self) -> ProblemParams<T> {
+        let context = self.context.borrow();
+        let data = self.data.borrow();
+
+        ProblemParams {
+            context: context.wgpu.clone(),
+            solutions: data.next.clone(),
+            results: data.results.clone(),
+            solutions_offset: self.solutions_offset,
+            solutions_count: self.solutions_count,
+            vector_length: context.options.vector_length,
+            objectives_count: context.options.objectives_count(),
+            violations: data.violations.clone(),
+        }
+    }
 }
 
 impl<T> Sliced for IterationParams<T> 
@@ -4,6 +4,7 @@ mod ga;
 mod individual;
 mod iteration_params;
 mod options;
+mod pareto;
 
 pub use context::*;
 pub use data::*;
@@ -11,6 +12,7 @@ pub use ga::*;
 pub use individual::*;
 pub use iteration_params::*;
 pub use options::*;
+pub use pareto::*;
 
 /// Common module
 /// 
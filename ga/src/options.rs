@@ -1,4 +1,4 @@
-use sgrmath_core::OptimizationDirection;
+use sgrmath_core::{ConstraintHandling, FitnessPolicy, OptimizationDirection};
 
 /// Configuration options for genetic algorithm.
 ///
@@ -7,7 +7,17 @@ use sgrmath_core::OptimizationDirection;
 #[derive(Clone, Debug)]
 pub struct Options {
     /// Direction of optimization - whether to minimize or maximize the fitness function
+    ///
+    /// For multi-objective problems this is the direction of the first objective.
     pub optimization_direction: OptimizationDirection,
+    /// Directions of the objectives after the first one, empty for single-objective problems
+    pub extra_objectives: Vec<OptimizationDirection>,
+    /// How NaN and infinite fitness values are ordered and whether they are errors
+    pub fitness_policy: FitnessPolicy,
+    /// How constraint violations rank individuals, `None` for unconstrained problems
+    ///
+    /// Constrained problems get a violations buffer in their parameters.
+    pub constraints: Option<ConstraintHandling>,
     /// Total size of the population
     pub population_size: usize,
     /// Number of individuals processed in each generation
@@ -20,4 +30,62 @@ pub struct Options {
     pub min_value: f32,
     /// Maximum possible value in the solution vector
     pub max_value: f32,
+    /// The seed of all random numbers of the run, `None` for a seed from entropy
+    ///
+    /// Runs with the same seed, options and operators on the same adapter produce the same
+    /// individuals. The seed of a run is returned by [`GA::seed`](crate::GA::seed).
+    pub seed: Option<u64>,
+}
+
+impl Default for Options {
+    /// Returns options of a single-objective unconstrained minimization seeded from entropy,
+    /// with a population of 100 vectors of length 1 in `[-1, 1]` and 2 parents per child
+    fn default() -> Self {
+        Self {
+            optimization_direction: OptimizationDirection::Minimize,
+            extra_objectives: vec![],
+            fitness_policy: FitnessPolicy::default(),
+            constraints: None,
+            population_size: 100,
+            generation_size: 100,
+            parents_count: 2,
+            vector_length: 1,
+            min_value: -1.0,
+            max_value: 1.0,
+            seed: None,
+        }
+    }
+}
+
+impl Options {
+    /// Returns the number of objectives each solution is evaluated on
+    pub fn objectives_count(&self) -> usize {
+        1 + self.extra_objectives.len()
+    }
+
+    /// Returns how constraint violations rank individuals
+    ///
+    /// Individuals of unconstrained problems have no violation, so any handling ranks them by fitness.
+    pub fn constraint_handling(&self) -> ConstraintHandling {
+        self.constraints.unwrap_or_default()
+    }
+
+    /// Returns the optimization direction of an objective
+    ///
+    /// # Arguments
+    /// * `objective` - The index of the objective, `0` is the first one
+    ///
+    /// # Panics
+    /// Panics if the objective index is out of range.
+    pub fn direction(&self, objective: usize) -> &OptimizationDirection {
+        match objective {
+            0 => &self.optimization_direction,
+            _ => &self.extra_objectives[objective - 1],
+        }
+    }
+
+    /// Returns the optimization directions of all objectives, starting from the first one
+    pub fn directions(&self) -> impl Iterator<Item = &OptimizationDirection> {
+        std::iter::once(&self.optimization_direction).chain(self.extra_objectives.iter())
+    }
 }
@@ -0,0 +1,5 @@
+#[cfg(test)]
+mod tests;
+mod pareto;
+
+pub use pareto::{constrained_sort, crowding_distance, dominates, non_dominated_sort, ParetoSolution};
@@ -0,0 +1,204 @@
+use std::cmp::Ordering;
+
+use sgrmath_core::{ConstraintHandling, FitnessPolicy, OptimizationDirection};
+
+/// A solution of the current Pareto front.
+///
+/// # Type Parameters
+/// * `T` - The element type of the solution vector
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParetoSolution<T> {
+    /// Index of the individual in the population
+    pub index: usize,
+    /// Values of all objectives of the individual
+    pub objectives: Vec<f32>,
+    /// The decoded solution vector of the individual
+    pub solution: Vec<T>,
+}
+
+/// Returns true if the objectives `a` dominate the objectives `b`.
+///
+/// `a` dominates `b` if it is not worse on any objective and better on at least one.
+///
+/// # Arguments
+/// * `a` - Values of the objectives of the first solution
+/// * `b` - Values of the objectives of the second solution
+/// * `directions` - Optimization direction of each objective
+/// * `policy` - How NaN and infinite values are ordered
+pub fn dominates(a: &[f32], b: &[f32], directions: &[OptimizationDirection], policy: &FitnessPolicy) -> bool {
+    let mut better = false;
+    for ((a, b), direction) in a.iter().zip(b.iter()).zip(directions.iter()) {
+        match direction.total_cmp(a, b, policy) {
+            Ordering::Greater => return false,
+            Ordering::Less => better = true,
+            Ordering::Equal => {}
+        }
+    }
+
+    better
+}
+
+/// Sorts solutions into fronts of non-dominated solutions.
+///
+/// The first front holds the solutions no other solution dominates, the second one
+/// the solutions dominated by the first front only, and so on.
+///
+/// # Arguments
+/// * `objectives` - Values of the objectives of each solution
+/// * `directions` - Optimization direction of each objective
+/// * `policy` - How NaN and infinite values are ordered
+///
+/// # Returns
+/// The fronts as indices into `objectives`, each front in ascending order
+pub fn non_dominated_sort(
+    objectives: &[Vec<f32>],
+    directions: &[OptimizationDirection],
+    policy: &FitnessPolicy
+) -> Vec<Vec<usize>> {
+    let mut dominated = vec![vec![]; objectives.len()];
+    let mut domination_count = vec![0; objectives.len()];
+
+    for a in 0..objectives.len() {
+        for b in (a + 1)..objectives.len() {
+            if dominates(&objectives[a], &objectives[b], directions, policy) {
+                dominated[a].push(b);
+                domination_count[b] += 1;
+            } else if dominates(&objectives[b], &objectives[a], directions, policy) {
+                dominated[b].push(a);
+                domination_count[a] += 1;
+            }
+        }
+    }
+
+    let mut fronts = vec![];
+    let mut front = (0..objectives.len()).filter(|&index| domination_count[index] == 0).collect::<Vec<_>>();
+    while !front.is_empty() {
+        let mut next = vec![];
+        for &index in front.iter() {
+            for &other in dominated[index].iter() {
+                domination_count[other] -= 1;
+                if domination_count[other] == 0 {
+                    next.push(other);
+                }
+            }
+        }
+
+        next.sort_unstable();
+        fronts.push(front);
+        front = next;
+    }
+
+    fronts
+}
+
+/// Sorts solutions of a constrained problem into fronts of non-dominated solutions.
+///
+/// With [`ConstraintHandling::Feasibility`] the fronts of the feasible solutions come first,
+/// followed by the infeasible solutions in order of their violation, solutions with equal
+/// violations sharing a front. With [`ConstraintHandling::Penalty`] every objective is
+/// penalized before the solutions are sorted.
+///
+/// # Arguments
+/// * `objectives` - Values of the objectives of each solution
+/// * `violations` - Constraint violation of each solution
+/// * `directions` - Optimization direction of each objective
+/// * `policy` - How NaN and infinite values are ordered
+/// * `constraints` - How constraint violations rank the solutions
+///
+/// # Returns
+/// The fronts as indices into `objectives`, each front in ascending order
+pub fn constrained_sort(
+    objectives: &[Vec<f32>],
+    violations: &[f32],
+    directions: &[OptimizationDirection],
+    policy: &FitnessPolicy,
+    constraints: ConstraintHandling
+) -> Vec<Vec<usize>> {
+    if let ConstraintHandling::Penalty(_) = constraints {
+        let penalized = objectives
+            .iter()
+            .zip(violations.iter())
+            .map(|(values, &violation)| values
+                .iter()
+                .zip(directions.iter())
+                .map(|(&value, direction)| constraints.penalized(direction, value, violation))
+                .collect::<Vec<_>>()
+            )
+            .collect::<Vec<_>>();
+
+        return non_dominated_sort(&penalized, directions, policy);
+    }
+
+    let (feasible, mut infeasible): (Vec<_>, Vec<_>) = (0..objectives.len())
+        .partition(|&index| ConstraintHandling::is_feasible(violations[index]));
+    let feasible_objectives = feasible.iter().map(|&index| objectives[index].clone()).collect::<Vec<_>>();
+
+    let mut fronts = non_dominated_sort(&feasible_objectives, directions, policy)
+        .into_iter()
+        .map(|front| front.into_iter().map(|index| feasible[index]).collect::<Vec<_>>())
+        .collect::<Vec<_>>();
+
+    infeasible.sort_by(|&a, &b| OptimizationDirection::Minimize.compare(&violations[a], &violations[b]));
+    let mut previous = None;
+    for index in infeasible {
+        let violation = violations[index];
+        match fronts.last_mut() {
+            Some(front) if previous.is_some_and(|previous| violation.total_cmp(&previous) == Ordering::Equal) => {
+                front.push(index);
+            }
+            _ => fronts.push(vec![ index ]),
+        }
+        previous = Some(violation);
+    }
+
+    fronts
+}
+
+/// Computes the crowding distance of the solutions of a front.
+///
+/// The distance sums, over all objectives, the normalized gap between the neighbours
+/// of a solution. The extreme solutions of each objective get an infinite distance,
+/// so they always survive.
+///
+/// # Arguments
+/// * `front` - Indices of the solutions of the front
+/// * `objectives` - Values of the objectives of each solution
+/// * `directions` - Optimization direction of each objective
+/// * `policy` - How NaN and infinite values are ordered
+///
+/// # Returns
+/// The distance of each solution, in the order of `front`
+pub fn crowding_distance(
+    front: &[usize],
+    objectives: &[Vec<f32>],
+    directions: &[OptimizationDirection],
+    policy: &FitnessPolicy
+) -> Vec<f32> {
+    let mut distances = vec![0.0; front.len()];
+    if front.len() < 3 {
+        return vec![f32::INFINITY; front.len()];
+    }
+
+    for (k, direction) in directions.iter().enumerate() {
+        let mut order = (0..front.len()).collect::<Vec<_>>();
+        order.sort_by(|&a, &b| direction.total_cmp(&objectives[front[a]][k], &objectives[front[b]][k], policy));
+
+        let (first, last) = (order[0], order[order.len() - 1]);
+        distances[first] = f32::INFINITY;
+        distances[last] = f32::INFINITY;
+
+        let range = (objectives[front[last]][k] - objectives[front[first]][k]).abs();
+        if !range.is_finite() || range == 0.0 {
+            continue;
+        }
+
+        for window in order.windows(3) {
+            let gap = (objectives[front[window[2]]][k] - objectives[front[window[0]]][k]).abs();
+            if gap.is_finite() {
+                distances[window[1]] += gap / range;
+            }
+        }
+    }
+
+    distances
+}
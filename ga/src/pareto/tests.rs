@@ -0,0 +1,94 @@
+use sgrmath_core::{ConstraintHandling, FitnessPolicy, OptimizationDirection};
+
+use crate::{constrained_sort, crowding_distance, dominates, non_dominated_sort};
+
+#[test]
+fn dominance() {
+    let directions = [ OptimizationDirection::Minimize, OptimizationDirection::Maximize ];
+    let policy = FitnessPolicy::default();
+
+    assert!(dominates(&[ 1.0, 5.0 ], &[ 2.0, 4.0 ], &directions, &policy));
+    assert!(dominates(&[ 1.0, 5.0 ], &[ 1.0, 4.0 ], &directions, &policy));
+    assert!(!dominates(&[ 1.0, 5.0 ], &[ 1.0, 5.0 ], &directions, &policy));
+    assert!(!dominates(&[ 1.0, 5.0 ], &[ 0.0, 4.0 ], &directions, &policy));
+    assert!(dominates(&[ 1.0, 5.0 ], &[ f32::NAN, 5.0 ], &directions, &policy));
+}
+
+#[test]
+fn sort() {
+    let objectives = vec![
+        vec![ 3.0, 3.0 ],
+        vec![ 1.0, 4.0 ],
+        vec![ 2.0, 2.0 ],
+        vec![ 4.0, 4.0 ],
+        vec![ 4.0, 1.0 ],
+        vec![ 3.0, 5.0 ],
+    ];
+
+    assert_eq!(
+        non_dominated_sort(
+            &objectives,
+            &[ OptimizationDirection::Minimize, OptimizationDirection::Minimize ],
+            &FitnessPolicy::default()
+        ),
+        vec![ vec![ 1, 2, 4 ], vec![ 0 ], vec![ 3, 5 ] ]
+    );
+}
+
+#[test]
+fn sort_empty() {
+    assert!(non_dominated_sort(&[], &[ OptimizationDirection::Minimize ], &FitnessPolicy::default()).is_empty());
+}
+
+#[test]
+fn sort_constrained() {
+    let objectives = vec![
+        vec![ 3.0, 3.0 ],
+        vec![ 1.0, 4.0 ],
+        vec![ 2.0, 2.0 ],
+        vec![ 0.0, 0.0 ],
+        vec![ 4.0, 1.0 ],
+        vec![ 0.0, 1.0 ],
+    ];
+    let violations = [ 0.0, 0.0, 0.0, 2.0, 0.0, 2.0 ];
+    let directions = [ OptimizationDirection::Minimize, OptimizationDirection::Minimize ];
+    let policy = FitnessPolicy::default();
+
+    assert_eq!(
+        constrained_sort(&objectives, &violations, &directions, &policy, ConstraintHandling::Feasibility),
+        vec![ vec![ 1, 2, 4 ], vec![ 0 ], vec![ 3, 5 ] ]
+    );
+    assert_eq!(
+        constrained_sort(&objectives, &violations, &directions, &policy, ConstraintHandling::Penalty(1.0)),
+        vec![ vec![ 1, 2, 3, 4 ], vec![ 5 ], vec![ 0 ] ]
+    );
+}
+
+#[test]
+fn crowding() {
+    let objectives = vec![
+        vec![ 0.0, 4.0 ],
+        vec![ 1.0, 3.0 ],
+        vec![ 3.0, 1.0 ],
+        vec![ 4.0, 0.0 ],
+    ];
+
+    assert_eq!(
+        crowding_distance(
+            &[ 0, 1, 2, 3 ],
+            &objectives,
+            &[ OptimizationDirection::Minimize, OptimizationDirection::Minimize ],
+            &FitnessPolicy::default()
+        ),
+        vec![ f32::INFINITY, 1.5, 1.5, f32::INFINITY ]
+    );
+    assert_eq!(
+        crowding_distance(
+            &[ 1, 2 ],
+            &objectives,
+            &[ OptimizationDirection::Minimize, OptimizationDirection::Minimize ],
+            &FitnessPolicy::default()
+        ),
+        vec![ f32::INFINITY, f32::INFINITY ]
+    );
+}
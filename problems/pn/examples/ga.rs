@@ -1,10 +1,10 @@
 use std::{fs, path::Path};
 
-use sgrmath_core::{OptimizationDirection, WgpuContext};
+use sgrmath_core::{Error, OptimizationDirection, WgpuContext};
 use sgrmath_ga::{GA, Options, continuous};
 use sgrmath_pn::{Solution, PNP};
 
-fn main() {
+fn main() -> Result<(), Error> {
     let wgpu = WgpuContext::new();
     let pnp = PNP::from_csv(
         &wgpu, 
@@ -33,6 +33,7 @@ fn main() {
             vector_length: pnp.vectors_count * pnp.vector_length,
             min_value: -255.0,
             max_value: 255.0,
+            ..Options::default()
         }
     )
         .problem(pnp.clone())
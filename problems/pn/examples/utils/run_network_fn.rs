@@ -56,14 +56,14 @@ fn process_csv(wgpu: &WgpuContext, filename: &str, solution: &Solution) {
         &pnp.wgpu, 
         &ShaderOptions::new(&pnp, 1)
     );
-    let params = ProblemParams {
-        context: wgpu.clone(),
-        results: StorageBuffer::new::<f32, _>(&pnp.wgpu, 1),
-        solutions_offset: 0,
-        solutions_count: 1,
-        vector_length: solution.vectors.len() / solution.vectors_count,
-        solutions: StorageBuffer::new::<f32, _>(&pnp.wgpu, (pnp.vectors_count, pnp.vector_length)),
-    };
+    let params = ProblemParams::new(
+        wgpu.clone(),
+        StorageBuffer::new(&pnp.wgpu, (pnp.vectors_count, pnp.vector_length)),
+        StorageBuffer::new(&pnp.wgpu, 1),
+        0,
+        1,
+        solution.vectors.len() / solution.vectors_count,
+    );
     params.solutions.update_buffer_range(&pnp.wgpu, &solution.vectors, 0);
 
     pnp.evaluate_with_buffers(
@@ -71,12 +71,16 @@ fn process_csv(wgpu: &WgpuContext, filename: &str, solution: &Solution) {
         (&buffer_options, &buffer_multiply, &buffer_permutations, &buffer_permutation_labels)
     );
 
-    let reader = ReadbackBuffer::new::<f32, _>(&pnp.wgpu, (pnp.examples_count, params.vector_length));
+    let reader = ReadbackBuffer::new(&pnp.wgpu, (pnp.examples_count, params.vector_length));
+    let labels_reader = ReadbackBuffer::new(
+        &pnp.wgpu, 
+        pnp.examples_count.max(pnp.permutations_count * pnp.outputs_count)
+    );
 
-    let examples = reader.read::<f32>(&pnp.wgpu, &pnp.examples, 0, pnp.examples_count * (params.vector_length));
-    let examples_labels = reader.read::<u32>(&pnp.wgpu, &pnp.labels, 0, pnp.examples_count);
-    let permutation_labels = reader.read::<u32>(&pnp.wgpu, &buffer_permutation_labels, 0, pnp.permutations_count * pnp.outputs_count);
-    let permutations = reader.read::<u32>(&pnp.wgpu, &buffer_permutations, 0, pnp.examples_count);
+    let examples = reader.read(&pnp.wgpu, &pnp.examples, 0, pnp.examples_count * (params.vector_length));
+    let examples_labels = labels_reader.read(&pnp.wgpu, &pnp.labels, 0, pnp.examples_count);
+    let permutation_labels = labels_reader.read(&pnp.wgpu, &buffer_permutation_labels, 0, pnp.permutations_count * pnp.outputs_count);
+    let permutations = labels_reader.read(&pnp.wgpu, &buffer_permutations, 0, pnp.examples_count);
 
     let mut data = vec![];
     // add title
@@ -1,10 +1,18 @@
 use std::collections::HashMap;
 
-use sgrmath_core::{Iteration, ProblemParams, StorageBuffer, ValueBuffer, WgpuContext};
+use sgrmath_core::{Error, Iteration, Lease, ProblemParams, StorageBuffer, TryIteration, ValueBuffer, WgpuContext};
 use wgpu::CommandBuffer;
 
 use crate::shaders::{ShaderOptions, Shaders};
 
+/// Buffers used by the PNP shaders: options, multiply, permutations and permutation labels
+pub type Buffers = (
+    ValueBuffer<ShaderOptions>, 
+    Lease<StorageBuffer<f32>>, 
+    Lease<StorageBuffer<u32>>, 
+    Lease<StorageBuffer<u32>>
+);
+
 #[derive(Debug, Clone)]
 pub struct PNP {
     /// Wgpu context
@@ -20,13 +28,20 @@ pub struct PNP {
     /// Number of outputs
     pub outputs_count: usize,
     /// Examples vectors
-    pub examples: StorageBuffer,
+    pub examples: StorageBuffer<f32>,
     /// Examples labels
-    pub labels: StorageBuffer,
+    pub labels: StorageBuffer<u32>,
     /// Shaders
     pub shaders: Shaders,
     /// Binded params
-    pub params: Option<ProblemParams>
+    pub params: Option<ProblemParams<f32>>,
+    /// Buffers bound to the shaders by `bind`
+    pub buffers: Option<Buffers>,
+    /// Buffers referenced by the command buffer of the last `evaluate_with_params_async`
+    ///
+    /// They are kept out of the pool until the next evaluation: the command buffer must be
+    /// submitted before it, so later submissions are ordered after its reads.
+    pub in_flight: Option<Buffers>,
 }
 
 impl PNP {
@@ -45,10 +60,12 @@ impl PNP {
             vectors_count,
             vector_length,
             outputs_count,
-            examples: StorageBuffer::new::<f32, _>(wgpu, examples_count * vector_length),
-            labels: StorageBuffer::new::<u32, _>(wgpu, examples_count),
-            shaders: Shaders::new(wgpu),
-            params: None
+            examples: StorageBuffer::new(wgpu, examples_count * vector_length),
+            labels: StorageBuffer::new(wgpu, examples_count),
+            shaders: Shaders::new(wgpu, vectors_count),
+            params: None,
+            buffers: None,
+            in_flight: None,
         }
     }
 
@@ -75,8 +92,10 @@ impl PNP {
             outputs_count,
             examples: buffer_examples, 
             labels: buffer_labels,
-            shaders: Shaders::new(wgpu),
-            params: None
+            shaders: Shaders::new(wgpu, vectors_count),
+            params: None,
+            buffers: None,
+            in_flight: None,
         }
     }
 
@@ -172,28 +191,27 @@ impl PNP {
         result
     }
     
+    /// Leases the shader buffers from the context pool,
+    /// so repeated evaluations reuse the same GPU memory
     pub fn create_buffers(
         &self, 
         ctx: &WgpuContext, 
         options: &ShaderOptions
-    ) -> (ValueBuffer, StorageBuffer, StorageBuffer, StorageBuffer) {
-        let buffer_options = ValueBuffer::init::<ShaderOptions>(ctx, options);
-        let buffer_multiply = StorageBuffer::new::<f32, _>(
-            ctx, 
+    ) -> Buffers {
+        let buffer_options = ValueBuffer::init(ctx, options);
+        let buffer_multiply = ctx.pool.storage(
             (
                 (options.vectors_count * options.solutions_count) as usize,
                 options.examples_count as usize,
             )
         );
-        let buffer_permutations = StorageBuffer::new::<u32, _>(
-            ctx, 
+        let buffer_permutations = ctx.pool.storage(
             (
                 options.solutions_count as usize,
                 options.examples_count as usize,
             )
         );
-        let buffer_permutation_labels = StorageBuffer::new::<u32, _>(
-            ctx, 
+        let buffer_permutation_labels = ctx.pool.storage(
             (
                 options.solutions_count as usize,
                 options.permutations_count as usize,
@@ -204,51 +222,112 @@ impl PNP {
         (buffer_options, buffer_multiply, buffer_permutations, buffer_permutation_labels)
     }
 
+    /// Evaluates the solutions of the parameters with the given buffers
+    ///
+    /// # Panics
+    /// Panics if the buffers do not match the shader bindings.
     pub fn evaluate_with_buffers(
         &self, 
-        params: &ProblemParams, 
-        (options, multiply, permutations, permutation_labels): (&ValueBuffer, &StorageBuffer, &StorageBuffer, &StorageBuffer)
+        params: &ProblemParams<f32>, 
+        buffers: (&ValueBuffer<ShaderOptions>, &StorageBuffer<f32>, &StorageBuffer<u32>, &StorageBuffer<u32>)
     ) {
+        self.try_evaluate_with_buffers(params, buffers).expect("PNP buffers do not match the shader bindings");
+    }
+
+    /// Evaluates the solutions of the parameters with the given buffers
+    ///
+    /// # Errors
+    /// Returns an error if the buffers do not match the shader bindings.
+    pub fn try_evaluate_with_buffers(
+        &self, 
+        params: &ProblemParams<f32>, 
+        (options, multiply, permutations, permutation_labels): (&ValueBuffer<ShaderOptions>, &StorageBuffer<f32>, &StorageBuffer<u32>, &StorageBuffer<u32>)
+    ) -> Result<(), Error> {
         self.shaders.multiply.execute_with_params(
             &params.context, 
             (self.examples_count, self.vectors_count * params.solutions_count),
-            &[ &options, &self.examples, &params.solutions, &multiply ]
-        );
+            &[ options, &self.examples, &params.solutions, multiply ]
+        )?;
         self.shaders.permutations.execute_with_params(
             &params.context, 
             (params.solutions_count, self.examples_count),
-            &[ &options, &multiply, &permutations ]
-        );
+            &[ options, multiply, permutations ]
+        )?;
         self.shaders.results.execute_with_params(
             &params.context, 
             params.solutions_count,
-            &[ &options, &self.labels, &permutations, &permutation_labels, &params.results ]
-        );
+            &[ options, &self.labels, permutations, permutation_labels, &params.results ]
+        )
+    }
+
+    /// Returns the bound parameters
+    fn bound_params(&self) -> Result<&ProblemParams<f32>, Error> {
+        self.params.as_ref().ok_or_else(|| Error::NotBound("PNP::evaluate".to_string()))
     }
 }
 
-impl Iteration<ProblemParams> for PNP {
-    fn bind(&mut self, params: &ProblemParams) {
-        self.params = Some(params.clone());
+impl Iteration<ProblemParams<f32>> for PNP {
+    /// # Panics
+    /// Panics if the buffers do not match the shader bindings.
+    fn bind(&mut self, params: &ProblemParams<f32>) {
+        self.try_bind(params).expect("PNP buffers do not match the shader bindings");
+    }
+
+    /// # Panics
+    /// Panics if the problem has not been bound.
+    fn evaluate(&mut self) {
+        self.try_evaluate().expect("PNP must be bound before evaluate()");
+    }
+
+    /// # Panics
+    /// Panics if the problem has not been bound.
+    fn evaluate_async(&mut self) -> Vec<CommandBuffer> {
+        self.try_evaluate_async().expect("PNP must be bound before evaluate()")
+    }
+
+    /// # Panics
+    /// Panics if the buffers do not match the shader bindings.
+    fn evaluate_with_params(&mut self, params: &ProblemParams<f32>) {
+        self.try_evaluate_with_params(params).expect("PNP buffers do not match the shader bindings");
+    }
+
+    /// # Panics
+    /// Panics if the buffers do not match the shader bindings.
+    fn evaluate_with_params_async(&mut self, params: &ProblemParams<f32>) -> Vec<CommandBuffer> {
+        self.try_evaluate_with_params_async(params).expect("PNP buffers do not match the shader bindings")
+    }
+}
+
+impl TryIteration<ProblemParams<f32>> for PNP {
+    /// # Errors
+    /// Returns an error if the buffers do not match the shader bindings, the previous binding
+    /// may be partially replaced then.
+    fn try_bind(&mut self, params: &ProblemParams<f32>) -> Result<(), Error> {
         let shader_options = ShaderOptions::new(&self, params.solutions_count);
         let (options, multiply, permutations, permutation_labels) = self.create_buffers(&self.wgpu, &shader_options);
 
         self.shaders.multiply.bind(
             &params.context, 
             &[ &options, &self.examples, &params.solutions, &multiply ]
-        );
+        )?;
         self.shaders.permutations.bind(
             &params.context, 
             &[ &options, &multiply, &permutations ]
-        );
+        )?;
         self.shaders.results.bind(
             &params.context, 
             &[ &options, &self.labels, &permutations, &permutation_labels, &params.results ]
-        );
+        )?;
+        self.params = Some(params.clone());
+        self.buffers = Some((options, multiply, permutations, permutation_labels));
+        Ok(())
     }
 
-    fn evaluate(&mut self) {
-        let params = self.params.as_ref().expect("evaluate called before bind");
+    /// # Errors
+    /// Returns [`Error::NotBound`] if the problem has not been bound.
+    fn try_evaluate(&mut self) -> Result<(), Error> {
+        self.in_flight = None;
+        let params = self.bound_params()?;
 
         self.shaders.multiply.execute(
             &params.context, 
@@ -262,10 +341,14 @@ impl Iteration<ProblemParams> for PNP {
             &params.context, 
             params.solutions_count
         );
+        Ok(())
     }
 
-    fn evaluate_async(&mut self) -> Vec<CommandBuffer> {
-        let params = self.params.as_ref().expect("evaluate called before bind");
+    /// # Errors
+    /// Returns [`Error::NotBound`] if the problem has not been bound.
+    fn try_evaluate_async(&mut self) -> Result<Vec<CommandBuffer>, Error> {
+        self.in_flight = None;
+        let params = self.bound_params()?;
 
         self.shaders.multiply.execute(
             &params.context, 
@@ -276,22 +359,29 @@ impl Iteration<ProblemParams> for PNP {
             (params.solutions_count, self.examples_count)
         );
         
-        vec![
+        Ok(vec![
             self.shaders.results.execute_async(
                 &params.context, 
                 params.solutions_count
             )
-        ]
+        ])
     }
 
-    fn evaluate_with_params(&mut self, params: &ProblemParams) {
+    /// # Errors
+    /// Returns an error if the buffers do not match the shader bindings.
+    fn try_evaluate_with_params(&mut self, params: &ProblemParams<f32>) -> Result<(), Error> {
+        self.in_flight = None;
         let shader_options = ShaderOptions::new(&self, params.solutions_count);
         let (options, multiply, permutations, permutation_labels) = self.create_buffers(&self.wgpu, &shader_options);
 
-        self.evaluate_with_buffers(params, (&options, &multiply, &permutations, &permutation_labels));
+        self.try_evaluate_with_buffers(params, (&options, &multiply, &permutations, &permutation_labels))
     }
 
-    fn evaluate_with_params_async(&mut self, params: &ProblemParams) -> Vec<CommandBuffer> {
+    /// # Errors
+    /// Returns an error if the buffers do not match the shader bindings.
+    fn try_evaluate_with_params_async(&mut self, params: &ProblemParams<f32>) -> Result<Vec<CommandBuffer>, Error> {
+        // The previous command buffer is submitted by now: its buffers can be leased again
+        self.in_flight = None;
         let shader_options = ShaderOptions::new(&self, params.solutions_count);
         let (options, multiply, permutations, permutation_labels) = self.create_buffers(&self.wgpu, &shader_options);
 
@@ -299,19 +389,22 @@ impl Iteration<ProblemParams> for PNP {
             &params.context, 
             (self.examples_count, self.vectors_count * params.solutions_count),
             &[ &options, &self.examples, &params.solutions, &multiply ]
-        );
+        )?;
         self.shaders.permutations.execute_with_params(
             &params.context, 
             (params.solutions_count, self.examples_count),
             &[ &options, &multiply, &permutations ]
-        );
+        )?;
         
-        vec![
-            self.shaders.results.execute_with_params_async(
-                &params.context, 
-                params.solutions_count,
-                &[ &options, &self.labels, &permutations, &permutation_labels, &params.results ]
-            )
-        ]
+        let command_buffer = self.shaders.results.execute_with_params_async(
+            &params.context, 
+            params.solutions_count,
+            &[ &options, &self.labels, &permutations, &permutation_labels, &params.results ]
+        )?;
+
+        // The command buffer is submitted by the caller: keep its buffers out of the pool until then
+        self.in_flight = Some((options, multiply, permutations, permutation_labels));
+
+        Ok(vec![command_buffer])
     }
 }
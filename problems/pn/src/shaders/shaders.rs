@@ -2,6 +2,9 @@ use sgrmath_core::{Shader, WgpuContext};
 
 use crate::shaders::ShaderOptions;
 
+/// Invocations per workgroup of the PNP shaders
+const WORKGROUP_SIZE: u32 = 64;
+
 #[derive(Debug, Clone)]
 pub struct Shaders {
     pub multiply: Shader,
@@ -11,28 +14,28 @@ pub struct Shaders {
 }
 
 impl Shaders {
-    pub fn new(ctx: &WgpuContext) -> Self {
+    /// Compiles the PNP shaders for solutions of `vectors_count` vectors
+    pub fn new(ctx: &WgpuContext, vectors_count: usize) -> Self {
         Self {
-            multiply: create_shader(ctx, "pnp::multiply", include_str!("wgsl/multiply.wgsl")),
-            permutations: create_shader(ctx, "pnp::permutations", include_str!("wgsl/permutations.wgsl")),
-            results: create_shader(ctx, "pnp::results", include_str!("wgsl/results.wgsl")),
-            test: create_shader(ctx, "pnp::test", include_str!("wgsl/test.wgsl")),
+            multiply: create_shader(ctx, "pnp::multiply", include_str!("wgsl/multiply.wgsl"), vectors_count),
+            permutations: create_shader(ctx, "pnp::permutations", include_str!("wgsl/permutations.wgsl"), vectors_count),
+            results: create_shader(ctx, "pnp::results", include_str!("wgsl/results.wgsl"), vectors_count),
+            test: create_shader(ctx, "pnp::test", include_str!("wgsl/test.wgsl"), vectors_count),
         }
     }
 }
 
-fn create_shader<S: Into<String>>(
+fn create_shader(
     ctx: &WgpuContext, 
     name: &str, 
-    shader: S,
+    shader: &str,
+    vectors_count: usize,
 ) -> Shader {
-    Shader::new(
-        ctx, 
-        name, 
-        format!(
-            "{}\n\n{}",
-            ShaderOptions::wgsl(),
-            shader.into()
-        )
-    )
+    Shader::builder(name, shader)
+        .include("pnp::options", ShaderOptions::wgsl())
+        .workgroup_size(WORKGROUP_SIZE, 1, 1)
+        // The vectors of a solution and the implicit zero vector are sorted
+        .constant("INDICES_COUNT", vectors_count as u32 + 1)
+        .build(ctx)
+        .expect("PNP shaders must compile")
 }
@@ -1,16 +1,27 @@
-const FACTORIALS: array<u32, 8> = array<u32, 8>(1u, 2u, 6u, 24u, 120u, 720u, 5040u, 40320u);
+#include "pnp::options"
+#include "sgrmath::dispatch"
 
 @group(0) @binding(0) var<uniform> options: PNPOptions;
 @group(0) @binding(1) var<storage, read> input: array<f32>;
 @group(0) @binding(2) var<storage, read_write> output: array<u32>;
 
-@compute @workgroup_size(1, 1)
-fn main(@builtin(global_invocation_id) global_id: vec3<u32>) {
+@compute @workgroup_size(WORKGROUP_SIZE_X, WORKGROUP_SIZE_Y, WORKGROUP_SIZE_Z)
+fn main(
+    @builtin(global_invocation_id) global_invocation_id: vec3<u32>,
+    @builtin(num_workgroups) num_workgroups: vec3<u32>,
+) {
+    let size = vec3<u32>(options.solutions_count, options.examples_count, 1u);
+    let index = dispatch_index(global_invocation_id, num_workgroups);
+    if (!dispatch_in_bounds(index, size)) {
+        return;
+    }
+    let global_id = dispatch_position(index, size);
+
     let solution_start = global_id.x * options.vectors_count * options.examples_count + global_id.y;
     let solution_end = solution_start + options.vectors_count * options.examples_count;
     
     // Create array for indices
-    var indices: array<u32, 8>;
+    var indices: array<u32, INDICES_COUNT>;
     // Initialize indices
     for (var i = 0u; i <= options.vectors_count; i = i + 1u) {
         indices[i] = solution_start + i * options.examples_count;
@@ -36,7 +47,7 @@ fn main(@builtin(global_invocation_id) global_id: vec3<u32>) {
         }
     }
 
-    // Lehmer code implementation
+    // Lehmer code implementation, the factorials are accumulated by Horner's scheme
     var result = 0u;
     for (var i = 0u; i <= options.vectors_count; i = i + 1u) {
         var count = 0u;
@@ -46,9 +57,8 @@ fn main(@builtin(global_invocation_id) global_id: vec3<u32>) {
                 count = count + 1u;
             }
         }
-        
-        // Get factorial directly from array
-        result = result + count * FACTORIALS[options.vectors_count - i - 1];
+
+        result = result * (options.vectors_count + 1u - i) + count;
     }
 
     for (var i = 0u; i <= options.vectors_count; i = i + 1u) {
@@ -1,11 +1,24 @@
+#include "pnp::options"
+#include "sgrmath::dispatch"
+
 @group(0) @binding(0) var<uniform> options: PNPOptions;
 @group(0) @binding(1) var<storage, read> labels: array<u32>;
 @group(0) @binding(2) var<storage, read> permutations: array<u32>;
 @group(0) @binding(3) var<storage, read_write> permutation_labels: array<u32>;
 @group(0) @binding(4) var<storage, read_write> output: array<f32>;
 
-@compute @workgroup_size(1)
-fn main(@builtin(global_invocation_id) global_id: vec3<u32>) {
+@compute @workgroup_size(WORKGROUP_SIZE_X, WORKGROUP_SIZE_Y, WORKGROUP_SIZE_Z)
+fn main(
+    @builtin(global_invocation_id) global_invocation_id: vec3<u32>,
+    @builtin(num_workgroups) num_workgroups: vec3<u32>,
+) {
+    let size = vec3<u32>(options.solutions_count, 1u, 1u);
+    let index = dispatch_index(global_invocation_id, num_workgroups);
+    if (!dispatch_in_bounds(index, size)) {
+        return;
+    }
+    let global_id = dispatch_position(index, size);
+
     let offset = global_id.x * options.examples_count;
     let permutation_size = options.permutations_count * options.outputs_count;
     let permutation_labels_start = global_id.x * permutation_size;
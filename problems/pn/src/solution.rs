@@ -1,7 +1,7 @@
 use std::collections::HashMap;
 
 use serde::{Deserialize, Serialize};
-use sgrmath_core::{ProblemParams, ReadbackBuffer, StorageBuffer};
+use sgrmath_core::{Lease, ProblemParams, StorageBuffer};
 
 use crate::{shaders::ShaderOptions, PNP};
 
@@ -40,24 +40,28 @@ impl Solution {
     /// Init
     pub fn init(pnp: &PNP, vectors: Vec<f32>) -> Self {
         let permutations_size = pnp.permutations_count * pnp.outputs_count;
-        let params = ProblemParams {
-            context: pnp.wgpu.clone(),
-            solutions_offset: 0,
-            solutions_count: 1,
-            vector_length: pnp.vector_length,
-            solutions: StorageBuffer::init(&pnp.wgpu, vectors.as_slice()),
-            results: StorageBuffer::new::<f32, _>(&pnp.wgpu, 1),
-        };
+        let (solutions, results) = Self::lease_buffers(pnp, &vectors);
+        let params = ProblemParams::new(
+            pnp.wgpu.clone(),
+            (*solutions).clone(),
+            (*results).clone(),
+            0,
+            1,
+            pnp.vector_length,
+        );
         let (options, multiply, permutations, permutation_labels) = pnp.create_buffers(&pnp.wgpu, &ShaderOptions::new(pnp, 1));
-        let reader = ReadbackBuffer::new::<f32, _>(&pnp.wgpu, permutations_size);
         pnp.evaluate_with_buffers(&params, (&options, &multiply, &permutations, &permutation_labels));
 
         Self {
             vectors_count: pnp.vectors_count,
             outputs_count: pnp.outputs_count,
             vectors,
-            permutations: Self::permutations_from_vec(reader.read(&pnp.wgpu, &permutation_labels, 0, permutations_size)),
-            result: reader.read::<f32>(&pnp.wgpu, &params.results, 0, 1)[0] as usize,
+            permutations: Self::permutations_from_vec(
+                pnp.wgpu.pool
+                    .readback(permutations_size)
+                    .read(&pnp.wgpu, &permutation_labels, 0, permutations_size)
+            ),
+            result: pnp.wgpu.pool.readback(1).read(&pnp.wgpu, &results, 0, 1)[0] as usize,
         }
     }
 
@@ -68,14 +72,15 @@ impl Solution {
             &pnp.wgpu, 
             &ShaderOptions::new(pnp, 1)
         );
-        let params = ProblemParams {
-            context: pnp.wgpu.clone(),
-            solutions_offset: 0,
-            solutions_count: 1,
-            vector_length: pnp.vector_length,
-            solutions: StorageBuffer::init(&pnp.wgpu, vectors.as_slice()),
-            results: StorageBuffer::new::<f32, _>(&pnp.wgpu, 1),
-        };
+        let (solutions, results) = Self::lease_buffers(pnp, &vectors);
+        let params = ProblemParams::new(
+            pnp.wgpu.clone(),
+            (*solutions).clone(),
+            (*results).clone(),
+            0,
+            1,
+            pnp.vector_length,
+        );
         let mut permutation_outputs = vec![0u32; pnp.permutations_count];
         for i in 0..pnp.permutations_count {
             let start = i * pnp.outputs_count;
@@ -94,28 +99,35 @@ impl Solution {
             &params.context, 
             (pnp.examples_count, pnp.vectors_count * params.solutions_count),
             &[ &options, &pnp.examples, &params.solutions, &multiply ]
-        );
+        ).expect("Solution buffers do not match the shader bindings");
         pnp.shaders.permutations.execute_with_params(
             &params.context, 
             (params.solutions_count, pnp.examples_count),
             &[ &options, &multiply, &permutations_buffer ]
-        );
+        ).expect("Solution buffers do not match the shader bindings");
         pnp.shaders.test.execute_with_params(
             &params.context, 
             params.solutions_count,
             &[ &options, &pnp.labels, &permutations_buffer, &permutation_labels, &params.results ]
-        );
+        ).expect("Solution buffers do not match the shader bindings");
 
         Self {
             vectors_count: pnp.vectors_count,
             outputs_count: pnp.outputs_count,
             vectors,
             permutations: Self::permutations_from_vec(permutations),
-            result: ReadbackBuffer::new::<f32, _>(&pnp.wgpu, 1)
-                .read::<f32>(&pnp.wgpu, &params.results, 0, 1)[0] as usize,
+            result: pnp.wgpu.pool.readback(1).read(&pnp.wgpu, &results, 0, 1)[0] as usize,
         }
     }
 
+    /// Leases the solution and result buffers from the context pool
+    fn lease_buffers(pnp: &PNP, vectors: &[f32]) -> (Lease<StorageBuffer<f32>>, Lease<StorageBuffer<f32>>) {
+        let solutions = pnp.wgpu.pool.storage(vectors.len());
+        solutions.update_buffer_range(&pnp.wgpu, vectors, 0);
+
+        (solutions, pnp.wgpu.pool.storage(1))
+    }
+
     pub fn permutations_from_vec(permutations: Vec<u32>) -> HashMap<usize, u32> {
         let mut result = HashMap::new();
         for i in 0..permutations.len() {
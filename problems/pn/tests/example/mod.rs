@@ -11,8 +11,10 @@ pub fn pnp(wgpu: &WgpuContext) -> PNP {
         outputs_count: 4,
         examples: StorageBuffer::init(&wgpu, &examples()),
         labels: StorageBuffer::init(&wgpu, &labels()),
-        shaders: Shaders::new(&wgpu),
+        shaders: Shaders::new(&wgpu, 2),
         params: None,
+        buffers: None,
+        in_flight: None,
     }
 }
 
@@ -0,0 +1,198 @@
+use std::{cell::Cell, rc::Rc};
+
+use sgrmath_core::{CompiledIteration, CpuProblem, Error, FitnessPolicy, Iteration, NanPolicy, OptimizationDirection, WgpuContext};
+use sgrmath_ga::{common, continuous, Individual, IterationParams, Options, GA};
+
+use crate::example;
+
+#[test]
+fn seeded_runs_are_identical() {
+    let wgpu = WgpuContext::new();
+
+    let (first, first_best) = run(&wgpu, Some(42));
+    let (second, second_best) = run(&wgpu, Some(42));
+
+    assert_eq!(first, second);
+    assert_eq!(first_best, second_best);
+}
+
+#[test]
+fn seeds_differ() {
+    let wgpu = WgpuContext::new();
+
+    assert_ne!(run(&wgpu, Some(42)).1, run(&wgpu, Some(43)).1);
+}
+
+#[test]
+fn entropy_seed_reproduces() {
+    let wgpu = WgpuContext::new();
+    let seed = ga(&wgpu, None).seed();
+
+    assert_eq!(ga(&wgpu, Some(seed)).seed(), seed);
+}
+
+#[test]
+fn mutations() {
+    let wgpu = WgpuContext::new();
+    let mutations: Vec<Box<dyn Iteration<IterationParams<f32>>>> = vec![
+        Box::new(continuous::mutations::Random::new(0.1)),
+        Box::new(continuous::mutations::Gaussian::new(0.1, 0.1)),
+        Box::new(continuous::mutations::Cauchy::new(0.05, 0.1)),
+        Box::new(continuous::mutations::Polynomial::new(20.0, 0.1)),
+    ];
+
+    for mutation in mutations {
+        let mut ga = ga(&wgpu, Some(42))
+            .initializer(continuous::initializers::Random::new())
+            .crossover(continuous::crossovers::BLXAlpha::new(0.5))
+            .mutation(mutation)
+            .compile();
+        for _ in 0..3 {
+            ga.generation().unwrap();
+        }
+
+        assert!(ga.best_value().iter().all(|value| (-1.0..=1.0).contains(value)));
+    }
+}
+
+#[test]
+fn cpu_problem_reads_initialized_population() {
+    let wgpu = WgpuContext::new();
+    let mut ga = GA::new(
+        &wgpu,
+        &Options {
+            population_size: 4,
+            generation_size: 4,
+            vector_length: 3,
+            min_value: 1.0,
+            max_value: 2.0,
+            seed: Some(42),
+            ..Options::default()
+        }
+    )
+        .problem(CpuProblem::new(
+            |solutions: Vec<f32>, _, _| solutions.chunks(3).map(|vector| vector.iter().copied().fold(f32::MAX, f32::min)).collect(),
+            ()
+        ))
+        .initializer(continuous::initializers::Random::new())
+        .crossover(continuous::crossovers::BLXAlpha::new(0.5))
+        .mutation(continuous::mutations::Random::new(0.1))
+        .compile();
+
+    ga.generation().unwrap();
+
+    assert!(ga.data.borrow().individuals.iter().all(|individual| individual.result >= 1.0));
+}
+
+#[test]
+fn invalid_fitness() {
+    let wgpu = WgpuContext::new();
+    for valid_generations in [ 0, 2 ] {
+        let evaluations = Rc::new(Cell::new(0));
+        let counter = evaluations.clone();
+        let mut ga = GA::new(
+            &wgpu,
+            &Options {
+                fitness_policy: FitnessPolicy { nan: NanPolicy::Error, ..FitnessPolicy::default() },
+                population_size: 4,
+                generation_size: 2,
+                vector_length: 2,
+                seed: Some(42),
+                ..Options::default()
+            }
+        )
+            .problem(CpuProblem::new(
+                move |solutions: Vec<f32>, _, _| {
+                    counter.set(counter.get() + 1);
+                    let value = if counter.get() > valid_generations { f32::NAN } else { 0.0 };
+                    vec![ value; solutions.len() / 2 ]
+                },
+                ()
+            ))
+            .initializer(continuous::initializers::Random::new())
+            .crossover(continuous::crossovers::BLXAlpha::new(0.5))
+            .mutation(continuous::mutations::Random::new(0.1))
+            .compile();
+
+        for _ in 0..valid_generations {
+            ga.generation().unwrap();
+        }
+
+        assert!(matches!(ga.generation(), Err(Error::InvalidFitness(value)) if value.is_nan()));
+        assert_eq!(evaluations.get(), valid_generations + 1);
+    }
+}
+
+#[test]
+fn missing_operator() {
+    let wgpu = WgpuContext::new();
+    let result = ga(&wgpu, Some(42))
+        .initializer(continuous::initializers::Random::new())
+        .mutation(continuous::mutations::Random::new(0.1))
+        .try_compile();
+
+    assert!(matches!(result, Err(Error::NotImplemented(message)) if message == "crossover"));
+}
+
+#[test]
+fn panicking_operator() {
+    let wgpu = WgpuContext::new();
+    let evaluations = Cell::new(0);
+    let mut ga = ga(&wgpu, Some(42))
+        .problem(CpuProblem::new(
+            move |solutions: Vec<f32>, _, _| {
+                evaluations.set(evaluations.get() + 1);
+                assert!(evaluations.get() == 1, "evaluated twice");
+                vec![ 0.0; solutions.len() / 4 ]
+            },
+            ()
+        ))
+        .initializer(continuous::initializers::Random::new())
+        .crossover(continuous::crossovers::BLXAlpha::new(0.5))
+        .mutation(continuous::mutations::Random::new(0.1))
+        .compile();
+
+    ga.generation().unwrap();
+    let Err(Error::IterationPanicked { label, message }) = ga.generation() else {
+        panic!("the problem should panic");
+    };
+
+    assert_eq!(label, "problem");
+    assert_eq!(message, "evaluated twice");
+}
+
+/// Returns the individuals after every generation and the best solution of a run
+fn run(wgpu: &WgpuContext, seed: Option<u64>) -> (Vec<Vec<Individual>>, Vec<f32>) {
+    let mut ga = ga(wgpu, seed)
+        .parents(CompiledIteration::new(common::parents::Tournament::new(2, true)))
+        .initializer(continuous::initializers::Random::new())
+        .crossover(continuous::crossovers::BLXAlpha::new(0.5))
+        .mutation(continuous::mutations::Random::new(0.1))
+        .compile();
+
+    let mut history = vec![];
+    for _ in 0..5 {
+        ga.generation().unwrap();
+        history.push(ga.data.borrow().individuals.clone());
+    }
+
+    (history, ga.best_value())
+}
+
+fn ga(wgpu: &WgpuContext, seed: Option<u64>) -> GA<f32> {
+    GA::new(
+        wgpu,
+        &Options {
+            optimization_direction: OptimizationDirection::Maximize,
+            population_size: 8,
+            generation_size: 4,
+            parents_count: 2,
+            vector_length: 4,
+            min_value: -1.0,
+            max_value: 1.0,
+            seed,
+            ..Options::default()
+        }
+    )
+        .try_problem(example::pnp(wgpu))
+}
@@ -1,4 +1,5 @@
 mod example;
+mod ga;
 mod pnp;
 mod shaders;
 mod solution;
\ No newline at end of file
@@ -1,4 +1,4 @@
-use sgrmath_core::{Iteration, ProblemParams, ReadbackBuffer, StorageBuffer, WgpuContext};
+use sgrmath_core::{Bindable, Error, Iteration, ProblemParams, ReadbackBuffer, StorageBuffer, TryIteration, WgpuContext};
 use sgrmath_pn::PNP;
 
 use crate::example;
@@ -24,17 +24,17 @@ fn init() {
         (0..1000).map(|i| i as f32).collect::<Vec<_>>(), 
         (0..100).map(|i| 99 - i).collect::<Vec<_>>()
     );
-    let reader = ReadbackBuffer::new::<u32, _>(&ctx, 1000);
+    let reader = ReadbackBuffer::new(&ctx, 1000);
 
     assert_eq!(pn.examples.size(), 4000);
     assert_eq!(pn.labels.size(), 400);
 
     assert_eq!(
-        reader.read::<f32>(&ctx, &pn.examples, 0, 1000), 
+        reader.read(&ctx, &pn.examples, 0, 1000), 
         (0..1000).map(|i| i as f32).collect::<Vec<_>>()
     );
     assert_eq!(
-        reader.read::<u32>(&ctx, &pn.labels, 0, 100), 
+        ReadbackBuffer::new(&ctx, 100).read(&ctx, &pn.labels, 0, 100), 
         (0..100).map(|i| (99 - i) as u32).collect::<Vec<_>>()
     );
 }
@@ -51,15 +51,15 @@ fn from_csv_with_static_value() {
         Some(255.0),
         None
     );
-    let reader = ReadbackBuffer::new::<u32, _>(&ctx, 12);
+    let reader = ReadbackBuffer::new(&ctx, 12);
 
     assert_eq!(pn.examples_count, 2);
     assert_eq!(
-        reader.read::<f32>(&ctx, &pn.examples, 0, 12), 
+        reader.read(&ctx, &pn.examples, 0, 12), 
         vec![ 0.0, 1.0, 2.0, 3.0, 4.0, 255.0, 10.0, 9.0, 8.0, 7.0, 6.0, 255.0 ]
     );
     assert_eq!(
-        reader.read::<u32>(&ctx, &pn.labels, 0, 2), 
+        ReadbackBuffer::new(&ctx, 2).read(&ctx, &pn.labels, 0, 2), 
         vec![ 0, 2 ]
     );
 }
@@ -76,15 +76,15 @@ fn from_csv_without_static_value() {
         None,
         None
     );
-    let reader = ReadbackBuffer::new::<u32, _>(&ctx, 10);
+    let reader = ReadbackBuffer::new(&ctx, 10);
 
     assert_eq!(pn.examples_count, 2);
     assert_eq!(
-        reader.read::<f32>(&ctx, &pn.examples, 0, 10), 
+        reader.read(&ctx, &pn.examples, 0, 10), 
         vec![ 0.0, 1.0, 2.0, 3.0, 4.0, 10.0, 9.0, 8.0, 7.0, 6.0 ]
     );
     assert_eq!(
-        reader.read::<u32>(&ctx, &pn.labels, 0, 2), 
+        ReadbackBuffer::new(&ctx, 2).read(&ctx, &pn.labels, 0, 2), 
         vec![ 0, 2 ]
     );
 }
@@ -92,45 +92,149 @@ fn from_csv_without_static_value() {
 #[test]
 fn bind_and_evaluate() {
     let ctx = WgpuContext::new();
-    let solutions = StorageBuffer::new::<f32, _>(&ctx, 20);
-    let results = StorageBuffer::new::<f32, _>(&ctx, 5);
-    let params = ProblemParams {
-        context: ctx.clone(),
-        solutions_offset: 0,
-        solutions_count: 5,
-        vector_length: 2, 
-        solutions: solutions.clone(), 
-        results: results.clone(), 
-    };
-    let reader = ReadbackBuffer::new::<f32, _>(&ctx, 5);
+    let solutions = StorageBuffer::<f32>::new(&ctx, 20);
+    let results = StorageBuffer::<f32>::new(&ctx, 5);
+    let params = ProblemParams::new(
+        ctx.clone(),
+        solutions.clone(),
+        results.clone(),
+        0,
+        5,
+        2,
+    );
+    let reader = ReadbackBuffer::<f32>::new(&ctx, 5);
     let mut pnp = example::pnp(&ctx);
     solutions.update_buffer_range(&ctx, &example::vectors(), 0);
 
     Iteration::<ProblemParams>::bind(&mut pnp, &params);
     Iteration::<ProblemParams>::evaluate(&mut pnp);
 
-    assert_eq!(reader.read::<f32>(&ctx, &results, 0, 5), example::results());
+    assert_eq!(reader.read(&ctx, &results, 0, 5), example::results());
 }
 
+#[test]
+fn try_evaluate_before_bind() {
+    let ctx = WgpuContext::new();
+    let mut pnp = example::pnp(&ctx);
+
+    assert!(matches!(pnp.try_evaluate(), Err(Error::NotBound(_))));
+    assert!(matches!(pnp.try_evaluate_async(), Err(Error::NotBound(_))));
+}
 
 #[test]
 fn evaluate_with_params() {
     let ctx = WgpuContext::new();
-    let solutions = StorageBuffer::new::<f32, _>(&ctx, 20);
-    let results = StorageBuffer::new::<f32, _>(&ctx, 5);
-    let params = ProblemParams {
-        context: ctx.clone(),
-        solutions_offset: 0,
-        solutions_count: 5,
-        vector_length: 2, 
-        solutions: solutions.clone(), 
-        results: results.clone(), 
-    };
-    let reader = ReadbackBuffer::new::<f32, _>(&ctx, 5);
+    let solutions = StorageBuffer::<f32>::new(&ctx, 20);
+    let results = StorageBuffer::<f32>::new(&ctx, 5);
+    let params = ProblemParams::new(
+        ctx.clone(),
+        solutions.clone(),
+        results.clone(),
+        0,
+        5,
+        2,
+    );
+    let reader = ReadbackBuffer::<f32>::new(&ctx, 5);
     let mut pnp = example::pnp(&ctx);
     
     solutions.update_buffer_range(&ctx, &example::vectors(), 0);
     Iteration::<ProblemParams>::evaluate_with_params(&mut pnp, &params);
 
-    assert_eq!(reader.read::<f32>(&ctx, &results, 0, 5), example::results());
+    assert_eq!(reader.read(&ctx, &results, 0, 5), example::results());
+}
+
+#[test]
+fn evaluate_with_params_reuses_buffers() {
+    let ctx = WgpuContext::new();
+    let solutions = StorageBuffer::<f32>::new(&ctx, 20);
+    let results = StorageBuffer::<f32>::new(&ctx, 5);
+    let params = ProblemParams::new(
+        ctx.clone(),
+        solutions.clone(),
+        results.clone(),
+        0,
+        5,
+        2,
+    );
+    let reader = ReadbackBuffer::<f32>::new(&ctx, 5);
+    let mut pnp = example::pnp(&ctx);
+    solutions.update_buffer_range(&ctx, &example::vectors(), 0);
+
+    Iteration::<ProblemParams>::evaluate_with_params(&mut pnp, &params);
+    let idle_count = ctx.pool.idle_count();
+    Iteration::<ProblemParams>::evaluate_with_params(&mut pnp, &params);
+
+    assert_eq!(idle_count, 3);
+    assert_eq!(ctx.pool.idle_count(), idle_count);
+    assert_eq!(reader.read(&ctx, &results, 0, 5), example::results());
+}
+
+#[test]
+fn evaluate_with_params_async_keeps_bound_buffers() {
+    let ctx = WgpuContext::new();
+    let solutions = StorageBuffer::<f32>::new(&ctx, 20);
+    let results = StorageBuffer::<f32>::new(&ctx, 5);
+    let params = ProblemParams::new(
+        ctx.clone(),
+        solutions.clone(),
+        results.clone(),
+        0,
+        5,
+        2,
+    );
+    let reader = ReadbackBuffer::<f32>::new(&ctx, 5);
+    let mut pnp = example::pnp(&ctx);
+    solutions.update_buffer_range(&ctx, &example::vectors(), 0);
+
+    Iteration::<ProblemParams>::bind(&mut pnp, &params);
+    let commands = Iteration::<ProblemParams>::evaluate_with_params_async(&mut pnp, &params);
+    assert_eq!(ctx.pool.idle_count(), 0);
+
+    ctx.queue.submit(commands);
+    results.update_buffer_range(&ctx, &[0.0; 5], 0);
+    Iteration::<ProblemParams>::evaluate(&mut pnp);
+    assert_eq!(ctx.pool.idle_count(), 3);
+
+    // New leases of the same size must not alias the bound buffers
+    let multiply = ctx.pool.storage::<f32, _>((2 * 5, 10));
+    assert!(multiply.buffer() != pnp.buffers.as_ref().unwrap().1.buffer());
+
+    assert_eq!(reader.read(&ctx, &results, 0, 5), example::results());
+}
+
+#[test]
+fn evaluate_with_params_async_releases_previous_buffers() {
+    let ctx = WgpuContext::new();
+    let solutions = StorageBuffer::<f32>::new(&ctx, 20);
+    let results = StorageBuffer::<f32>::new(&ctx, 5);
+    let params = ProblemParams::new(
+        ctx.clone(),
+        solutions.clone(),
+        results.clone(),
+        0,
+        5,
+        2,
+    );
+    let reader = ReadbackBuffer::<f32>::new(&ctx, 5);
+    let mut pnp = example::pnp(&ctx);
+    solutions.update_buffer_range(&ctx, &example::vectors(), 0);
+
+    let commands = Iteration::<ProblemParams>::evaluate_with_params_async(&mut pnp, &params);
+    ctx.queue.submit(commands);
+    let leased = |pnp: &PNP| {
+        let (_, multiply, permutations, permutation_labels) = pnp.in_flight.as_ref().unwrap();
+        [multiply.buffer().clone(), permutations.buffer().clone(), permutation_labels.buffer().clone()]
+    };
+    let first = leased(&pnp);
+    assert_eq!(ctx.pool.idle_count(), 0);
+
+    // The next evaluation takes the submitted leases back instead of growing the pool
+    let commands = Iteration::<ProblemParams>::evaluate_with_params_async(&mut pnp, &params);
+    ctx.queue.submit(commands);
+    assert!(leased(&pnp).iter().all(|buffer| first.contains(buffer)));
+    assert_eq!(ctx.pool.idle_count(), 0);
+
+    Iteration::<ProblemParams>::evaluate_with_params(&mut pnp, &params);
+    assert_eq!(ctx.pool.idle_count(), 3);
+    assert_eq!(reader.read(&ctx, &results, 0, 5), example::results());
 }
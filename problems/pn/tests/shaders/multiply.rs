@@ -40,15 +40,15 @@ fn calc(
     let output_size = (options.solutions_count * options.vectors_count * options.examples_count) as usize;
 
     let options_buffer = ValueBuffer::init(ctx, &options);
-    let examples_buffer = StorageBuffer::new::<f32, _>(ctx, examples_size);
-    let vectors_buffer = StorageBuffer::new::<f32, _>(ctx, vectors_size);
-    let output_buffer = StorageBuffer::new::<f32, _>(ctx, output_size);
-    let readback_buffer = ReadbackBuffer::new::<f32, _>(ctx, output_size);
+    let examples_buffer = StorageBuffer::<f32>::new(ctx, examples_size);
+    let vectors_buffer = StorageBuffer::<f32>::new(ctx, vectors_size);
+    let output_buffer = StorageBuffer::<f32>::new(ctx, output_size);
+    let readback_buffer = ReadbackBuffer::<f32>::new(ctx, output_size);
     
-    examples_buffer.update_buffer_range::<f32>(ctx, examples.as_slice(), 0);
-    vectors_buffer.update_buffer_range::<f32>(ctx, vectors.as_slice(), 0);
+    examples_buffer.update_buffer_range(ctx, examples.as_slice(), 0);
+    vectors_buffer.update_buffer_range(ctx, vectors.as_slice(), 0);
 
-    let shader = Shaders::new(ctx).multiply;
+    let shader = Shaders::new(ctx, options.vectors_count as usize).multiply;
     shader.execute_with_params(
         ctx, 
         (
@@ -56,7 +56,7 @@ fn calc(
             (options.vectors_count * options.solutions_count) as usize
         ), 
         &[ &options_buffer, &examples_buffer, &vectors_buffer, &output_buffer, ]
-    );
+    ).unwrap();
 
-    readback_buffer.read::<f32>(ctx, &output_buffer, 0, output_size)
+    readback_buffer.read(ctx, &output_buffer, 0, output_size)
 }
\ No newline at end of file
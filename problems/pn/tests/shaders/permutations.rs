@@ -33,23 +33,45 @@ fn evaluate() {
     );
 }
 
+#[test]
+fn evaluate_many_vectors() {
+    let ctx = WgpuContext::new();
+
+    // Sorted descending the vectors are reversed and followed by the implicit zero vector
+    assert_eq!(
+        calc(
+            &ctx,
+            ShaderOptions {
+                examples_count: 1,
+                vector_length: 1,
+                vectors_count: 8,
+                solutions_count: 1,
+                outputs_count: 2,
+                permutations_count: 362_880
+            },
+            vec![ 1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0 ]
+        ),
+        vec![ 316_646 ]
+    );
+}
+
 fn calc(ctx: &WgpuContext, options: ShaderOptions, input: Vec<f32>) -> Vec<u32> {
     let input_size = (options.examples_count * options.vectors_count * options.solutions_count) as usize;
     let output_size: Size = (options.solutions_count as usize, options.examples_count as usize).into();
 
     let options_buffer = ValueBuffer::init(ctx, &options);
-    let input_buffer = StorageBuffer::new::<f32, _>(ctx, input_size);
-    let output_buffer = StorageBuffer::new::<f32, _>(ctx, output_size.len());
-    let readback_buffer = ReadbackBuffer::new::<f32, _>(ctx, output_size.len());
+    let input_buffer = StorageBuffer::<f32>::new(ctx, input_size);
+    let output_buffer = StorageBuffer::<u32>::new(ctx, output_size.len());
+    let readback_buffer = ReadbackBuffer::new(ctx, output_size.len());
     
-    input_buffer.update_buffer_range::<f32>(ctx, input.as_slice(), 0);
+    input_buffer.update_buffer_range(ctx, input.as_slice(), 0);
 
-    let shader = Shaders::new(ctx).permutations;
+    let shader = Shaders::new(ctx, options.vectors_count as usize).permutations;
     shader.execute_with_params(
         ctx, 
         output_size.clone(), 
         &[ &options_buffer, &input_buffer, &output_buffer, ]
-    );
+    ).unwrap();
 
-    readback_buffer.read::<u32>(ctx, &output_buffer, 0, output_size.len())
+    readback_buffer.read(ctx, &output_buffer, 0, output_size.len())
 }
@@ -25,30 +25,30 @@ fn calc(
     permutations: Vec<u32>
 ) -> Vec<f32> {
     let options_buffer = ValueBuffer::init(ctx, &options);
-    let examples_buffer = StorageBuffer::new::<u32, _>(
+    let examples_buffer = StorageBuffer::<u32>::new(
         ctx, 
         options.examples_count as usize
     );
-    let permutations_buffer = StorageBuffer::new::<u32, _>(
+    let permutations_buffer = StorageBuffer::<u32>::new(
         ctx, 
         (options.permutations_count as usize, options.examples_count as usize)
     );
-    let permutation_labels_buffer = StorageBuffer::new::<f32, _>(
+    let permutation_labels_buffer = StorageBuffer::<f32>::new(
         ctx, 
         (options.solutions_count * options.permutations_count * options.outputs_count) as usize
     );
-    let output_buffer = StorageBuffer::new::<f32, _>(ctx, options.solutions_count as usize);
-    let readback_buffer = ReadbackBuffer::new::<f32, _>(ctx, options.solutions_count as usize);
+    let output_buffer = StorageBuffer::<f32>::new(ctx, options.solutions_count as usize);
+    let readback_buffer = ReadbackBuffer::<f32>::new(ctx, options.solutions_count as usize);
     
     examples_buffer.update_buffer_range(ctx, examples.as_slice(), 0);
     permutations_buffer.update_buffer_range(ctx, permutations.as_slice(), 0);
 
-    let shader = Shaders::new(ctx).results;
+    let shader = Shaders::new(ctx, options.vectors_count as usize).results;
     shader.execute_with_params(
         ctx, 
         options.solutions_count as usize, 
         &[ &options_buffer, &examples_buffer, &permutations_buffer, &permutation_labels_buffer, &output_buffer, ]
-    );
+    ).unwrap();
 
     readback_buffer.read(
         ctx, 